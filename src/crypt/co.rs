@@ -0,0 +1,58 @@
+#![allow(non_camel_case_types, non_upper_case_globals)]
+
+const_str! { BCRYPT_ALG;
+	/// [CNG algorithm identifiers](https://learn.microsoft.com/en-us/windows/win32/seccng/cng-algorithm-identifiers)
+	/// (`&'static str`).
+	///
+	/// Originally has `BCRYPT` prefix and `ALGORITHM` suffix.
+	=>
+	MD5 "MD5"
+	SHA1 "SHA1"
+	SHA256 "SHA256"
+	SHA384 "SHA384"
+	SHA512 "SHA512"
+	RNG "RNG"
+}
+
+const_bitflag! { BCRYPT_ALG_FLAG: u32;
+	/// [`HBCRYPTALG::BCryptOpenAlgorithmProvider`](crate::prelude::crypt_Hbcryptalg::BCryptOpenAlgorithmProvider)
+	/// `flags` (`u32`).
+	///
+	/// Originally has `BCRYPT` prefix.
+	=>
+	=>
+	/// None of the actual values (zero).
+	NoValue 0
+	PROV_DISPATCH 0x0000_0001
+	/// Originally `BCRYPT_ALG_HANDLE_HMAC_FLAG`.
+	HMAC 0x0000_0008
+	/// Originally `BCRYPT_HASH_REUSABLE_FLAG`.
+	HASH_REUSABLE 0x0000_0020
+}
+
+const_str! { BCRYPT_PROP;
+	/// [CNG property identifiers](https://learn.microsoft.com/en-us/windows/win32/seccng/cng-property-identifiers)
+	/// (`&'static str`).
+	///
+	/// Originally has `BCRYPT` prefix.
+	=>
+	ALGORITHM_NAME "AlgorithmName"
+	BLOCK_LENGTH "BlockLength"
+	HASH_BLOCK_LENGTH "HashBlockLength"
+	HASH_LENGTH "HashDigestLength"
+	OBJECT_LENGTH "ObjectLength"
+	PROVIDER_HANDLE "ProviderHandle"
+}
+
+const_bitflag! { CRYPTPROTECT: u32;
+	/// [`CryptProtectData`](crate::CryptProtectData) and
+	/// [`CryptUnprotectData`](crate::CryptUnprotectData) `flags` (`u32`).
+	=>
+	=>
+	/// None of the actual values (zero).
+	NoValue 0
+	UI_FORBIDDEN 0x1
+	LOCAL_MACHINE 0x4
+	AUDIT 0x10
+	VERIFY_PROTECTION 0x40
+}
@@ -0,0 +1,22 @@
+use crate::kernel::ffi_types::*;
+
+extern_sys! { "bcrypt";
+	BCryptCloseAlgorithmProvider(HANDLE, u32) -> i32
+	BCryptCreateHash(HANDLE, *mut HANDLE, PVOID, u32, PCVOID, u32, u32) -> i32
+	BCryptDestroyHash(HANDLE) -> i32
+	BCryptDuplicateHash(HANDLE, *mut HANDLE, PVOID, u32, u32) -> i32
+	BCryptFinishHash(HANDLE, PVOID, u32, u32) -> i32
+	BCryptGenRandom(HANDLE, PVOID, u32, u32) -> i32
+	BCryptGetProperty(HANDLE, PCSTR, PVOID, u32, *mut u32, u32) -> i32
+	BCryptHashData(HANDLE, PCVOID, u32, u32) -> i32
+	BCryptOpenAlgorithmProvider(*mut HANDLE, PCSTR, PCSTR, u32) -> i32
+}
+
+extern_sys! { "crypt32";
+	CryptProtectData(PCVOID, PCSTR, PCVOID, PVOID, PVOID, u32, PVOID) -> BOOL
+	CryptUnprotectData(PCVOID, *mut PSTR, PCVOID, PVOID, PVOID, u32, PVOID) -> BOOL
+}
+
+extern_sys! { "ntdll";
+	RtlNtStatusToDosError(i32) -> u32
+}
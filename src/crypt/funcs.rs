@@ -0,0 +1,159 @@
+#![allow(non_snake_case)]
+
+use crate::co;
+use crate::crypt::{ffi, privs::*};
+use crate::decl::*;
+use crate::guard::*;
+use crate::kernel::privs::*;
+use crate::prelude::*;
+
+/// [`BCryptGenRandom`](https://learn.microsoft.com/en-us/windows/win32/api/bcrypt/nf-bcrypt-bcryptgenrandom)
+/// function.
+///
+/// If `halg` is `None`, the system-preferred random number generator is used.
+/// Otherwise, it must be a provider opened with
+/// [`co::BCRYPT_ALG::RNG`](crate::co::BCRYPT_ALG::RNG).
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let mut salt = [0u8; 16];
+/// w::BCryptGenRandom(None, &mut salt)?;
+/// # Ok::<_, co::ERROR>(())
+/// ```
+pub fn BCryptGenRandom(
+	halg: Option<&HBCRYPTALG>,
+	buffer: &mut [u8],
+) -> SysResult<()>
+{
+	for chunk in buffer.chunks_mut(u32::MAX as _) {
+		ntstatus_to_sysresult(
+			unsafe {
+				ffi::BCryptGenRandom(
+					halg.map_or(std::ptr::null_mut(), |h| h.ptr()),
+					chunk.as_mut_ptr() as _,
+					chunk.len() as _,
+					if halg.is_none() { BCRYPT_USE_SYSTEM_PREFERRED_RNG } else { 0 },
+				)
+			},
+		)?;
+	}
+	Ok(())
+}
+
+/// [`CryptProtectData`](https://learn.microsoft.com/en-us/windows/win32/api/dpapi/nf-dpapi-cryptprotectdata)
+/// function.
+///
+/// Encrypts the data with the credentials of the current user – or of the
+/// computer, with
+/// [`co::CRYPTPROTECT::LOCAL_MACHINE`](crate::co::CRYPTPROTECT::LOCAL_MACHINE)
+/// –, so it can be stored at rest and later decrypted with
+/// [`CryptUnprotectData`](crate::CryptUnprotectData). The optional `entropy`
+/// must be passed again for decryption.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let encrypted = w::CryptProtectData(
+///     b"my password",
+///     Some("Saved password"),
+///     None,
+///     co::CRYPTPROTECT::UI_FORBIDDEN,
+/// )?;
+///
+/// let (decrypted, descr) = w::CryptUnprotectData(
+///     &encrypted,
+///     None,
+///     co::CRYPTPROTECT::UI_FORBIDDEN,
+/// )?;
+/// assert_eq!(decrypted, b"my password");
+/// assert_eq!(descr, "Saved password");
+/// # Ok::<_, co::ERROR>(())
+/// ```
+#[must_use]
+pub fn CryptProtectData(
+	data: &[u8],
+	description: Option<&str>,
+	entropy: Option<&[u8]>,
+	flags: co::CRYPTPROTECT,
+) -> SysResult<Vec<u8>>
+{
+	let blob_in = DATA_BLOB::from_slice(data);
+	let blob_entropy = entropy.map(DATA_BLOB::from_slice);
+	let mut blob_out = DATA_BLOB::default();
+
+	bool_to_sysresult(
+		unsafe {
+			ffi::CryptProtectData(
+				&blob_in as *const _ as _,
+				WString::from_opt_str(description).as_ptr(),
+				blob_entropy.as_ref().map_or(std::ptr::null(), |b| b as *const _ as _),
+				std::ptr::null_mut(),
+				std::ptr::null_mut(),
+				flags.raw(),
+				&mut blob_out as *mut _ as _,
+			)
+		},
+	)?;
+
+	let data_out = unsafe {
+		std::slice::from_raw_parts(blob_out.pbData, blob_out.cbData as _)
+	}.to_vec();
+	let _ = unsafe { LocalFreeGuard::new(HLOCAL::from_ptr(blob_out.pbData as _)) }; // free returned pointer
+	Ok(data_out)
+}
+
+/// [`CryptUnprotectData`](https://learn.microsoft.com/en-us/windows/win32/api/dpapi/nf-dpapi-cryptunprotectdata)
+/// function.
+///
+/// Decrypts data encrypted with
+/// [`CryptProtectData`](crate::CryptProtectData), returning the decrypted
+/// data and its description. The decrypted data is zeroed in the
+/// system-allocated buffer before it's freed.
+#[must_use]
+pub fn CryptUnprotectData(
+	data: &[u8],
+	entropy: Option<&[u8]>,
+	flags: co::CRYPTPROTECT,
+) -> SysResult<(Vec<u8>, String)>
+{
+	let blob_in = DATA_BLOB::from_slice(data);
+	let blob_entropy = entropy.map(DATA_BLOB::from_slice);
+	let mut blob_out = DATA_BLOB::default();
+	let mut pdescr = std::ptr::null_mut::<u16>();
+
+	bool_to_sysresult(
+		unsafe {
+			ffi::CryptUnprotectData(
+				&blob_in as *const _ as _,
+				&mut pdescr,
+				blob_entropy.as_ref().map_or(std::ptr::null(), |b| b as *const _ as _),
+				std::ptr::null_mut(),
+				std::ptr::null_mut(),
+				flags.raw(),
+				&mut blob_out as *mut _ as _,
+			)
+		},
+	)?;
+
+	let _descr_guard = unsafe { LocalFreeGuard::new(HLOCAL::from_ptr(pdescr as _)) };
+	let _data_guard = unsafe { LocalFreeGuard::new(HLOCAL::from_ptr(blob_out.pbData as _)) };
+
+	let descr = if pdescr.is_null() {
+		String::default()
+	} else {
+		WString::from_wchars_nullt(pdescr).to_string()
+	};
+
+	let decrypted = unsafe {
+		std::slice::from_raw_parts_mut(blob_out.pbData, blob_out.cbData as _)
+	};
+	let data_out = decrypted.to_vec();
+	decrypted.iter_mut().for_each(|b| unsafe { std::ptr::write_volatile(b, 0) }); // don't leave the secret behind
+
+	Ok((data_out, descr))
+}
@@ -0,0 +1,73 @@
+use std::ops::{Deref, DerefMut};
+
+use crate::crypt::ffi;
+use crate::decl::*;
+use crate::prelude::*;
+
+/// RAII implementation for [`HBCRYPTALG`](crate::HBCRYPTALG) which
+/// automatically calls
+/// [`BCryptCloseAlgorithmProvider`](https://learn.microsoft.com/en-us/windows/win32/api/bcrypt/nf-bcrypt-bcryptclosealgorithmprovider)
+/// when the object goes out of scope.
+pub struct BCryptCloseAlgorithmProviderGuard {
+	handle: HBCRYPTALG,
+}
+
+impl Drop for BCryptCloseAlgorithmProviderGuard {
+	fn drop(&mut self) {
+		if let Some(h) = self.handle.as_opt() {
+			unsafe { ffi::BCryptCloseAlgorithmProvider(h.ptr(), 0); } // ignore errors
+		}
+	}
+}
+
+impl Deref for BCryptCloseAlgorithmProviderGuard {
+	type Target = HBCRYPTALG;
+
+	fn deref(&self) -> &Self::Target {
+		&self.handle
+	}
+}
+
+impl DerefMut for BCryptCloseAlgorithmProviderGuard {
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		&mut self.handle
+	}
+}
+
+impl BCryptCloseAlgorithmProviderGuard {
+	/// Constructs the guard by taking ownership of the handle.
+	///
+	/// # Safety
+	///
+	/// Be sure the handle must be freed with
+	/// [`BCryptCloseAlgorithmProvider`](https://learn.microsoft.com/en-us/windows/win32/api/bcrypt/nf-bcrypt-bcryptclosealgorithmprovider)
+	/// at the end of scope.
+	///
+	/// This method is used internally by the library, and not intended to be
+	/// used externally.
+	#[must_use]
+	pub const unsafe fn new(handle: HBCRYPTALG) -> Self {
+		Self { handle }
+	}
+
+	/// Ejects the underlying handle, leaving a
+	/// [`Handle::INVALID`](crate::prelude::Handle::INVALID) in its place.
+	///
+	/// Since the internal handle will be invalidated, the destructor will not
+	/// run. It's your responsability to run it, otherwise you'll cause a
+	/// resource leak.
+	#[must_use]
+	pub fn leak(&mut self) -> HBCRYPTALG {
+		std::mem::replace(&mut self.handle, HBCRYPTALG::INVALID)
+	}
+}
+
+//------------------------------------------------------------------------------
+
+handle_guard! { BCryptDestroyHashGuard: HBCRYPTHASH;
+	ffi::BCryptDestroyHash;
+	/// RAII implementation for [`HBCRYPTHASH`](crate::HBCRYPTHASH) which
+	/// automatically calls
+	/// [`BCryptDestroyHash`](https://learn.microsoft.com/en-us/windows/win32/api/bcrypt/nf-bcrypt-bcryptdestroyhash)
+	/// when the object goes out of scope.
+}
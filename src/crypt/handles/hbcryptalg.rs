@@ -0,0 +1,127 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::crypt::{ffi, privs::*};
+use crate::decl::*;
+use crate::guard::*;
+use crate::prelude::*;
+
+impl_handle! { HBCRYPTALG;
+	/// Handle to a
+	/// [CNG algorithm provider](https://learn.microsoft.com/en-us/windows/win32/seccng/cng-algorithm-identifiers).
+	/// Originally `BCRYPT_ALG_HANDLE`.
+}
+
+impl crypt_Hbcryptalg for HBCRYPTALG {}
+
+/// This trait is enabled with the `crypt` feature, and provides methods for
+/// [`HBCRYPTALG`](crate::HBCRYPTALG).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait crypt_Hbcryptalg: Handle {
+	/// [`BCryptCreateHash`](https://learn.microsoft.com/en-us/windows/win32/api/bcrypt/nf-bcrypt-bcryptcreatehash)
+	/// function.
+	///
+	/// The `secret` is the HMAC key, and it's used only if the provider was
+	/// opened with
+	/// [`co::BCRYPT_ALG_FLAG::HMAC`](crate::co::BCRYPT_ALG_FLAG::HMAC).
+	///
+	/// The memory for the hash object is allocated by the system.
+	#[must_use]
+	fn BCryptCreateHash(&self,
+		secret: Option<&[u8]>,
+	) -> SysResult<BCryptDestroyHashGuard>
+	{
+		let mut hhash = HBCRYPTHASH::NULL;
+		unsafe {
+			ntstatus_to_sysresult(
+				ffi::BCryptCreateHash(
+					self.ptr(),
+					hhash.as_mut(),
+					std::ptr::null_mut(),
+					0,
+					secret.map_or(std::ptr::null(), |s| s.as_ptr() as _),
+					secret.map_or(0, |s| s.len() as _),
+					0,
+				),
+			).map(|_| BCryptDestroyHashGuard::new(hhash))
+		}
+	}
+
+	/// [`BCryptGetProperty`](https://learn.microsoft.com/en-us/windows/win32/api/bcrypt/nf-bcrypt-bcryptgetproperty)
+	/// function.
+	///
+	/// Returns the number of bytes written to the buffer. If the buffer is
+	/// empty, returns the number of bytes required.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let halg = w::HBCRYPTALG::BCryptOpenAlgorithmProvider(
+	///     co::BCRYPT_ALG::SHA256, None, co::BCRYPT_ALG_FLAG::NoValue)?;
+	///
+	/// let mut buf = [0u8; 4];
+	/// halg.BCryptGetProperty(co::BCRYPT_PROP::HASH_LENGTH, &mut buf)?;
+	/// let hash_len = u32::from_le_bytes(buf);
+	/// # Ok::<_, co::ERROR>(())
+	/// ```
+	fn BCryptGetProperty(&self,
+		property: co::BCRYPT_PROP,
+		buf: &mut [u8],
+	) -> SysResult<u32>
+	{
+		let mut num_bytes = u32::default();
+		ntstatus_to_sysresult(
+			unsafe {
+				ffi::BCryptGetProperty(
+					self.ptr(),
+					WString::from(property).as_ptr(),
+					if buf.is_empty() { std::ptr::null_mut() } else { buf.as_mut_ptr() as _ },
+					buf.len() as _,
+					&mut num_bytes,
+					0,
+				)
+			},
+		).map(|_| num_bytes)
+	}
+
+	/// [`BCryptOpenAlgorithmProvider`](https://learn.microsoft.com/en-us/windows/win32/api/bcrypt/nf-bcrypt-bcryptopenalgorithmprovider)
+	/// static method.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let halg = w::HBCRYPTALG::BCryptOpenAlgorithmProvider(
+	///     co::BCRYPT_ALG::SHA256, None, co::BCRYPT_ALG_FLAG::HMAC)?;
+	///
+	/// let hhash = halg.BCryptCreateHash(Some(b"secret key"))?;
+	/// # Ok::<_, co::ERROR>(())
+	/// ```
+	#[must_use]
+	fn BCryptOpenAlgorithmProvider(
+		algorithm: co::BCRYPT_ALG,
+		implementation: Option<&str>,
+		flags: co::BCRYPT_ALG_FLAG,
+	) -> SysResult<BCryptCloseAlgorithmProviderGuard>
+	{
+		let mut halg = HBCRYPTALG::NULL;
+		unsafe {
+			ntstatus_to_sysresult(
+				ffi::BCryptOpenAlgorithmProvider(
+					halg.as_mut(),
+					WString::from(algorithm).as_ptr(),
+					WString::from_opt_str(implementation).as_ptr(),
+					flags.raw(),
+				),
+			).map(|_| BCryptCloseAlgorithmProviderGuard::new(halg))
+		}
+	}
+}
@@ -0,0 +1,90 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::crypt::{ffi, privs::*};
+use crate::decl::*;
+use crate::guard::*;
+use crate::prelude::*;
+
+impl_handle! { HBCRYPTHASH;
+	/// Handle to a
+	/// [CNG hash object](https://learn.microsoft.com/en-us/windows/win32/seccng/creating-a-hash-with-cng).
+	/// Originally `BCRYPT_HASH_HANDLE`.
+}
+
+impl crypt_Hbcrypthash for HBCRYPTHASH {}
+
+/// This trait is enabled with the `crypt` feature, and provides methods for
+/// [`HBCRYPTHASH`](crate::HBCRYPTHASH).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait crypt_Hbcrypthash: Handle {
+	/// [`BCryptDuplicateHash`](https://learn.microsoft.com/en-us/windows/win32/api/bcrypt/nf-bcrypt-bcryptduplicatehash)
+	/// function.
+	///
+	/// Useful to compute the hashes of many inputs which share a common
+	/// prefix. The memory for the new hash object is allocated by the system.
+	#[must_use]
+	fn BCryptDuplicateHash(&self) -> SysResult<BCryptDestroyHashGuard> {
+		let mut hhash = HBCRYPTHASH::NULL;
+		unsafe {
+			ntstatus_to_sysresult(
+				ffi::BCryptDuplicateHash(
+					self.ptr(),
+					hhash.as_mut(),
+					std::ptr::null_mut(),
+					0,
+					0,
+				),
+			).map(|_| BCryptDestroyHashGuard::new(hhash))
+		}
+	}
+
+	/// [`BCryptFinishHash`](https://learn.microsoft.com/en-us/windows/win32/api/bcrypt/nf-bcrypt-bcryptfinishhash)
+	/// function.
+	///
+	/// The buffer must have exactly the hash length of the algorithm, which
+	/// can be retrieved with
+	/// [`HBCRYPTALG::BCryptGetProperty`](crate::prelude::crypt_Hbcryptalg::BCryptGetProperty).
+	///
+	/// After this call, the hash object can no longer be used, unless the
+	/// provider was opened with
+	/// [`co::BCRYPT_ALG_FLAG::HASH_REUSABLE`](crate::co::BCRYPT_ALG_FLAG::HASH_REUSABLE).
+	fn BCryptFinishHash(&self, output: &mut [u8]) -> SysResult<()> {
+		ntstatus_to_sysresult(
+			unsafe {
+				ffi::BCryptFinishHash(
+					self.ptr(),
+					output.as_mut_ptr() as _,
+					output.len() as _,
+					0,
+				)
+			},
+		)
+	}
+
+	/// [`BCryptHashData`](https://learn.microsoft.com/en-us/windows/win32/api/bcrypt/nf-bcrypt-bcrypthashdata)
+	/// function.
+	///
+	/// Can be called many times to hash data in chunks. Inputs larger than
+	/// 4 GB – like slices of a [`FileMapped`](crate::FileMapped) – are split
+	/// in multiple calls.
+	fn BCryptHashData(&self, input: &[u8]) -> SysResult<()> {
+		for chunk in input.chunks(u32::MAX as _) {
+			ntstatus_to_sysresult(
+				unsafe {
+					ffi::BCryptHashData(
+						self.ptr(),
+						chunk.as_ptr() as _,
+						chunk.len() as _,
+						0,
+					)
+				},
+			)?;
+		}
+		Ok(())
+	}
+}
@@ -0,0 +1,12 @@
+mod hbcryptalg;
+mod hbcrypthash;
+
+pub mod decl {
+	pub use super::hbcryptalg::HBCRYPTALG;
+	pub use super::hbcrypthash::HBCRYPTHASH;
+}
+
+pub mod traits {
+	pub use super::hbcryptalg::crypt_Hbcryptalg;
+	pub use super::hbcrypthash::crypt_Hbcrypthash;
+}
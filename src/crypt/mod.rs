@@ -0,0 +1,20 @@
+#![cfg(feature = "crypt")]
+
+mod funcs;
+mod handles;
+mod utilities;
+
+pub(in crate::crypt) mod ffi;
+pub(in crate::crypt) mod privs;
+pub mod co;
+pub mod guard;
+
+pub mod decl {
+	pub use super::funcs::*;
+	pub use super::handles::decl::*;
+	pub use super::utilities::*;
+}
+
+pub mod traits {
+	pub use super::handles::traits::*;
+}
@@ -0,0 +1,40 @@
+#![allow(non_snake_case)]
+
+use crate::co;
+use crate::crypt::ffi;
+use crate::decl::*;
+
+pub(crate) const BCRYPT_USE_SYSTEM_PREFERRED_RNG: u32 = 0x0000_0002;
+
+/// [`DATA_BLOB`](https://learn.microsoft.com/en-us/previous-versions/windows/desktop/legacy/aa381414(v=vs.85))
+/// struct, used only internally, since the public API deals with slices.
+#[repr(C)]
+pub(crate) struct DATA_BLOB {
+	pub(crate) cbData: u32,
+	pub(crate) pbData: *mut u8,
+}
+
+impl Default for DATA_BLOB {
+	fn default() -> Self {
+		Self { cbData: 0, pbData: std::ptr::null_mut() }
+	}
+}
+
+impl DATA_BLOB {
+	/// Points to the given slice, which must outlive the struct.
+	pub(crate) fn from_slice(data: &[u8]) -> Self {
+		Self { cbData: data.len() as _, pbData: data.as_ptr() as _ }
+	}
+}
+
+/// If the `NTSTATUS` is a success or an informational code, yields `Ok(())`,
+/// otherwise converts it with
+/// [`RtlNtStatusToDosError`](https://learn.microsoft.com/en-us/windows/win32/api/winternl/nf-winternl-rtlntstatustodoserror)
+/// and yields `Err(err)`.
+pub(crate) fn ntstatus_to_sysresult(status: i32) -> SysResult<()> {
+	if status >= 0 { // NT_SUCCESS
+		Ok(())
+	} else {
+		Err(unsafe { co::ERROR::from_raw(ffi::RtlNtStatusToDosError(status)) })
+	}
+}
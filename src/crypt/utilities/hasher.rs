@@ -0,0 +1,139 @@
+use crate::co;
+use crate::decl::*;
+use crate::guard::*;
+use crate::prelude::*;
+
+/// Computes a hash or an HMAC with a
+/// [CNG](https://learn.microsoft.com/en-us/windows/win32/seccng/cng-portal)
+/// algorithm provider, whose handles are closed automatically when the object
+/// goes out of scope.
+///
+/// Data can be fed at once, or in many chunks, with
+/// [`Hasher::update`](crate::Hasher::update). The `Hasher` also implements
+/// [`std::io::Write`](std::io::Write), so it can be the target of
+/// [`std::io::copy`](std::io::copy).
+///
+/// # Examples
+///
+/// Hashing a file, memory-mapped with [`FileMapped`](crate::FileMapped):
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let f = w::FileMapped::open(
+///     "C:\\Temp\\foo.iso",
+///     w::FileAccess::ExistingReadOnly,
+/// )?;
+///
+/// let mut hasher = w::Hasher::new(co::BCRYPT_ALG::SHA256)?;
+/// hasher.update(f.as_slice())?;
+/// let digest = hasher.finish()?;
+///
+/// let hex = digest.iter()
+///     .map(|b| format!("{:02x}", b))
+///     .collect::<String>();
+/// println!("{}", hex);
+/// # Ok::<_, co::ERROR>(())
+/// ```
+///
+/// Computing an HMAC in a single call:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let mac = w::Hasher::hmac(co::BCRYPT_ALG::SHA512, b"key", b"message")?;
+/// # Ok::<_, co::ERROR>(())
+/// ```
+pub struct Hasher {
+	hhash: BCryptDestroyHashGuard, // must be destroyed before the provider
+	_halg: BCryptCloseAlgorithmProviderGuard,
+	hash_len: usize,
+}
+
+impl std::io::Write for Hasher {
+	fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+		self.update(buf)
+			.map(|_| buf.len())
+			.map_err(|err| std::io::Error::from_raw_os_error(err.raw() as _))
+	}
+
+	fn flush(&mut self) -> std::io::Result<()> {
+		Ok(())
+	}
+}
+
+impl Hasher {
+	/// Opens the algorithm provider and creates a hash object.
+	#[must_use]
+	pub fn new(algorithm: co::BCRYPT_ALG) -> SysResult<Self> {
+		Self::create(algorithm, None)
+	}
+
+	/// Opens the algorithm provider and creates an HMAC object with the given
+	/// key.
+	#[must_use]
+	pub fn new_hmac(algorithm: co::BCRYPT_ALG, key: &[u8]) -> SysResult<Self> {
+		Self::create(algorithm, Some(key))
+	}
+
+	/// Computes the hash of the data in a single call.
+	#[must_use]
+	pub fn hash(algorithm: co::BCRYPT_ALG, data: &[u8]) -> SysResult<Vec<u8>> {
+		let mut hasher = Self::new(algorithm)?;
+		hasher.update(data)?;
+		hasher.finish()
+	}
+
+	/// Computes the HMAC of the data in a single call.
+	#[must_use]
+	pub fn hmac(
+		algorithm: co::BCRYPT_ALG,
+		key: &[u8],
+		data: &[u8],
+	) -> SysResult<Vec<u8>>
+	{
+		let mut hasher = Self::new_hmac(algorithm, key)?;
+		hasher.update(data)?;
+		hasher.finish()
+	}
+
+	/// Returns the length of the resulting hash, in bytes.
+	#[must_use]
+	pub const fn hash_len(&self) -> usize {
+		self.hash_len
+	}
+
+	/// Returns the underlying hash handle.
+	#[must_use]
+	pub fn hhash(&self) -> &HBCRYPTHASH {
+		&self.hhash
+	}
+
+	/// Feeds data into the hash. Can be called many times.
+	pub fn update(&mut self, data: &[u8]) -> SysResult<()> {
+		self.hhash.BCryptHashData(data)
+	}
+
+	/// Finishes the computation and returns the resulting hash.
+	#[must_use]
+	pub fn finish(self) -> SysResult<Vec<u8>> {
+		let mut output = vec![0u8; self.hash_len];
+		self.hhash.BCryptFinishHash(&mut output)
+			.map(|_| output)
+	}
+
+	fn create(algorithm: co::BCRYPT_ALG, key: Option<&[u8]>) -> SysResult<Self> {
+		let halg = HBCRYPTALG::BCryptOpenAlgorithmProvider(
+			algorithm,
+			None,
+			if key.is_some() { co::BCRYPT_ALG_FLAG::HMAC } else { co::BCRYPT_ALG_FLAG::NoValue },
+		)?;
+
+		let mut buf = [0u8; 4];
+		halg.BCryptGetProperty(co::BCRYPT_PROP::HASH_LENGTH, &mut buf)?;
+		let hash_len = u32::from_le_bytes(buf) as usize;
+
+		let hhash = halg.BCryptCreateHash(key)?;
+		Ok(Self { hhash, _halg: halg, hash_len })
+	}
+}
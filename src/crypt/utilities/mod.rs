@@ -0,0 +1,3 @@
+mod hasher;
+
+pub use hasher::Hasher;
@@ -1,4 +1,4 @@
-#![allow(non_camel_case_types)]
+#![allow(non_camel_case_types, non_upper_case_globals)]
 
 const_ordinary! { DWM_SIT: u32;
 	/// [`DwmSetIconicLivePreviewBitmap`](crate::prelude::dwm_Hwnd::DwmSetIconicLivePreviewBitmap)
@@ -7,3 +7,99 @@ const_ordinary! { DWM_SIT: u32;
 	=>
 	DISPLAYFRAME 0x0000_0001
 }
+
+const_bitflag! { DWM_CLOAKED: u32;
+	/// [`DwmAttr::Cloaked`](crate::DwmAttr::Cloaked) reasons (`u32`).
+	=>
+	=>
+	/// None of the actual values (zero).
+	NoValue 0
+	APP 0x0000_0001
+	SHELL 0x0000_0002
+	INHERITED 0x0000_0004
+}
+
+const_bitflag! { DWM_TNP: u32;
+	/// [`DWM_THUMBNAIL_PROPERTIES`](crate::DWM_THUMBNAIL_PROPERTIES) `dwFlags`
+	/// (`u32`).
+	=>
+	=>
+	RECTDESTINATION 0x0000_0001
+	RECTSOURCE 0x0000_0002
+	OPACITY 0x0000_0004
+	VISIBLE 0x0000_0008
+	SOURCECLIENTAREAONLY 0x0000_0010
+}
+
+const_ordinary! { DWMNCRP: u32;
+	/// [`DWMNCRENDERINGPOLICY`](https://learn.microsoft.com/en-us/windows/win32/api/dwmapi/ne-dwmapi-dwmncrenderingpolicy)
+	/// enumeration (`u32`).
+	=>
+	=>
+	USEWINDOWSTYLE 0
+	DISABLED 1
+	ENABLED 2
+}
+
+const_ordinary! { DWMSBT: u32;
+	/// [`DWM_SYSTEMBACKDROP_TYPE`](https://learn.microsoft.com/en-us/windows/win32/api/dwmapi/ne-dwmapi-dwm_systembackdrop_type)
+	/// enumeration (`u32`).
+	=>
+	=>
+	/// Let the system decide.
+	AUTO 0
+	/// No backdrop.
+	NONE 1
+	/// Mica, for long-lived windows.
+	MAINWINDOW 2
+	/// Acrylic, for transient windows, like popups.
+	TRANSIENTWINDOW 3
+	/// Mica Alt, for windows with tabbed title bars.
+	TABBEDWINDOW 4
+}
+
+const_ordinary! { DWMWA: u32;
+	/// [`DWMWINDOWATTRIBUTE`](https://learn.microsoft.com/en-us/windows/win32/api/dwmapi/ne-dwmapi-dwmwindowattribute)
+	/// enumeration (`u32`).
+	=>
+	=>
+	NCRENDERING_ENABLED 1
+	NCRENDERING_POLICY 2
+	TRANSITIONS_FORCEDISABLED 3
+	ALLOW_NCPAINT 4
+	CAPTION_BUTTON_BOUNDS 5
+	NONCLIENT_RTL_LAYOUT 6
+	FORCE_ICONIC_REPRESENTATION 7
+	FLIP3D_POLICY 8
+	EXTENDED_FRAME_BOUNDS 9
+	HAS_ICONIC_BITMAP 10
+	DISALLOW_PEEK 11
+	EXCLUDED_FROM_PEEK 12
+	CLOAK 13
+	CLOAKED 14
+	FREEZE_REPRESENTATION 15
+	PASSIVE_UPDATE_MODE 16
+	USE_HOSTBACKDROPBRUSH 17
+	USE_IMMERSIVE_DARK_MODE 20
+	WINDOW_CORNER_PREFERENCE 33
+	BORDER_COLOR 34
+	CAPTION_COLOR 35
+	TEXT_COLOR 36
+	VISIBLE_FRAME_BORDER_THICKNESS 37
+	SYSTEMBACKDROP_TYPE 38
+}
+
+const_ordinary! { DWMWCP: u32;
+	/// [`DWM_WINDOW_CORNER_PREFERENCE`](https://learn.microsoft.com/en-us/windows/win32/api/dwmapi/ne-dwmapi-dwm_window_corner_preference)
+	/// enumeration (`u32`).
+	=>
+	=>
+	/// Let the system decide.
+	DEFAULT 0
+	/// Never round the corners.
+	DONOTROUND 1
+	/// Round the corners, if appropriate.
+	ROUND 2
+	/// Round the corners with a small radius, if appropriate.
+	ROUNDSMALL 3
+}
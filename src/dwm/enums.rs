@@ -0,0 +1,164 @@
+use crate::co;
+use crate::decl::*;
+
+/// A window attribute, set with
+/// [`HWND::DwmSetWindowAttribute`](crate::prelude::dwm_Hwnd::DwmSetWindowAttribute)
+/// and retrieved with
+/// [`HWND::DwmGetWindowAttribute`](crate::prelude::dwm_Hwnd::DwmGetWindowAttribute).
+///
+/// Originally a [`co::DWMWA`](crate::co::DWMWA) constant plus the data it
+/// carries. Some attributes can only be retrieved, others only set; some
+/// require Windows 11.
+///
+/// Color attributes accept the special values `0xffff_ffff`
+/// (`DWMWA_COLOR_DEFAULT`), to reset the color, and `0xffff_fffe`
+/// (`DWMWA_COLOR_NONE`), to suppress it.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum DwmAttr {
+	/// [`co::DWMWA::ALLOW_NCPAINT`](crate::co::DWMWA::ALLOW_NCPAINT).
+	AllowNcPaint(bool),
+	/// [`co::DWMWA::BORDER_COLOR`](crate::co::DWMWA::BORDER_COLOR).
+	BorderColor(COLORREF),
+	/// [`co::DWMWA::CAPTION_BUTTON_BOUNDS`](crate::co::DWMWA::CAPTION_BUTTON_BOUNDS),
+	/// get only.
+	CaptionButtonBounds(RECT),
+	/// [`co::DWMWA::CAPTION_COLOR`](crate::co::DWMWA::CAPTION_COLOR).
+	CaptionColor(COLORREF),
+	/// [`co::DWMWA::CLOAK`](crate::co::DWMWA::CLOAK), set only.
+	Cloak(bool),
+	/// [`co::DWMWA::CLOAKED`](crate::co::DWMWA::CLOAKED), get only.
+	Cloaked(co::DWM_CLOAKED),
+	/// [`co::DWMWA::DISALLOW_PEEK`](crate::co::DWMWA::DISALLOW_PEEK).
+	DisallowPeek(bool),
+	/// [`co::DWMWA::EXCLUDED_FROM_PEEK`](crate::co::DWMWA::EXCLUDED_FROM_PEEK).
+	ExcludedFromPeek(bool),
+	/// [`co::DWMWA::EXTENDED_FRAME_BOUNDS`](crate::co::DWMWA::EXTENDED_FRAME_BOUNDS),
+	/// get only.
+	ExtendedFrameBounds(RECT),
+	/// [`co::DWMWA::FORCE_ICONIC_REPRESENTATION`](crate::co::DWMWA::FORCE_ICONIC_REPRESENTATION).
+	ForceIconicRepresentation(bool),
+	/// [`co::DWMWA::FREEZE_REPRESENTATION`](crate::co::DWMWA::FREEZE_REPRESENTATION).
+	FreezeRepresentation(bool),
+	/// [`co::DWMWA::HAS_ICONIC_BITMAP`](crate::co::DWMWA::HAS_ICONIC_BITMAP).
+	HasIconicBitmap(bool),
+	/// [`co::DWMWA::NCRENDERING_ENABLED`](crate::co::DWMWA::NCRENDERING_ENABLED),
+	/// get only.
+	NcRenderingEnabled(bool),
+	/// [`co::DWMWA::NCRENDERING_POLICY`](crate::co::DWMWA::NCRENDERING_POLICY).
+	NcRenderingPolicy(co::DWMNCRP),
+	/// [`co::DWMWA::NONCLIENT_RTL_LAYOUT`](crate::co::DWMWA::NONCLIENT_RTL_LAYOUT).
+	NonClientRtlLayout(bool),
+	/// [`co::DWMWA::PASSIVE_UPDATE_MODE`](crate::co::DWMWA::PASSIVE_UPDATE_MODE).
+	PassiveUpdateMode(bool),
+	/// [`co::DWMWA::SYSTEMBACKDROP_TYPE`](crate::co::DWMWA::SYSTEMBACKDROP_TYPE).
+	SystemBackdropType(co::DWMSBT),
+	/// [`co::DWMWA::TEXT_COLOR`](crate::co::DWMWA::TEXT_COLOR).
+	TextColor(COLORREF),
+	/// [`co::DWMWA::TRANSITIONS_FORCEDISABLED`](crate::co::DWMWA::TRANSITIONS_FORCEDISABLED).
+	TransitionsForceDisabled(bool),
+	/// [`co::DWMWA::USE_HOSTBACKDROPBRUSH`](crate::co::DWMWA::USE_HOSTBACKDROPBRUSH).
+	UseHostBackdropBrush(bool),
+	/// [`co::DWMWA::USE_IMMERSIVE_DARK_MODE`](crate::co::DWMWA::USE_IMMERSIVE_DARK_MODE).
+	UseImmersiveDarkMode(bool),
+	/// [`co::DWMWA::VISIBLE_FRAME_BORDER_THICKNESS`](crate::co::DWMWA::VISIBLE_FRAME_BORDER_THICKNESS),
+	/// get only.
+	VisibleFrameBorderThickness(u32),
+	/// [`co::DWMWA::WINDOW_CORNER_PREFERENCE`](crate::co::DWMWA::WINDOW_CORNER_PREFERENCE).
+	WindowCornerPreference(co::DWMWCP),
+}
+
+impl DwmAttr {
+	/// Returns the attribute identifier.
+	#[must_use]
+	pub const fn attr(&self) -> co::DWMWA {
+		match self {
+			Self::AllowNcPaint(_) => co::DWMWA::ALLOW_NCPAINT,
+			Self::BorderColor(_) => co::DWMWA::BORDER_COLOR,
+			Self::CaptionButtonBounds(_) => co::DWMWA::CAPTION_BUTTON_BOUNDS,
+			Self::CaptionColor(_) => co::DWMWA::CAPTION_COLOR,
+			Self::Cloak(_) => co::DWMWA::CLOAK,
+			Self::Cloaked(_) => co::DWMWA::CLOAKED,
+			Self::DisallowPeek(_) => co::DWMWA::DISALLOW_PEEK,
+			Self::ExcludedFromPeek(_) => co::DWMWA::EXCLUDED_FROM_PEEK,
+			Self::ExtendedFrameBounds(_) => co::DWMWA::EXTENDED_FRAME_BOUNDS,
+			Self::ForceIconicRepresentation(_) => co::DWMWA::FORCE_ICONIC_REPRESENTATION,
+			Self::FreezeRepresentation(_) => co::DWMWA::FREEZE_REPRESENTATION,
+			Self::HasIconicBitmap(_) => co::DWMWA::HAS_ICONIC_BITMAP,
+			Self::NcRenderingEnabled(_) => co::DWMWA::NCRENDERING_ENABLED,
+			Self::NcRenderingPolicy(_) => co::DWMWA::NCRENDERING_POLICY,
+			Self::NonClientRtlLayout(_) => co::DWMWA::NONCLIENT_RTL_LAYOUT,
+			Self::PassiveUpdateMode(_) => co::DWMWA::PASSIVE_UPDATE_MODE,
+			Self::SystemBackdropType(_) => co::DWMWA::SYSTEMBACKDROP_TYPE,
+			Self::TextColor(_) => co::DWMWA::TEXT_COLOR,
+			Self::TransitionsForceDisabled(_) => co::DWMWA::TRANSITIONS_FORCEDISABLED,
+			Self::UseHostBackdropBrush(_) => co::DWMWA::USE_HOSTBACKDROPBRUSH,
+			Self::UseImmersiveDarkMode(_) => co::DWMWA::USE_IMMERSIVE_DARK_MODE,
+			Self::VisibleFrameBorderThickness(_) => co::DWMWA::VISIBLE_FRAME_BORDER_THICKNESS,
+			Self::WindowCornerPreference(_) => co::DWMWA::WINDOW_CORNER_PREFERENCE,
+		}
+	}
+
+	/// Returns the raw value, either a 4-byte integer or a `RECT`.
+	pub(in crate::dwm) fn as_raw(&self) -> (u32, Option<RECT>) {
+		match *self {
+			Self::CaptionButtonBounds(rc) | Self::ExtendedFrameBounds(rc) => (0, Some(rc)),
+			Self::AllowNcPaint(b)
+				| Self::Cloak(b)
+				| Self::DisallowPeek(b)
+				| Self::ExcludedFromPeek(b)
+				| Self::ForceIconicRepresentation(b)
+				| Self::FreezeRepresentation(b)
+				| Self::HasIconicBitmap(b)
+				| Self::NcRenderingEnabled(b)
+				| Self::NonClientRtlLayout(b)
+				| Self::PassiveUpdateMode(b)
+				| Self::TransitionsForceDisabled(b)
+				| Self::UseHostBackdropBrush(b)
+				| Self::UseImmersiveDarkMode(b) => (b as _, None),
+			Self::BorderColor(c) | Self::CaptionColor(c) | Self::TextColor(c) => (c.into(), None),
+			Self::Cloaked(c) => (c.raw(), None),
+			Self::NcRenderingPolicy(p) => (p.raw(), None),
+			Self::SystemBackdropType(t) => (t.raw(), None),
+			Self::VisibleFrameBorderThickness(n) => (n, None),
+			Self::WindowCornerPreference(p) => (p.raw(), None),
+		}
+	}
+
+	/// Builds the attribute from its raw value, or returns `None` if the
+	/// attribute is not supported.
+	pub(in crate::dwm) fn from_raw(
+		attr: co::DWMWA,
+		val: u32,
+		rc: RECT,
+	) -> Option<Self>
+	{
+		Some(unsafe {
+			match attr {
+				co::DWMWA::ALLOW_NCPAINT => Self::AllowNcPaint(val != 0),
+				co::DWMWA::BORDER_COLOR => Self::BorderColor(COLORREF::from_raw(val)),
+				co::DWMWA::CAPTION_BUTTON_BOUNDS => Self::CaptionButtonBounds(rc),
+				co::DWMWA::CAPTION_COLOR => Self::CaptionColor(COLORREF::from_raw(val)),
+				co::DWMWA::CLOAK => Self::Cloak(val != 0),
+				co::DWMWA::CLOAKED => Self::Cloaked(co::DWM_CLOAKED::from_raw(val)),
+				co::DWMWA::DISALLOW_PEEK => Self::DisallowPeek(val != 0),
+				co::DWMWA::EXCLUDED_FROM_PEEK => Self::ExcludedFromPeek(val != 0),
+				co::DWMWA::EXTENDED_FRAME_BOUNDS => Self::ExtendedFrameBounds(rc),
+				co::DWMWA::FORCE_ICONIC_REPRESENTATION => Self::ForceIconicRepresentation(val != 0),
+				co::DWMWA::FREEZE_REPRESENTATION => Self::FreezeRepresentation(val != 0),
+				co::DWMWA::HAS_ICONIC_BITMAP => Self::HasIconicBitmap(val != 0),
+				co::DWMWA::NCRENDERING_ENABLED => Self::NcRenderingEnabled(val != 0),
+				co::DWMWA::NCRENDERING_POLICY => Self::NcRenderingPolicy(co::DWMNCRP::from_raw(val)),
+				co::DWMWA::NONCLIENT_RTL_LAYOUT => Self::NonClientRtlLayout(val != 0),
+				co::DWMWA::PASSIVE_UPDATE_MODE => Self::PassiveUpdateMode(val != 0),
+				co::DWMWA::SYSTEMBACKDROP_TYPE => Self::SystemBackdropType(co::DWMSBT::from_raw(val)),
+				co::DWMWA::TEXT_COLOR => Self::TextColor(COLORREF::from_raw(val)),
+				co::DWMWA::TRANSITIONS_FORCEDISABLED => Self::TransitionsForceDisabled(val != 0),
+				co::DWMWA::USE_HOSTBACKDROPBRUSH => Self::UseHostBackdropBrush(val != 0),
+				co::DWMWA::USE_IMMERSIVE_DARK_MODE => Self::UseImmersiveDarkMode(val != 0),
+				co::DWMWA::VISIBLE_FRAME_BORDER_THICKNESS => Self::VisibleFrameBorderThickness(val),
+				co::DWMWA::WINDOW_CORNER_PREFERENCE => Self::WindowCornerPreference(co::DWMWCP::from_raw(val)),
+				_ => return None,
+			}
+		})
+	}
+}
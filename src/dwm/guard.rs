@@ -0,0 +1,11 @@
+use crate::decl::*;
+use crate::dwm::ffi;
+use crate::prelude::*;
+
+handle_guard! { DwmUnregisterThumbnailGuard: HTHUMBNAIL;
+	ffi::DwmUnregisterThumbnail;
+	/// RAII implementation for [`HTHUMBNAIL`](crate::HTHUMBNAIL) which
+	/// automatically calls
+	/// [`DwmUnregisterThumbnail`](https://learn.microsoft.com/en-us/windows/win32/api/dwmapi/nf-dwmapi-dwmunregisterthumbnail)
+	/// when the object goes out of scope.
+}
@@ -0,0 +1,55 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::decl::*;
+use crate::dwm::ffi;
+use crate::ole::privs::*;
+use crate::prelude::*;
+
+impl_handle! { HTHUMBNAIL;
+	/// Handle to a
+	/// [DWM thumbnail](https://learn.microsoft.com/en-us/windows/win32/dwm/thumbnail-ovw).
+	///
+	/// Originally just a `HANDLE`.
+}
+
+impl dwm_Hthumbnail for HTHUMBNAIL {}
+
+/// This trait is enabled with the `dwm` feature, and provides methods for
+/// [`HTHUMBNAIL`](crate::HTHUMBNAIL).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait dwm_Hthumbnail: Handle {
+	/// [`DwmQueryThumbnailSourceSize`](https://learn.microsoft.com/en-us/windows/win32/api/dwmapi/nf-dwmapi-dwmquerythumbnailsourcesize)
+	/// function.
+	#[must_use]
+	fn DwmQueryThumbnailSourceSize(&self) -> HrResult<SIZE> {
+		let mut sz = SIZE::default();
+		ok_to_hrresult(
+			unsafe {
+				ffi::DwmQueryThumbnailSourceSize(self.ptr(), &mut sz as *mut _ as _)
+			},
+		).map(|_| sz)
+	}
+
+	/// [`DwmUpdateThumbnailProperties`](https://learn.microsoft.com/en-us/windows/win32/api/dwmapi/nf-dwmapi-dwmupdatethumbnailproperties)
+	/// function.
+	///
+	/// Only the properties flagged in `dwFlags` are changed.
+	fn DwmUpdateThumbnailProperties(&self,
+		props: &DWM_THUMBNAIL_PROPERTIES,
+	) -> HrResult<()>
+	{
+		ok_to_hrresult(
+			unsafe {
+				ffi::DwmUpdateThumbnailProperties(
+					self.ptr(),
+					props as *const _ as _,
+				)
+			},
+		)
+	}
+}
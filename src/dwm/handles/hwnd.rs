@@ -3,6 +3,7 @@
 use crate::co;
 use crate::decl::*;
 use crate::dwm::ffi;
+use crate::guard::*;
 use crate::ole::privs::*;
 use crate::prelude::*;
 
@@ -33,6 +34,54 @@ pub trait dwm_Hwnd: uxtheme_Hwnd {
 		)
 	}
 
+	/// [`DwmGetWindowAttribute`](https://learn.microsoft.com/en-us/windows/win32/api/dwmapi/nf-dwmapi-dwmgetwindowattribute)
+	/// function.
+	///
+	/// Returns
+	/// [`co::HRESULT::E_INVALIDARG`](crate::co::HRESULT::E_INVALIDARG) if the
+	/// attribute is not supported.
+	///
+	/// # Examples
+	///
+	/// Retrieving the window bounds without the invisible resize borders:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let hwnd: w::HWND; // initialized somewhere
+	/// # let hwnd = w::HWND::NULL;
+	///
+	/// if let w::DwmAttr::ExtendedFrameBounds(rc) =
+	///     hwnd.DwmGetWindowAttribute(co::DWMWA::EXTENDED_FRAME_BOUNDS)?
+	/// {
+	///     println!("{}", rc);
+	/// }
+	/// # Ok::<_, co::HRESULT>(())
+	/// ```
+	#[must_use]
+	fn DwmGetWindowAttribute(&self, attr: co::DWMWA) -> HrResult<DwmAttr> {
+		let mut rc = RECT::default(); // largest possible value
+		let sz = match attr {
+			co::DWMWA::CAPTION_BUTTON_BOUNDS
+				| co::DWMWA::EXTENDED_FRAME_BOUNDS => std::mem::size_of::<RECT>(),
+			_ => std::mem::size_of::<u32>(),
+		};
+
+		ok_to_hrresult(
+			unsafe {
+				ffi::DwmGetWindowAttribute(
+					self.ptr(),
+					attr.raw(),
+					&mut rc as *mut _ as _,
+					sz as _,
+				)
+			},
+		)?;
+
+		DwmAttr::from_raw(attr, rc.left as _, rc) // 4-byte values are in the first field
+			.ok_or(co::HRESULT::E_INVALIDARG)
+	}
+
 	/// [`DwmInvalidateIconicBitmaps`](https://learn.microsoft.com/en-us/windows/win32/api/dwmapi/nf-dwmapi-dwminvalidateiconicbitmaps)
 	/// function.
 	fn DwmInvalidateIconicBitmaps(&self) -> HrResult<()> {
@@ -41,6 +90,53 @@ pub trait dwm_Hwnd: uxtheme_Hwnd {
 		)
 	}
 
+	/// [`DwmRegisterThumbnail`](https://learn.microsoft.com/en-us/windows/win32/api/dwmapi/nf-dwmapi-dwmregisterthumbnail)
+	/// function.
+	///
+	/// Renders a live thumbnail of `hwnd_source` into this window, which must
+	/// be a top-level window. The thumbnail is invisible until its properties
+	/// are set with
+	/// [`HTHUMBNAIL::DwmUpdateThumbnailProperties`](crate::prelude::dwm_Hthumbnail::DwmUpdateThumbnailProperties).
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let hwnd_dest: w::HWND; // initialized somewhere
+	/// # let hwnd_dest = w::HWND::NULL;
+	/// let hwnd_src: w::HWND;
+	/// # let hwnd_src = w::HWND::NULL;
+	///
+	/// let hthumb = hwnd_dest.DwmRegisterThumbnail(&hwnd_src)?;
+	/// let sz = hthumb.DwmQueryThumbnailSourceSize()?;
+	///
+	/// hthumb.DwmUpdateThumbnailProperties(
+	///     &w::DWM_THUMBNAIL_PROPERTIES::default()
+	///         .destination(w::RECT { left: 0, top: 0, right: sz.cx / 4, bottom: sz.cy / 4 })
+	///         .visible(true),
+	/// )?;
+	///
+	/// // DwmUnregisterThumbnail() automatically called
+	/// # Ok::<_, winsafe::co::HRESULT>(())
+	/// ```
+	#[must_use]
+	fn DwmRegisterThumbnail(&self,
+		hwnd_source: &HWND,
+	) -> HrResult<DwmUnregisterThumbnailGuard>
+	{
+		let mut hthumb = HTHUMBNAIL::NULL;
+		unsafe {
+			ok_to_hrresult(
+				ffi::DwmRegisterThumbnail(
+					self.ptr(),
+					hwnd_source.ptr(),
+					hthumb.as_mut(),
+				),
+			).map(|_| DwmUnregisterThumbnailGuard::new(hthumb))
+		}
+	}
+
 	/// [`DwmSetIconicLivePreviewBitmap`](https://learn.microsoft.com/en-us/windows/win32/api/dwmapi/nf-dwmapi-dwmseticoniclivepreviewbitmap)
 	/// function.
 	fn DwmSetIconicLivePreviewBitmap(&self,
@@ -78,4 +174,36 @@ pub trait dwm_Hwnd: uxtheme_Hwnd {
 			},
 		)
 	}
+
+	/// [`DwmSetWindowAttribute`](https://learn.microsoft.com/en-us/windows/win32/api/dwmapi/nf-dwmapi-dwmsetwindowattribute)
+	/// function.
+	///
+	/// # Examples
+	///
+	/// Dark title bar with Mica backdrop, on Windows 11:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let hwnd: w::HWND; // initialized somewhere
+	/// # let hwnd = w::HWND::NULL;
+	///
+	/// hwnd.DwmSetWindowAttribute(w::DwmAttr::UseImmersiveDarkMode(true))?;
+	/// hwnd.DwmSetWindowAttribute(
+	///     w::DwmAttr::SystemBackdropType(co::DWMSBT::MAINWINDOW))?;
+	/// # Ok::<_, co::HRESULT>(())
+	/// ```
+	fn DwmSetWindowAttribute(&self, attr: DwmAttr) -> HrResult<()> {
+		let (val, rc) = attr.as_raw();
+		let (ptr, sz) = match &rc {
+			Some(rc) => (rc as *const _ as *const std::ffi::c_void, std::mem::size_of::<RECT>()),
+			None => (&val as *const _ as _, std::mem::size_of::<u32>()),
+		};
+
+		ok_to_hrresult(
+			unsafe {
+				ffi::DwmSetWindowAttribute(self.ptr(), attr.attr().raw(), ptr, sz as _)
+			},
+		)
+	}
 }
@@ -1,5 +1,11 @@
+mod hthumbnail;
 mod hwnd;
 
+pub mod decl {
+	pub use super::hthumbnail::HTHUMBNAIL;
+}
+
 pub mod traits {
+	pub use super::hthumbnail::dwm_Hthumbnail;
 	pub use super::hwnd::dwm_Hwnd;
 }
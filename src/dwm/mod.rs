@@ -1,13 +1,19 @@
 #![cfg(feature = "dwm")]
 
+mod enums;
 mod funcs;
 mod handles;
+mod structs;
 
 pub(in crate::dwm) mod ffi;
 pub mod co;
+pub mod guard;
 
 pub mod decl {
+	pub use super::enums::*;
 	pub use super::funcs::*;
+	pub use super::handles::decl::*;
+	pub use super::structs::*;
 }
 
 pub mod traits {
@@ -0,0 +1,88 @@
+#![allow(non_snake_case)]
+
+use crate::co;
+use crate::decl::*;
+use crate::kernel::ffi_types::*;
+
+/// [`DWM_THUMBNAIL_PROPERTIES`](https://learn.microsoft.com/en-us/windows/win32/api/dwmapi/ns-dwmapi-dwm_thumbnail_properties)
+/// struct.
+///
+/// The builder methods set the value and its flag in `dwFlags`, so only the
+/// given properties are changed by
+/// [`HTHUMBNAIL::DwmUpdateThumbnailProperties`](crate::prelude::dwm_Hthumbnail::DwmUpdateThumbnailProperties).
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let hthumb: w::HTHUMBNAIL; // initialized somewhere
+/// # let hthumb = w::HTHUMBNAIL::NULL;
+///
+/// hthumb.DwmUpdateThumbnailProperties(
+///     &w::DWM_THUMBNAIL_PROPERTIES::default()
+///         .destination(w::RECT { left: 10, top: 10, right: 210, bottom: 160 })
+///         .opacity(200)
+///         .visible(true),
+/// )?;
+/// # Ok::<_, winsafe::co::HRESULT>(())
+/// ```
+#[repr(C)]
+#[derive(Default, Clone, Copy)]
+pub struct DWM_THUMBNAIL_PROPERTIES {
+	pub dwFlags: co::DWM_TNP,
+	pub rcDestination: RECT,
+	pub rcSource: RECT,
+	pub opacity: u8,
+	fVisible: BOOL,
+	fSourceClientAreaOnly: BOOL,
+}
+
+impl DWM_THUMBNAIL_PROPERTIES {
+	pub_fn_bool_get_set!(fVisible, set_fVisible);
+	pub_fn_bool_get_set!(fSourceClientAreaOnly, set_fSourceClientAreaOnly);
+
+	/// Sets the area of the destination window where the thumbnail is
+	/// rendered, in client coordinates, and its flag.
+	#[must_use]
+	pub fn destination(mut self, rc: RECT) -> Self {
+		self.rcDestination = rc;
+		self.dwFlags |= co::DWM_TNP::RECTDESTINATION;
+		self
+	}
+
+	/// Sets the area of the source window to be shown, and its flag. By
+	/// default, the whole window is shown.
+	#[must_use]
+	pub fn source(mut self, rc: RECT) -> Self {
+		self.rcSource = rc;
+		self.dwFlags |= co::DWM_TNP::RECTSOURCE;
+		self
+	}
+
+	/// Sets the opacity, from `0` (transparent) to `255` (opaque), and its
+	/// flag.
+	#[must_use]
+	pub fn opacity(mut self, opacity: u8) -> Self {
+		self.opacity = opacity;
+		self.dwFlags |= co::DWM_TNP::OPACITY;
+		self
+	}
+
+	/// Sets whether the thumbnail is visible, and its flag.
+	#[must_use]
+	pub fn visible(mut self, visible: bool) -> Self {
+		self.fVisible = visible as _;
+		self.dwFlags |= co::DWM_TNP::VISIBLE;
+		self
+	}
+
+	/// Sets whether only the client area of the source window is shown, and
+	/// its flag.
+	#[must_use]
+	pub fn source_client_area_only(mut self, client_only: bool) -> Self {
+		self.fSourceClientAreaOnly = client_only as _;
+		self.dwFlags |= co::DWM_TNP::SOURCECLIENTAREAONLY;
+		self
+	}
+}
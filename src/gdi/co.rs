@@ -102,6 +102,133 @@ const_ordinary! { DIB: u32;
 	PAL_COLORS 1
 }
 
+const_ordinary! { EMR: u32;
+	/// Enhanced metafile
+	/// [record types](https://learn.microsoft.com/en-us/openspecs/windows_protocols/ms-emf/1eec80ba-799b-4784-a9ac-91597d590ae1)
+	/// (`u32`).
+	=>
+	=>
+	HEADER 1
+	POLYBEZIER 2
+	POLYGON 3
+	POLYLINE 4
+	POLYBEZIERTO 5
+	POLYLINETO 6
+	POLYPOLYLINE 7
+	POLYPOLYGON 8
+	SETWINDOWEXTEX 9
+	SETWINDOWORGEX 10
+	SETVIEWPORTEXTEX 11
+	SETVIEWPORTORGEX 12
+	SETBRUSHORGEX 13
+	EOF 14
+	SETPIXELV 15
+	SETMAPPERFLAGS 16
+	SETMAPMODE 17
+	SETBKMODE 18
+	SETPOLYFILLMODE 19
+	SETROP2 20
+	SETSTRETCHBLTMODE 21
+	SETTEXTALIGN 22
+	SETCOLORADJUSTMENT 23
+	SETTEXTCOLOR 24
+	SETBKCOLOR 25
+	OFFSETCLIPRGN 26
+	MOVETOEX 27
+	SETMETARGN 28
+	EXCLUDECLIPRECT 29
+	INTERSECTCLIPRECT 30
+	SCALEVIEWPORTEXTEX 31
+	SCALEWINDOWEXTEX 32
+	SAVEDC 33
+	RESTOREDC 34
+	SETWORLDTRANSFORM 35
+	MODIFYWORLDTRANSFORM 36
+	SELECTOBJECT 37
+	CREATEPEN 38
+	CREATEBRUSHINDIRECT 39
+	DELETEOBJECT 40
+	ANGLEARC 41
+	ELLIPSE 42
+	RECTANGLE 43
+	ROUNDRECT 44
+	ARC 45
+	CHORD 46
+	PIE 47
+	SELECTPALETTE 48
+	CREATEPALETTE 49
+	SETPALETTEENTRIES 50
+	RESIZEPALETTE 51
+	REALIZEPALETTE 52
+	EXTFLOODFILL 53
+	LINETO 54
+	ARCTO 55
+	POLYDRAW 56
+	SETARCDIRECTION 57
+	SETMITERLIMIT 58
+	BEGINPATH 59
+	ENDPATH 60
+	CLOSEFIGURE 61
+	FILLPATH 62
+	STROKEANDFILLPATH 63
+	STROKEPATH 64
+	FLATTENPATH 65
+	WIDENPATH 66
+	SELECTCLIPPATH 67
+	ABORTPATH 68
+	GDICOMMENT 70
+	FILLRGN 71
+	FRAMERGN 72
+	INVERTRGN 73
+	PAINTRGN 74
+	EXTSELECTCLIPRGN 75
+	BITBLT 76
+	STRETCHBLT 77
+	MASKBLT 78
+	PLGBLT 79
+	SETDIBITSTODEVICE 80
+	STRETCHDIBITS 81
+	EXTCREATEFONTINDIRECTW 82
+	EXTTEXTOUTA 83
+	EXTTEXTOUTW 84
+	POLYBEZIER16 85
+	POLYGON16 86
+	POLYLINE16 87
+	POLYBEZIERTO16 88
+	POLYLINETO16 89
+	POLYPOLYLINE16 90
+	POLYPOLYGON16 91
+	POLYDRAW16 92
+	CREATEMONOBRUSH 93
+	CREATEDIBPATTERNBRUSHPT 94
+	EXTCREATEPEN 95
+	POLYTEXTOUTA 96
+	POLYTEXTOUTW 97
+	SETICMMODE 98
+	CREATECOLORSPACE 99
+	SETCOLORSPACE 100
+	DELETECOLORSPACE 101
+	GLSRECORD 102
+	GLSBOUNDEDRECORD 103
+	PIXELFORMAT 104
+	DRAWESCAPE 105
+	EXTESCAPE 106
+	SMALLTEXTOUT 108
+	FORCEUFIMAPPING 109
+	NAMEDESCAPE 110
+	COLORCORRECTPALETTE 111
+	SETICMPROFILEA 112
+	SETICMPROFILEW 113
+	ALPHABLEND 114
+	SETLAYOUT 115
+	TRANSPARENTBLT 116
+	GRADIENTFILL 118
+	SETLINKEDUFIS 119
+	SETTEXTJUSTIFICATION 120
+	COLORMATCHTOTARGETW 121
+	CREATECOLORSPACEW 122
+}
+
 const_ordinary! { FF: u8;
 	/// [`LOGFONT`](crate::LOGFONT) `lfPitchAndFamily` (`u8`) used with
 	/// [`PITCH`](crate::co::PITCH).
@@ -115,6 +242,29 @@ const_ordinary! { FF: u8;
 	DECORATIVE 5 << 4
 }
 
+const_bitflag! { FONTTYPE: u32;
+	/// [`HDC::EnumFontFamiliesEx`](crate::prelude::gdi_Hdc::EnumFontFamiliesEx)
+	/// callback font type (`u32`).
+	///
+	/// Originally has `FONTTYPE` suffix.
+	=>
+	=>
+	RASTER 0x0001
+	DEVICE 0x0002
+	TRUETYPE 0x0004
+}
+
+const_bitflag! { FR: u32;
+	/// [`AddFontResourceEx`](crate::AddFontResourceEx) and
+	/// [`RemoveFontResourceEx`](crate::RemoveFontResourceEx) `flags` (`u32`).
+	=>
+	=>
+	/// The font is available only to this process, and it's not enumerated.
+	PRIVATE 0x10
+	/// The font is not enumerated.
+	NOT_ENUM 0x20
+}
+
 const_ordinary! { FW: u32;
 	/// [`HFONT::CreateFont`](crate::prelude::gdi_Hfont::CreateFont) `weight`
 	/// and [`LOGFONT`](crate::LOGFONT) `lfWeight` (`u32`).
@@ -214,6 +364,30 @@ const_ordinary! { HS: i32;
 	DIAGCROSS 5
 }
 
+const_ordinary! { LCS: u32;
+	/// [`BITMAPV4HEADER`](crate::BITMAPV4HEADER) `bV4CSType` and
+	/// [`BITMAPV5HEADER`](crate::BITMAPV5HEADER) `bV5CSType` (`u32`).
+	///
+	/// Also includes constants with `PROFILE` prefix.
+	=>
+	=>
+	CALIBRATED_RGB 0x0000_0000
+	sRGB 0x7352_4742
+	WINDOWS_COLOR_SPACE 0x5769_6e20
+	PROFILE_LINKED 0x4c49_4e4b
+	PROFILE_EMBEDDED 0x4d42_4544
+}
+
+const_ordinary! { LCS_GM: u32;
+	/// [`BITMAPV5HEADER`](crate::BITMAPV5HEADER) `bV5Intent` (`u32`).
+	=>
+	=>
+	BUSINESS 0x0000_0001
+	GRAPHICS 0x0000_0002
+	IMAGES 0x0000_0004
+	ABS_COLORIMETRIC 0x0000_0008
+}
+
 const_bitflag! { LR: u32;
 	/// [`HINSTANCE::LoadImageBitmap`](crate::prelude::gdi_Hinstance::LoadImageBitmap),
 	/// [`HINSTANCE::LoadImageCursor`](crate::prelude::gdi_Hinstance::LoadImageCursor)
@@ -237,6 +411,21 @@ const_bitflag! { LR: u32;
 	SHARED 0x0000_8000
 }
 
+const_bitflag! { NTM: u32;
+	/// [`NEWTEXTMETRIC`](crate::NEWTEXTMETRIC) `ntmFlags` (`u32`).
+	=>
+	=>
+	ITALIC 0x0000_0001
+	BOLD 0x0000_0020
+	REGULAR 0x0000_0040
+	NONNEGATIVE_AC 0x0001_0000
+	PS_OPENTYPE 0x0002_0000
+	TT_OPENTYPE 0x0004_0000
+	MULTIPLEMASTER 0x0008_0000
+	TYPE1 0x0010_0000
+	DSIG 0x0020_0000
+}
+
 const_ordinary! { OUT_PRECIS: u8;
 	/// [`HFONT::CreateFont`](crate::prelude::gdi_Hfont::CreateFont)
 	/// `out_precision` and [`LOGFONT`](crate::LOGFONT) `lfOutPrecision` (`u8`).
@@ -284,6 +473,17 @@ impl PITCH {
 	}
 }
 
+const_ordinary! { POLYFILL: i32;
+	/// [`HRGN::CreatePolygonRgn`](crate::prelude::gdi_Hrgn::CreatePolygonRgn)
+	/// `mode` (`i32`).
+	///
+	/// Originally has no prefix.
+	=>
+	=>
+	ALTERNATE 1
+	WINDING 2
+}
+
 const_ordinary! { PS: i32;
 	/// [`HPEN::CreatePen`](crate::prelude::gdi_Hpen::CreatePen) `style`
 	/// (`i32`).
@@ -313,7 +513,8 @@ const_ordinary! { QUALITY: u8;
 }
 
 const_ordinary! { RGN: i32;
-	/// [`HDC::SelectClipPath`](crate::prelude::gdi_Hdc::SelectClipPath) `mode`
+	/// [`HDC::SelectClipPath`](crate::prelude::gdi_Hdc::SelectClipPath) and
+	/// [`HRGN::CombineRgn`](crate::prelude::gdi_Hrgn::CombineRgn) `mode`
 	/// (`i32`).
 	=>
 	=>
@@ -2,6 +2,8 @@ use crate::kernel::ffi_types::*;
 
 extern_sys! { "gdi32";
 	AbortPath(HANDLE) -> BOOL
+	AddFontMemResourceEx(PCVOID, u32, PVOID, *mut u32) -> HANDLE
+	AddFontResourceExW(PCSTR, u32, PVOID) -> i32
 	AngleArc(HANDLE, i32, i32, u32, f32, f32) -> BOOL
 	Arc(HANDLE, i32, i32, i32, i32, i32, i32, i32, i32) -> BOOL
 	ArcTo(HANDLE, i32, i32, i32, i32, i32, i32, i32, i32) -> BOOL
@@ -9,11 +11,15 @@ extern_sys! { "gdi32";
 	BitBlt(HANDLE, i32, i32, i32, i32, HANDLE, i32, i32, u32) -> BOOL
 	CancelDC(HANDLE) -> BOOL
 	Chord(HANDLE, i32, i32, i32, i32, i32, i32, i32, i32) -> BOOL
+	CloseEnhMetaFile(HANDLE) -> HANDLE
 	CloseFigure(HANDLE) -> BOOL
+	CombineRgn(HANDLE, HANDLE, HANDLE, i32) -> i32
 	CreateBitmap(i32, i32, u32, u32, PVOID) -> HANDLE
 	CreateBrushIndirect(PCVOID) -> HANDLE
 	CreateCompatibleBitmap(HANDLE, i32, i32) -> HANDLE
 	CreateCompatibleDC(HANDLE) -> HANDLE
+	CreateDIBSection(HANDLE, PCVOID, u32, *mut PVOID, HANDLE, u32) -> HANDLE
+	CreateEnhMetaFileW(HANDLE, PCSTR, PCVOID, PCSTR) -> HANDLE
 	CreateFontIndirectW(PCVOID) -> HANDLE
 	CreateFontW(i32, i32, i32, i32, i32, u32, u32, u32, u32, u32, u32, u32, u32, PCSTR) -> HANDLE
 	CreateHalftonePalette(HANDLE) -> HANDLE
@@ -22,14 +28,19 @@ extern_sys! { "gdi32";
 	CreatePatternBrush(HANDLE) -> HANDLE
 	CreatePen(i32, i32, u32) -> HANDLE
 	CreatePenIndirect(PCVOID) -> HANDLE
+	CreatePolygonRgn(PCVOID, i32, i32) -> HANDLE
 	CreateRectRgn(i32, i32, i32, i32) -> HANDLE
 	CreateRectRgnIndirect(PVOID) -> HANDLE
 	CreateRoundRectRgn(i32, i32, i32, i32, i32, i32) -> HANDLE
 	CreateSolidBrush(u32) -> HANDLE
 	DeleteDC(HANDLE) -> BOOL
+	DeleteEnhMetaFile(HANDLE) -> BOOL
 	DeleteObject(HANDLE) -> BOOL
 	Ellipse(HANDLE, i32, i32, i32, i32) -> BOOL
 	EndPath(HANDLE) -> BOOL
+	EnumEnhMetaFile(HANDLE, HANDLE, PFUNC, PVOID, PCVOID) -> BOOL
+	EnumFontFamiliesExW(HANDLE, PCVOID, PFUNC, isize, u32) -> i32
+	EqualRgn(HANDLE, HANDLE) -> BOOL
 	FillPath(HANDLE) -> BOOL
 	FillRect(HANDLE, PCVOID, HANDLE) -> i32
 	FillRgn(HANDLE, HANDLE, HANDLE) -> BOOL
@@ -44,11 +55,17 @@ extern_sys! { "gdi32";
 	GetDCPenColor(HANDLE) -> u32
 	GetDeviceCaps(HANDLE, i32) -> i32
 	GetDIBits(HANDLE, HANDLE, u32, u32, PVOID, PVOID, u32) -> i32
+	GetEnhMetaFileBits(HANDLE, u32, PVOID) -> u32
+	GetEnhMetaFileHeader(HANDLE, u32, PVOID) -> u32
+	GetEnhMetaFileW(PCSTR) -> HANDLE
 	GetObjectW(HANDLE, i32, PVOID) -> i32
+	GetRegionData(HANDLE, u32, PVOID) -> u32
+	GetRgnBox(HANDLE, PVOID) -> i32
 	GetStockObject(i32) -> HANDLE
 	GetStretchBltMode(HANDLE) -> i32
 	GetSysColorBrush(i32) -> HANDLE
 	GetTextColor(HANDLE) -> u32
+	GetTextExtentExPointW(HANDLE, PCSTR, i32, i32, *mut i32, *mut i32, PVOID) -> BOOL
 	GetTextExtentPoint32W(HANDLE, PCSTR, i32, PVOID) -> BOOL
 	GetTextFaceW(HANDLE, i32, PSTR) -> i32
 	GetTextMetricsW(HANDLE, PVOID) -> BOOL
@@ -63,6 +80,7 @@ extern_sys! { "gdi32";
 	PatBlt(HANDLE, i32, i32, i32, i32, u32) -> BOOL
 	PathToRegion(HANDLE) -> HANDLE
 	Pie(HANDLE, i32, i32, i32, i32, i32, i32, i32, i32) -> BOOL
+	PlayEnhMetaFile(HANDLE, HANDLE, PCVOID) -> BOOL
 	PolyBezier(HANDLE, PCVOID, u32) -> BOOL
 	PolyBezierTo(HANDLE, PCVOID, u32) -> BOOL
 	Polyline(HANDLE, PCVOID, u32) -> BOOL
@@ -72,6 +90,8 @@ extern_sys! { "gdi32";
 	RealizePalette(HANDLE) -> u32
 	Rectangle(HANDLE, i32, i32, i32, i32) -> BOOL
 	RectInRegion(HANDLE, PCVOID) -> BOOL
+	RemoveFontMemResourceEx(HANDLE) -> BOOL
+	RemoveFontResourceExW(PCSTR, u32, PVOID) -> BOOL
 	RestoreDC(HANDLE, i32) -> BOOL
 	RoundRect(HANDLE, i32, i32, i32, i32, i32, i32) -> BOOL
 	SaveDC(HANDLE) -> i32
@@ -86,6 +106,7 @@ extern_sys! { "gdi32";
 	SetDCBrushColor(HANDLE, u32) -> u32
 	SetDCPenColor(HANDLE, u32) -> u32
 	SetDIBits(HANDLE, HANDLE, u32, u32, PCVOID, PCVOID, u32) -> i32
+	SetEnhMetaFileBits(u32, PCVOID) -> HANDLE
 	SetGraphicsMode(HANDLE, i32) -> i32
 	SetStretchBltMode(HANDLE, i32) -> i32
 	SetTextAlign(HANDLE, u32) -> u32
@@ -109,5 +130,8 @@ extern_sys! { "msimg32";
 }
 
 extern_sys! { "user32";
+	CreateIconFromResourceEx(PCVOID, u32, BOOL, u32, i32, i32, u32) -> HANDLE
+	CreateIconIndirect(PCVOID) -> HANDLE
+	GetIconInfo(HANDLE, PVOID) -> BOOL
 	LoadImageW(HANDLE, PCSTR, u32, i32, i32, u32) -> HANDLE // returns GdiObjectGuard, so needs gdi feature
 }
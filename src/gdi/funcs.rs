@@ -1,9 +1,71 @@
 #![allow(non_snake_case)]
 
+use crate::co;
 use crate::decl::*;
 use crate::gdi::ffi;
+use crate::guard::*;
 use crate::kernel::privs::*;
 
+/// [`AddFontMemResourceEx`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-addfontmemresourceex)
+/// function.
+///
+/// Installs the fonts contained in the bytes of a font file, making them
+/// available only to this process, and not enumerated. Returns the guard, which
+/// removes the fonts when dropped, and the number of fonts installed.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let ttf_bytes: Vec<u8>; // initialized somewhere
+/// # let ttf_bytes = Vec::<u8>::new();
+///
+/// let (_font_res, num_fonts) = w::AddFontMemResourceEx(&ttf_bytes)?;
+///
+/// let lf = w::LOGFONT::default()
+///     .face("My Embedded Font")
+///     .point_size(12.0, 96);
+/// let hfont = w::HFONT::CreateFontIndirect(&lf)?;
+/// # Ok::<_, winsafe::co::ERROR>(())
+/// ```
+#[must_use]
+pub fn AddFontMemResourceEx(
+	font_data: &[u8],
+) -> SysResult<(RemoveFontMemResourceExGuard, u32)>
+{
+	let mut num_fonts = u32::default();
+	unsafe {
+		ptr_to_sysresult_handle(
+			ffi::AddFontMemResourceEx(
+				font_data.as_ptr() as _,
+				font_data.len() as _,
+				std::ptr::null_mut(),
+				&mut num_fonts,
+			),
+		).map(|h| (RemoveFontMemResourceExGuard::new(h), num_fonts))
+	}
+}
+
+/// [`AddFontResourceEx`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-addfontresourceexw)
+/// function.
+///
+/// Returns the number of fonts installed. The fonts must be removed with
+/// [`RemoveFontResourceEx`](crate::RemoveFontResourceEx), with the same
+/// flags.
+pub fn AddFontResourceEx(file_name: &str, flags: co::FR) -> SysResult<u32> {
+	match unsafe {
+		ffi::AddFontResourceExW(
+			WString::from_str(file_name).as_ptr(),
+			flags.raw(),
+			std::ptr::null_mut(),
+		)
+	} {
+		0 => Err(GetLastError()),
+		n => Ok(n as _),
+	}
+}
+
 /// [`GdiFlush`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-gdiflush)
 /// function.
 pub fn GdiFlush() -> SysResult<()> {
@@ -25,3 +87,17 @@ pub fn GdiSetBatchLimit(limit: u32) -> SysResult<u32> {
 		n => Ok(n),
 	}
 }
+
+/// [`RemoveFontResourceEx`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-removefontresourceexw)
+/// function.
+pub fn RemoveFontResourceEx(file_name: &str, flags: co::FR) -> SysResult<()> {
+	bool_to_sysresult(
+		unsafe {
+			ffi::RemoveFontResourceExW(
+				WString::from_str(file_name).as_ptr(),
+				flags.raw(),
+				std::ptr::null_mut(),
+			)
+		},
+	)
+}
@@ -3,8 +3,85 @@ use std::ops::{Deref, DerefMut};
 use crate::co;
 use crate::decl::*;
 use crate::gdi::ffi;
+use crate::kernel::privs::*;
 use crate::prelude::*;
 
+/// RAII implementation for a metafile [`HDC`](crate::HDC), returned by
+/// [`HDC::CreateEnhMetaFile`](crate::prelude::gdi_Hdc::CreateEnhMetaFile).
+///
+/// Call [`CloseEnhMetaFile`](crate::guard::CloseEnhMetaFileGuard::CloseEnhMetaFile)
+/// to finish the recording and retrieve the metafile. If the guard goes out of
+/// scope before that,
+/// [`CloseEnhMetaFile`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-closeenhmetafile)
+/// is automatically called and the recording is discarded.
+pub struct CloseEnhMetaFileGuard {
+	hdc: HDC,
+}
+
+impl Drop for CloseEnhMetaFileGuard {
+	fn drop(&mut self) {
+		if let Some(h) = self.hdc.as_opt() {
+			unsafe {
+				let hemf = ffi::CloseEnhMetaFile(h.ptr());
+				if !hemf.is_null() {
+					ffi::DeleteEnhMetaFile(hemf); // ignore errors
+				}
+			}
+		}
+	}
+}
+
+impl Deref for CloseEnhMetaFileGuard {
+	type Target = HDC;
+
+	fn deref(&self) -> &Self::Target {
+		&self.hdc
+	}
+}
+
+impl CloseEnhMetaFileGuard {
+	/// Constructs the guard by taking ownership of the handle.
+	///
+	/// # Safety
+	///
+	/// Be sure the handle is a metafile `HDC`, which must be closed with
+	/// [`CloseEnhMetaFile`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-closeenhmetafile)
+	/// at the end of scope.
+	///
+	/// This method is used internally by the library, and not intended to be
+	/// used externally.
+	#[must_use]
+	pub const unsafe fn new(hdc: HDC) -> Self {
+		Self { hdc }
+	}
+
+	/// [`CloseEnhMetaFile`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-closeenhmetafile)
+	/// function.
+	///
+	/// Finishes the recording, returning the metafile.
+	#[allow(non_snake_case)]
+	pub fn CloseEnhMetaFile(mut self) -> SysResult<DeleteEnhMetaFileGuard> {
+		let hdc = std::mem::replace(&mut self.hdc, HDC::NULL); // destructor won't run
+		unsafe {
+			ptr_to_sysresult_handle(ffi::CloseEnhMetaFile(hdc.ptr()))
+				.map(|h| DeleteEnhMetaFileGuard::new(h))
+		}
+	}
+
+	/// Ejects the underlying handle, leaving a
+	/// [`Handle::INVALID`](crate::prelude::Handle::INVALID) in its place.
+	///
+	/// Since the internal handle will be invalidated, the destructor will not
+	/// run. It's your responsability to run it, otherwise you'll cause a
+	/// resource leak.
+	#[must_use]
+	pub fn leak(&mut self) -> HDC {
+		std::mem::replace(&mut self.hdc, Handle::INVALID)
+	}
+}
+
+//------------------------------------------------------------------------------
+
 handle_guard! { DeleteDCGuard: HDC;
 	ffi::DeleteDC;
 	/// RAII implementation for [`HDC`](crate::HDC) which automatically calls
@@ -12,6 +89,22 @@ handle_guard! { DeleteDCGuard: HDC;
 	/// when the object goes out of scope.
 }
 
+handle_guard! { DeleteEnhMetaFileGuard: HENHMETAFILE;
+	ffi::DeleteEnhMetaFile;
+	/// RAII implementation for [`HENHMETAFILE`](crate::HENHMETAFILE) which
+	/// automatically calls
+	/// [`DeleteEnhMetaFile`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-deleteenhmetafile)
+	/// when the object goes out of scope.
+}
+
+handle_guard! { RemoveFontMemResourceExGuard: HFONTMEM;
+	ffi::RemoveFontMemResourceEx;
+	/// RAII implementation for [`HFONTMEM`](crate::HFONTMEM) which
+	/// automatically calls
+	/// [`RemoveFontMemResourceEx`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-removefontmemresourceex)
+	/// when the object goes out of scope.
+}
+
 //------------------------------------------------------------------------------
 
 /// RAII implementation for a [`GdiObject`](crate::prelude::GdiObject) which
@@ -84,6 +177,144 @@ impl<T> DeleteObjectGuard<T>
 
 //------------------------------------------------------------------------------
 
+/// RAII implementation for a DIB section [`HBITMAP`](crate::HBITMAP), returned
+/// by [`HDC::CreateDIBSection`](crate::prelude::gdi_Hdc::CreateDIBSection),
+/// which automatically calls
+/// [`DeleteObject`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-deleteobject)
+/// when the object goes out of scope.
+///
+/// Also gives access to the pixel buffer, which is owned by the bitmap. Before
+/// reading the pixels after drawing onto the bitmap, call
+/// [`GdiFlush`](crate::GdiFlush).
+pub struct DeleteObjectDibGuard {
+	hbmp: HBITMAP,
+	bits: *mut u8,
+	len: usize,
+}
+
+impl Drop for DeleteObjectDibGuard {
+	fn drop(&mut self) {
+		if let Some(h) = self.hbmp.as_opt() {
+			unsafe { ffi::DeleteObject(h.ptr()); } // ignore errors
+		}
+	}
+}
+
+impl Deref for DeleteObjectDibGuard {
+	type Target = HBITMAP;
+
+	fn deref(&self) -> &Self::Target {
+		&self.hbmp
+	}
+}
+
+impl DeleteObjectDibGuard {
+	/// Constructs the guard by taking ownership of the handle.
+	///
+	/// # Safety
+	///
+	/// Be sure the handle is a DIB section, which must be freed with
+	/// [`DeleteObject`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-deleteobject)
+	/// at the end of scope, and that `bits` points to its pixel buffer with
+	/// `len` bytes.
+	///
+	/// This method is used internally by the library, and not intended to be
+	/// used externally.
+	#[must_use]
+	pub const unsafe fn new(hbmp: HBITMAP, bits: *mut u8, len: usize) -> Self {
+		Self { hbmp, bits, len }
+	}
+
+	/// Returns the pixel buffer.
+	#[must_use]
+	pub fn bits(&self) -> &[u8] {
+		if self.hbmp == HBITMAP::INVALID || self.bits.is_null() {
+			&[]
+		} else {
+			unsafe { std::slice::from_raw_parts(self.bits, self.len) }
+		}
+	}
+
+	/// Returns the pixel buffer.
+	#[must_use]
+	pub fn bits_mut(&mut self) -> &mut [u8] {
+		if self.hbmp == HBITMAP::INVALID || self.bits.is_null() {
+			&mut []
+		} else {
+			unsafe { std::slice::from_raw_parts_mut(self.bits, self.len) }
+		}
+	}
+
+	/// Ejects the underlying handle, leaving a
+	/// [`Handle::INVALID`](crate::prelude::Handle::INVALID) in its place.
+	///
+	/// Since the internal handle will be invalidated, the destructor will not
+	/// run. It's your responsability to run it, otherwise you'll cause a
+	/// resource leak.
+	#[must_use]
+	pub fn leak(&mut self) -> HBITMAP {
+		std::mem::replace(&mut self.hbmp, HBITMAP::INVALID)
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// RAII implementation for [`ICONINFO`](crate::ICONINFO) which automatically
+/// calls
+/// [`DeleteObject`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-deleteobject)
+/// on `hbmMask` and `hbmColor` fields when the object goes out of scope.
+pub struct DeleteObjectIconInfoGuard {
+	ii: ICONINFO,
+}
+
+impl Drop for DeleteObjectIconInfoGuard {
+	fn drop(&mut self) {
+		for hbmp in [&self.ii.hbmMask, &self.ii.hbmColor] {
+			if let Some(h) = hbmp.as_opt() {
+				unsafe { ffi::DeleteObject(h.ptr()); } // ignore errors
+			}
+		}
+	}
+}
+
+impl Deref for DeleteObjectIconInfoGuard {
+	type Target = ICONINFO;
+
+	fn deref(&self) -> &Self::Target {
+		&self.ii
+	}
+}
+
+impl DeleteObjectIconInfoGuard {
+	/// Constructs the guard by taking ownership of the struct.
+	///
+	/// # Safety
+	///
+	/// Be sure the bitmaps must be freed with
+	/// [`DeleteObject`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-deleteobject)
+	/// at the end of scope.
+	///
+	/// This method is used internally by the library, and not intended to be
+	/// used externally.
+	#[must_use]
+	pub const unsafe fn new(ii: ICONINFO) -> Self {
+		Self { ii }
+	}
+
+	/// Ejects the underlying struct, leaving
+	/// [`ICONINFO::default`](crate::ICONINFO::default) in its place.
+	///
+	/// Since the internal handles will be invalidated, the destructor will not
+	/// run. It's your responsibility to run it, otherwise you'll cause a
+	/// resource leak.
+	#[must_use]
+	pub fn leak(&mut self) -> ICONINFO {
+		std::mem::take(&mut self.ii)
+	}
+}
+
+//------------------------------------------------------------------------------
+
 /// RAII implementation for [`LOGPALETTE`](crate::LOGPALETTE) which manages the
 /// allocated memory.
 pub struct LogpaletteGuard {
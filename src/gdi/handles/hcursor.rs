@@ -0,0 +1,72 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::decl::*;
+use crate::gdi::ffi;
+use crate::guard::*;
+use crate::kernel::privs::*;
+use crate::prelude::*;
+
+impl gdi_Hcursor for HCURSOR {}
+
+/// This trait is enabled with the `gdi` feature, and provides methods for
+/// [`HCURSOR`](crate::HCURSOR).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait gdi_Hcursor: user_Hcursor {
+	/// [`CreateIconFromResourceEx`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createiconfromresourceex)
+	/// function for a cursor.
+	///
+	/// The bits are a single image of a cursor resource, which starts with the
+	/// hotspot as two `u16`, followed by a DIB or a PNG. If `sz` is zero, the
+	/// system cursor size is used.
+	#[must_use]
+	fn CreateIconFromResourceEx(
+		res_bits: &[u8],
+		sz: SIZE,
+		flags: co::LR,
+	) -> SysResult<DestroyCursorGuard>
+	{
+		unsafe {
+			ptr_to_sysresult_handle(
+				ffi::CreateIconFromResourceEx(
+					res_bits.as_ptr() as _,
+					res_bits.len() as _,
+					0,
+					0x0003_0000,
+					sz.cx,
+					sz.cy,
+					flags.raw(),
+				),
+			).map(|h| DestroyCursorGuard::new(h))
+		}
+	}
+
+	/// [`CreateIconIndirect`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createiconindirect)
+	/// function for a cursor.
+	///
+	/// The bitmaps are copied, so they can be deleted afterwards. The
+	/// [`ICONINFO::fIcon`](crate::ICONINFO::fIcon) field must be `false`.
+	#[must_use]
+	fn CreateIconIndirect(info: &ICONINFO) -> SysResult<DestroyCursorGuard> {
+		unsafe {
+			ptr_to_sysresult_handle(ffi::CreateIconIndirect(info as *const _ as _))
+				.map(|h| DestroyCursorGuard::new(h))
+		}
+	}
+
+	/// [`GetIconInfo`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-geticoninfo)
+	/// function for a cursor.
+	#[must_use]
+	fn GetIconInfo(&self) -> SysResult<DeleteObjectIconInfoGuard> {
+		let mut ii = ICONINFO::default();
+		unsafe {
+			bool_to_sysresult(ffi::GetIconInfo(self.ptr(), &mut ii as *mut _ as _))
+				.map(|_| DeleteObjectIconInfoGuard::new(ii))
+		}
+	}
+}
@@ -6,7 +6,7 @@ use crate::co;
 use crate::decl::*;
 use crate::gdi::{ffi, privs::*};
 use crate::guard::*;
-use crate::kernel::privs::*;
+use crate::kernel::{ffi_types::*, privs::*};
 use crate::prelude::*;
 
 impl gdi_Hdc for HDC {}
@@ -175,6 +175,126 @@ pub trait gdi_Hdc: user_Hdc {
 		}
 	}
 
+	/// [`CreateDIBSection`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-createdibsection)
+	/// function.
+	///
+	/// The returned guard gives direct access to the pixel buffer, whose size
+	/// is computed from `bmi`. Only uncompressed formats are supported –
+	/// [`co::BI::RGB`](crate::co::BI::RGB) and
+	/// [`co::BI::BITFIELDS`](crate::co::BI::BITFIELDS) –, otherwise
+	/// [`co::ERROR::NOT_SUPPORTED`](crate::co::ERROR::NOT_SUPPORTED) is
+	/// returned.
+	///
+	/// # Safety
+	///
+	/// If the format has a color table – 1, 4 and 8 bpp – or bit field masks,
+	/// `bmi` must be followed by them in memory, otherwise GDI will read past
+	/// the struct.
+	///
+	/// # Examples
+	///
+	/// Creating a 32 bpp top-down bitmap and painting it red:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let hdc: w::HDC; // initialized somewhere
+	/// # let hdc = w::HDC::NULL;
+	///
+	/// let mut bi = w::BITMAPINFO::default();
+	/// bi.bmiHeader.biWidth = 64;
+	/// bi.bmiHeader.biHeight = -64; // negative: top-down
+	/// bi.bmiHeader.biPlanes = 1;
+	/// bi.bmiHeader.biBitCount = 32;
+	/// bi.bmiHeader.biCompression = co::BI::RGB;
+	///
+	/// let mut hbmp = unsafe { hdc.CreateDIBSection(&bi, co::DIB::RGB_COLORS)? };
+	/// for px in hbmp.bits_mut().chunks_exact_mut(4) {
+	///     px.copy_from_slice(&[0x00, 0x00, 0xff, 0xff]); // BGRA
+	/// }
+	/// # Ok::<_, co::ERROR>(())
+	/// ```
+	#[must_use]
+	unsafe fn CreateDIBSection(&self,
+		bmi: &BITMAPINFO,
+		usage: co::DIB,
+	) -> SysResult<DeleteObjectDibGuard>
+	{
+		let hdr = &bmi.bmiHeader;
+		match (hdr.biCompression, hdr.biBitCount) {
+			(co::BI::RGB, 1 | 4 | 8 | 16 | 24 | 32) | (co::BI::BITFIELDS, 16 | 32) => {},
+			_ => return Err(co::ERROR::NOT_SUPPORTED),
+		}
+		let len = (hdr.biWidth.unsigned_abs() as usize)
+			.checked_mul(hdr.biBitCount as _)
+			.map(|bits| bits.div_ceil(32) * 4) // stride
+			.and_then(|stride| stride.checked_mul(hdr.biHeight.unsigned_abs() as _))
+			.ok_or(co::ERROR::INVALID_PARAMETER)?;
+
+		let mut bits = std::ptr::null_mut();
+		unsafe {
+			ptr_to_sysresult_handle(
+				ffi::CreateDIBSection(
+					self.ptr(),
+					bmi as *const _ as _,
+					usage.raw(),
+					&mut bits,
+					std::ptr::null_mut(),
+					0,
+				),
+			).map(|h| DeleteObjectDibGuard::new(h, bits as _, len))
+		}
+	}
+
+	/// [`CreateEnhMetaFile`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-createenhmetafilew)
+	/// function.
+	///
+	/// This `HDC` is the reference device, which can be
+	/// [`HDC::NULL`](crate::prelude::Handle::NULL) to use the screen. The
+	/// optional `file_name` saves the metafile to disk, `rc_frame` is in
+	/// 0.01 millimeter units, and `description` is the application name and
+	/// the picture title.
+	///
+	/// # Examples
+	///
+	/// Recording a drawing, then playing it:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let hdc_emf = w::HDC::NULL.CreateEnhMetaFile(
+	///     None, None, Some(("My app", "Chart")))?;
+	/// hdc_emf.Ellipse(w::RECT { left: 0, top: 0, right: 100, bottom: 50 })?;
+	/// let hemf = hdc_emf.CloseEnhMetaFile()?;
+	///
+	/// let hdc: w::HDC; // initialized somewhere
+	/// # let hdc = w::HDC::NULL;
+	/// hdc.PlayEnhMetaFile(&hemf, w::RECT { left: 0, top: 0, right: 200, bottom: 100 })?;
+	///
+	/// let emf_bytes = hemf.GetEnhMetaFileBits()?;
+	/// // DeleteEnhMetaFile() automatically called
+	/// # Ok::<_, winsafe::co::ERROR>(())
+	/// ```
+	#[must_use]
+	fn CreateEnhMetaFile(&self,
+		file_name: Option<&str>,
+		rc_frame: Option<&RECT>,
+		description: Option<(&str, &str)>,
+	) -> SysResult<CloseEnhMetaFileGuard>
+	{
+		let description_w = description.map(|(app, title)| WString::from_str_vec(&[app, title]));
+		unsafe {
+			ptr_to_sysresult_handle(
+				ffi::CreateEnhMetaFileW(
+					self.ptr(),
+					WString::from_opt_str(file_name).as_ptr(),
+					rc_frame.map_or(std::ptr::null(), |rc| rc as *const _ as _),
+					description_w.as_ref().map_or(std::ptr::null(), |d| d.as_ptr()),
+				),
+			).map(|h| CloseEnhMetaFileGuard::new(h))
+		}
+	}
+
 	/// [`CreateHalftonePalette`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-createhalftonepalette)
 	/// function.
 	#[must_use]
@@ -205,6 +325,51 @@ pub trait gdi_Hdc: user_Hdc {
 		bool_to_sysresult(unsafe { ffi::EndPath(self.ptr()) })
 	}
 
+	/// [`EnumFontFamiliesEx`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-enumfontfamiliesexw)
+	/// function.
+	///
+	/// The callback receives the font attributes, its metrics and the font
+	/// type; return `true` to continue the enumeration. For non-TrueType fonts,
+	/// only the [`TEXTMETRIC`](crate::TEXTMETRIC) part of
+	/// [`NEWTEXTMETRICEX`](crate::NEWTEXTMETRICEX) is filled.
+	///
+	/// # Examples
+	///
+	/// Listing the installed font families:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let hdc = w::HWND::DESKTOP.GetDC()?;
+	///
+	/// let mut lf = w::LOGFONT::default();
+	/// lf.lfCharSet = co::CHARSET::DEFAULT;
+	///
+	/// let mut families = Vec::<String>::new();
+	/// hdc.EnumFontFamiliesEx(&lf, |elf, _ntm, _font_type| {
+	///     let name = elf.elfLogFont.lfFaceName();
+	///     if !families.contains(&name) {
+	///         families.push(name);
+	///     }
+	///     true
+	/// });
+	/// # Ok::<_, co::ERROR>(())
+	/// ```
+	fn EnumFontFamiliesEx<F>(&self, lf: &LOGFONT, func: F)
+		where F: FnMut(&ENUMLOGFONTEX, &NEWTEXTMETRICEX, co::FONTTYPE) -> bool,
+	{
+		let mut func = func;
+		unsafe {
+			ffi::EnumFontFamiliesExW(
+				self.ptr(),
+				lf as *const _ as _,
+				enum_font_families_ex_proc::<F> as _,
+				&mut func as *mut _ as _,
+				0,
+			);
+		}
+	}
+
 	/// [`FillPath`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-fillpath)
 	/// function.
 	fn FillPath(&self) -> SysResult<()> {
@@ -404,6 +569,44 @@ pub trait gdi_Hdc: user_Hdc {
 		}
 	}
 
+	/// [`GetTextExtentExPoint`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-gettextextentexpointw)
+	/// function.
+	///
+	/// Returns the size of the whole string, the number of characters which fit
+	/// into `max_extent` (or the total number of characters, if `None`), and
+	/// the partial extent of each of these characters. Counts and extents are
+	/// given in UTF-16 code units.
+	#[must_use]
+	fn GetTextExtentExPoint(&self,
+		text: &str,
+		max_extent: Option<i32>,
+	) -> SysResult<(SIZE, u32, Vec<i32>)>
+	{
+		let wtext = WString::from_str(text);
+		let num_chars = text.encode_utf16().count();
+		let mut fit = 0i32;
+		let mut dx = vec![0i32; num_chars];
+		let mut sz = SIZE::default();
+
+		bool_to_sysresult(
+			unsafe {
+				ffi::GetTextExtentExPointW(
+					self.ptr(),
+					wtext.as_ptr(),
+					num_chars as _,
+					max_extent.unwrap_or_default(),
+					max_extent.map_or(std::ptr::null_mut(), |_| &mut fit),
+					dx.as_mut_ptr(),
+					&mut sz as *mut _ as _,
+				)
+			},
+		).map(|_| {
+			let fit = if max_extent.is_some() { fit as usize } else { num_chars };
+			dx.truncate(fit);
+			(sz, fit as _, dx)
+		})
+	}
+
 	/// [`GetTextExtentPoint32`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-gettextextentpoint32w)
 	/// function.
 	#[must_use]
@@ -576,6 +779,18 @@ pub trait gdi_Hdc: user_Hdc {
 		)
 	}
 
+	/// [`PlayEnhMetaFile`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-playenhmetafile)
+	/// function.
+	///
+	/// The picture is stretched to fit the rectangle.
+	fn PlayEnhMetaFile(&self, hemf: &HENHMETAFILE, rc: RECT) -> SysResult<()> {
+		bool_to_sysresult(
+			unsafe {
+				ffi::PlayEnhMetaFile(self.ptr(), hemf.ptr(), &rc as *const _ as _)
+			},
+		)
+	}
+
 	/// [`PolyBezier`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-polybezier)
 	/// function.
 	fn PolyBezier(&self, pts: &[POINT]) -> SysResult<()> {
@@ -1045,3 +1260,32 @@ pub trait gdi_Hdc: user_Hdc {
 		bool_to_sysresult(unsafe { ffi::WidenPath(self.ptr()) })
 	}
 }
+
+extern "system" fn enum_font_families_ex_proc<F>(
+	lpelfe: PCVOID,
+	lpntme: PCVOID,
+	font_type: u32,
+	lparam: isize,
+) -> BOOL
+	where F: FnMut(&ENUMLOGFONTEX, &NEWTEXTMETRICEX, co::FONTTYPE) -> bool,
+{
+	let func = unsafe { &mut *(lparam as *mut F) };
+	let font_type = unsafe { co::FONTTYPE::from_raw(font_type) };
+	let elf = unsafe { &*(lpelfe as *const ENUMLOGFONTEX) }.clone();
+
+	let mut ntm = NEWTEXTMETRICEX::default();
+	let ntm_len = if font_type.has(co::FONTTYPE::TRUETYPE) {
+		std::mem::size_of::<NEWTEXTMETRICEX>()
+	} else {
+		std::mem::size_of::<TEXTMETRIC>() // non-TrueType fonts receive a TEXTMETRIC only
+	};
+	unsafe {
+		std::ptr::copy_nonoverlapping(
+			lpntme as *const u8,
+			&mut ntm as *mut _ as *mut u8,
+			ntm_len,
+		);
+	}
+
+	func(&elf, &ntm, font_type) as _
+}
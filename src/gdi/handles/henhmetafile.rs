@@ -0,0 +1,147 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::decl::*;
+use crate::gdi::ffi;
+use crate::guard::*;
+use crate::kernel::{ffi_types::*, privs::*};
+use crate::prelude::*;
+
+impl_handle! { HENHMETAFILE;
+	/// Handle to an
+	/// [enhanced metafile](https://learn.microsoft.com/en-us/windows/win32/gdi/enhanced-format-metafiles).
+}
+
+impl gdi_Henhmetafile for HENHMETAFILE {}
+
+/// This trait is enabled with the `gdi` feature, and provides methods for
+/// [`HENHMETAFILE`](crate::HENHMETAFILE).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait gdi_Henhmetafile: Handle {
+	/// [`EnumEnhMetaFile`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-enumenhmetafile)
+	/// function.
+	///
+	/// Calls the closure for each record, until it returns `false`. The
+	/// records are not played.
+	///
+	/// To parse the records from a byte buffer, see
+	/// [`EmfRecords`](crate::EmfRecords).
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let hemf: w::HENHMETAFILE; // initialized somewhere
+	/// # let hemf = w::HENHMETAFILE::NULL;
+	///
+	/// hemf.EnumEnhMetaFile(|rec: w::EmfRecord| -> bool {
+	///     println!("{} {} bytes", rec.kind(), rec.emr().nSize);
+	///     true
+	/// })?;
+	/// # Ok::<_, winsafe::co::ERROR>(())
+	/// ```
+	fn EnumEnhMetaFile<F>(&self, func: F) -> SysResult<()>
+		where F: FnMut(EmfRecord) -> bool,
+	{
+		let mut func = func;
+		bool_to_sysresult(
+			unsafe {
+				ffi::EnumEnhMetaFile(
+					std::ptr::null_mut(),
+					self.ptr(),
+					enum_enh_meta_file_proc::<F> as _,
+					&mut func as *mut _ as _,
+					std::ptr::null(),
+				)
+			},
+		)
+	}
+
+	/// [`GetEnhMetaFile`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-getenhmetafilew)
+	/// function.
+	///
+	/// Loads an enhanced metafile from a `.emf` file.
+	#[must_use]
+	fn GetEnhMetaFile(file_name: &str) -> SysResult<DeleteEnhMetaFileGuard> {
+		unsafe {
+			ptr_to_sysresult_handle(
+				ffi::GetEnhMetaFileW(WString::from_str(file_name).as_ptr()),
+			).map(|h| DeleteEnhMetaFileGuard::new(h))
+		}
+	}
+
+	/// [`GetEnhMetaFileBits`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-getenhmetafilebits)
+	/// function.
+	///
+	/// Returns the contents of the metafile, which can be saved as an `.emf`
+	/// file.
+	#[must_use]
+	fn GetEnhMetaFileBits(&self) -> SysResult<Vec<u8>> {
+		let sz = match unsafe {
+			ffi::GetEnhMetaFileBits(self.ptr(), 0, std::ptr::null_mut())
+		} {
+			0 => return Err(GetLastError()),
+			sz => sz,
+		};
+
+		let mut buf = vec![0u8; sz as _];
+		match unsafe {
+			ffi::GetEnhMetaFileBits(self.ptr(), sz, buf.as_mut_ptr() as _)
+		} {
+			0 => Err(GetLastError()),
+			_ => Ok(buf),
+		}
+	}
+
+	/// [`GetEnhMetaFileHeader`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-getenhmetafileheader)
+	/// function.
+	#[must_use]
+	fn GetEnhMetaFileHeader(&self) -> SysResult<ENHMETAHEADER> {
+		let mut emh = ENHMETAHEADER::default();
+		match unsafe {
+			ffi::GetEnhMetaFileHeader(
+				self.ptr(),
+				std::mem::size_of::<ENHMETAHEADER>() as _,
+				&mut emh as *mut _ as _,
+			)
+		} {
+			0 => Err(GetLastError()),
+			_ => Ok(emh),
+		}
+	}
+
+	/// [`SetEnhMetaFileBits`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-setenhmetafilebits)
+	/// function.
+	///
+	/// Creates an enhanced metafile from the contents of an `.emf` file.
+	#[must_use]
+	fn SetEnhMetaFileBits(data: &[u8]) -> SysResult<DeleteEnhMetaFileGuard> {
+		unsafe {
+			ptr_to_sysresult_handle(
+				ffi::SetEnhMetaFileBits(data.len() as _, data.as_ptr() as _),
+			).map(|h| DeleteEnhMetaFileGuard::new(h))
+		}
+	}
+}
+
+//------------------------------------------------------------------------------
+
+extern "system" fn enum_enh_meta_file_proc<F>(
+	_: HANDLE, _: PVOID, lpr: PCVOID, _: i32, lparam: isize) -> BOOL
+	where F: FnMut(EmfRecord) -> bool,
+{
+	let func = unsafe { &mut *(lparam as *mut F) };
+	let data = unsafe {
+		let n_size = *(lpr as *const u32).add(1); // EMR::nSize
+		std::slice::from_raw_parts(lpr as *const u8, n_size as _)
+	};
+	match EmfRecord::parse(data) {
+		Ok(rec) => func(rec) as _,
+		Err(_) => 0, // malformed record, stop
+	}
+}
@@ -0,0 +1,98 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::decl::*;
+use crate::gdi::ffi;
+use crate::guard::*;
+use crate::kernel::privs::*;
+use crate::prelude::*;
+
+impl gdi_Hicon for HICON {}
+
+/// This trait is enabled with the `gdi` feature, and provides methods for
+/// [`HICON`](crate::HICON).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait gdi_Hicon: user_Hicon {
+	/// [`CreateIconFromResourceEx`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createiconfromresourceex)
+	/// function.
+	///
+	/// The bits are a single image of an icon resource or `.ico` file, either
+	/// a DIB or a PNG. If `sz` is zero, the system icon size is used.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let png_bytes: Vec<u8>; // initialized somewhere
+	/// # let png_bytes = Vec::<u8>::new();
+	///
+	/// let hicon = w::HICON::CreateIconFromResourceEx(
+	///     &png_bytes, w::SIZE::new(32, 32), co::LR::DEFAULTCOLOR)?;
+	/// # Ok::<_, co::ERROR>(())
+	/// ```
+	#[must_use]
+	fn CreateIconFromResourceEx(
+		res_bits: &[u8],
+		sz: SIZE,
+		flags: co::LR,
+	) -> SysResult<DestroyIconGuard>
+	{
+		unsafe {
+			ptr_to_sysresult_handle(
+				ffi::CreateIconFromResourceEx(
+					res_bits.as_ptr() as _,
+					res_bits.len() as _,
+					1,
+					0x0003_0000,
+					sz.cx,
+					sz.cy,
+					flags.raw(),
+				),
+			).map(|h| DestroyIconGuard::new(h))
+		}
+	}
+
+	/// [`CreateIconIndirect`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createiconindirect)
+	/// function.
+	///
+	/// The bitmaps are copied, so they can be deleted afterwards.
+	#[must_use]
+	fn CreateIconIndirect(info: &ICONINFO) -> SysResult<DestroyIconGuard> {
+		unsafe {
+			ptr_to_sysresult_handle(ffi::CreateIconIndirect(info as *const _ as _))
+				.map(|h| DestroyIconGuard::new(h))
+		}
+	}
+
+	/// [`GetIconInfo`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-geticoninfo)
+	/// function.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let hicon: w::HICON; // initialized somewhere
+	/// # let hicon = w::HICON::NULL;
+	///
+	/// let ii = hicon.GetIconInfo()?;
+	/// let mut bmp = w::BITMAP::default();
+	/// ii.hbmColor.GetObject(&mut bmp)?;
+	/// println!("{}x{}", bmp.bmWidth, bmp.bmHeight);
+	/// # Ok::<_, winsafe::co::ERROR>(())
+	/// ```
+	#[must_use]
+	fn GetIconInfo(&self) -> SysResult<DeleteObjectIconInfoGuard> {
+		let mut ii = ICONINFO::default();
+		unsafe {
+			bool_to_sysresult(ffi::GetIconInfo(self.ptr(), &mut ii as *mut _ as _))
+				.map(|_| DeleteObjectIconInfoGuard::new(ii))
+		}
+	}
+}
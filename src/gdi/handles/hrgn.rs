@@ -20,6 +20,40 @@ impl gdi_Hrgn for HRGN {}
 /// use winsafe::prelude::*;
 /// ```
 pub trait gdi_Hrgn: Handle {
+	/// [`CombineRgn`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-combinergn)
+	/// function.
+	///
+	/// The result is stored in this region, which must already exist. To
+	/// combine into a new region, prefer the `&`, `|`, `^` and `-` operators.
+	fn CombineRgn(&self,
+		src1: &HRGN,
+		src2: &HRGN,
+		mode: co::RGN,
+	) -> SysResult<co::REGION>
+	{
+		match unsafe {
+			ffi::CombineRgn(self.ptr(), src1.ptr(), src2.ptr(), mode.raw())
+		} {
+			0 => Err(GetLastError()),
+			ret => Ok(unsafe { co::REGION::from_raw(ret) }),
+		}
+	}
+
+	/// [`CreatePolygonRgn`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-createpolygonrgn)
+	/// function.
+	#[must_use]
+	fn CreatePolygonRgn(
+		pts: &[POINT],
+		mode: co::POLYFILL,
+	) -> SysResult<DeleteObjectGuard<HRGN>>
+	{
+		unsafe {
+			ptr_to_sysresult_handle(
+				ffi::CreatePolygonRgn(pts.as_ptr() as _, pts.len() as _, mode.raw()),
+			).map(|h| DeleteObjectGuard::new(h))
+		}
+	}
+
 	/// [`CreateRectRgn`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-createrectrgn)
 	/// function.
 	#[must_use]
@@ -54,13 +88,81 @@ pub trait gdi_Hrgn: Handle {
 		unsafe {
 			ptr_to_sysresult_handle(
 				ffi::CreateRoundRectRgn(
-					bounds.left, bounds.top, bounds.right, bounds.top,
+					bounds.left, bounds.top, bounds.right, bounds.bottom,
 					size.cx, size.cy,
 				),
 			).map(|h| DeleteObjectGuard::new(h))
 		}
 	}
 
+	/// [`EqualRgn`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-equalrgn)
+	/// function.
+	#[must_use]
+	fn EqualRgn(&self, other: &HRGN) -> bool {
+		unsafe { ffi::EqualRgn(self.ptr(), other.ptr()) != 0 }
+	}
+
+	/// [`GetRegionData`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-getregiondata)
+	/// function.
+	///
+	/// Returns the header and the rectangles which compose the region.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let hrgn: w::HRGN; // initialized somewhere
+	/// # let hrgn = w::HRGN::NULL;
+	///
+	/// let (header, rects) = hrgn.GetRegionData()?;
+	/// println!("Bounds: {}", header.rcBound);
+	/// for rc in rects.iter() {
+	///     println!("{}", rc);
+	/// }
+	/// # Ok::<_, winsafe::co::ERROR>(())
+	/// ```
+	#[must_use]
+	fn GetRegionData(&self) -> SysResult<(RGNDATAHEADER, Vec<RECT>)> {
+		let num_bytes = match unsafe {
+			ffi::GetRegionData(self.ptr(), 0, std::ptr::null_mut())
+		} {
+			0 => return Err(GetLastError()),
+			n if (n as usize) < std::mem::size_of::<RGNDATAHEADER>() => {
+				return Err(co::ERROR::INVALID_DATA);
+			},
+			n => n as usize,
+		};
+
+		let mut buf = vec![0u32; num_bytes.div_ceil(4)]; // RECT alignment
+		if unsafe {
+			ffi::GetRegionData(self.ptr(), num_bytes as _, buf.as_mut_ptr() as _)
+		} == 0 {
+			return Err(GetLastError());
+		}
+
+		let header = unsafe { (*(buf.as_ptr() as *const RGNDATAHEADER)).clone() };
+		let rects_off = std::mem::size_of::<RGNDATAHEADER>() / 4;
+		let num_rects = (header.nCount as usize)
+			.min((buf.len() - rects_off) * 4 / std::mem::size_of::<RECT>());
+		let rects = unsafe {
+			std::slice::from_raw_parts(
+				buf[rects_off..].as_ptr() as *const RECT,
+				num_rects,
+			)
+		}.to_vec();
+		Ok((header, rects))
+	}
+
+	/// [`GetRgnBox`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-getrgnbox)
+	/// function.
+	fn GetRgnBox(&self, rc: &mut RECT) -> SysResult<co::REGION> {
+		match unsafe { ffi::GetRgnBox(self.ptr(), rc as *mut _ as _) } {
+			0 => Err(GetLastError()),
+			ret => Ok(unsafe { co::REGION::from_raw(ret) }),
+		}
+	}
+
 	/// [`OffsetClipRgn`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-offsetcliprgn)
 	/// function.
 	fn OffsetClipRgn(&self, x: i32, y: i32) -> SysResult<co::REGION> {
@@ -93,3 +195,81 @@ pub trait gdi_Hrgn: Handle {
 		unsafe { ffi::RectInRegion(self.ptr(), rc as *const _ as _) != 0 }
 	}
 }
+
+/// Combines two regions into a new one.
+fn combine_into_new(
+	src1: &HRGN,
+	src2: &HRGN,
+	mode: co::RGN,
+) -> SysResult<DeleteObjectGuard<HRGN>>
+{
+	let hrgn = HRGN::CreateRectRgn(RECT::default())?; // empty region to receive the result
+	hrgn.CombineRgn(src1, src2, mode)?;
+	Ok(hrgn)
+}
+
+/// Intersection of two regions, with
+/// [`HRGN::CombineRgn`](crate::prelude::gdi_Hrgn::CombineRgn) and
+/// [`co::RGN::AND`](crate::co::RGN::AND).
+impl std::ops::BitAnd for &HRGN {
+	type Output = SysResult<DeleteObjectGuard<HRGN>>;
+
+	fn bitand(self, rhs: Self) -> Self::Output {
+		combine_into_new(self, rhs, co::RGN::AND)
+	}
+}
+
+/// Union of two regions, with
+/// [`HRGN::CombineRgn`](crate::prelude::gdi_Hrgn::CombineRgn) and
+/// [`co::RGN::OR`](crate::co::RGN::OR).
+impl std::ops::BitOr for &HRGN {
+	type Output = SysResult<DeleteObjectGuard<HRGN>>;
+
+	fn bitor(self, rhs: Self) -> Self::Output {
+		combine_into_new(self, rhs, co::RGN::OR)
+	}
+}
+
+/// Union of two regions minus their intersection, with
+/// [`HRGN::CombineRgn`](crate::prelude::gdi_Hrgn::CombineRgn) and
+/// [`co::RGN::XOR`](crate::co::RGN::XOR).
+impl std::ops::BitXor for &HRGN {
+	type Output = SysResult<DeleteObjectGuard<HRGN>>;
+
+	fn bitxor(self, rhs: Self) -> Self::Output {
+		combine_into_new(self, rhs, co::RGN::XOR)
+	}
+}
+
+/// Parts of the first region which are not in the second, with
+/// [`HRGN::CombineRgn`](crate::prelude::gdi_Hrgn::CombineRgn) and
+/// [`co::RGN::DIFF`](crate::co::RGN::DIFF).
+///
+/// # Examples
+///
+/// Shaping a window as a circle with a rectangular notch removed:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let hwnd: w::HWND; // initialized somewhere
+/// # let hwnd = w::HWND::NULL;
+///
+/// let circle = w::HRGN::CreateRoundRectRgn(
+///     w::RECT { left: 0, top: 0, right: 200, bottom: 200 },
+///     w::SIZE::new(200, 200))?;
+/// let notch = w::HRGN::CreateRectRgn(
+///     w::RECT { left: 90, top: 0, right: 110, bottom: 40 })?;
+///
+/// let mut shape = (&*circle - &*notch)?;
+/// hwnd.SetWindowRgn(&shape, true)?;
+/// let _ = shape.leak(); // now owned by the system
+/// # Ok::<_, co::ERROR>(())
+/// ```
+impl std::ops::Sub for &HRGN {
+	type Output = SysResult<DeleteObjectGuard<HRGN>>;
+
+	fn sub(self, rhs: Self) -> Self::Output {
+		combine_into_new(self, rhs, co::RGN::DIFF)
+	}
+}
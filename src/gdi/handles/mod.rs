@@ -1,25 +1,41 @@
 mod gdi_traits;
 mod hbitmap;
 mod hbrush;
+mod hcursor;
 mod hdc;
+mod henhmetafile;
 mod hfont;
+mod hicon;
 mod hinstance;
 mod hpalette;
 mod hpen;
 mod hrgn;
 
 pub mod decl {
+	pub use super::henhmetafile::HENHMETAFILE;
 	pub use super::hfont::HFONT;
 	pub use super::hpalette::HPALETTE;
 	pub use super::hpen::HPEN;
+
+	impl_handle! { HFONTMEM;
+		/// Handle to a
+		/// [font memory resource](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-addfontmemresourceex).
+		/// Originally just a `HANDLE`.
+		///
+		/// For an example, see
+		/// [`AddFontMemResourceEx`](crate::AddFontMemResourceEx).
+	}
 }
 
 pub mod traits {
 	pub use super::gdi_traits::*;
 	pub use super::hbitmap::gdi_Hbitmap;
 	pub use super::hbrush::gdi_Hbrush;
+	pub use super::hcursor::gdi_Hcursor;
 	pub use super::hdc::gdi_Hdc;
+	pub use super::henhmetafile::gdi_Henhmetafile;
 	pub use super::hfont::gdi_Hfont;
+	pub use super::hicon::gdi_Hicon;
 	pub use super::hinstance::gdi_Hinstance;
 	pub use super::hpalette::gdi_Hpalette;
 	pub use super::hpen::gdi_Hpen;
@@ -4,6 +4,7 @@ mod enums;
 mod funcs;
 mod handles;
 mod structs;
+mod utilities;
 
 pub(in crate::gdi) mod ffi;
 pub(crate) mod privs;
@@ -16,6 +17,7 @@ pub mod decl {
 	pub use super::funcs::*;
 	pub use super::handles::decl::*;
 	pub use super::structs::*;
+	pub use super::utilities::*;
 }
 
 pub mod traits {
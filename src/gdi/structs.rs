@@ -4,6 +4,7 @@ use crate::co;
 use crate::decl::*;
 use crate::gdi::privs::*;
 use crate::guard::*;
+use crate::kernel::ffi_types::*;
 use crate::prelude::*;
 
 /// [`BITMAP`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/ns-wingdi-bitmap)
@@ -86,6 +87,181 @@ impl BITMAPINFOHEADER {
 	pub_fn_serialize!();
 }
 
+/// [`BITMAPV4HEADER`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/ns-wingdi-bitmapv4header)
+/// struct.
+#[repr(C)]
+#[derive(Clone)]
+pub struct BITMAPV4HEADER {
+	bV4Size: u32,
+	pub bV4Width: i32,
+	pub bV4Height: i32,
+	pub bV4Planes: u16,
+	pub bV4BitCount: u16,
+	pub bV4V4Compression: co::BI,
+	pub bV4SizeImage: u32,
+	pub bV4XPelsPerMeter: i32,
+	pub bV4YPelsPerMeter: i32,
+	pub bV4ClrUsed: u32,
+	pub bV4ClrImportant: u32,
+	pub bV4RedMask: u32,
+	pub bV4GreenMask: u32,
+	pub bV4BlueMask: u32,
+	pub bV4AlphaMask: u32,
+	pub bV4CSType: co::LCS,
+	pub bV4Endpoints: CIEXYZTRIPLE,
+	pub bV4GammaRed: u32,
+	pub bV4GammaGreen: u32,
+	pub bV4GammaBlue: u32,
+}
+
+impl_default_with_size!(BITMAPV4HEADER, bV4Size);
+
+impl BITMAPV4HEADER {
+	pub_fn_serialize!();
+}
+
+/// [`BITMAPV5HEADER`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/ns-wingdi-bitmapv5header)
+/// struct.
+#[repr(C)]
+#[derive(Clone)]
+pub struct BITMAPV5HEADER {
+	bV5Size: u32,
+	pub bV5Width: i32,
+	pub bV5Height: i32,
+	pub bV5Planes: u16,
+	pub bV5BitCount: u16,
+	pub bV5Compression: co::BI,
+	pub bV5SizeImage: u32,
+	pub bV5XPelsPerMeter: i32,
+	pub bV5YPelsPerMeter: i32,
+	pub bV5ClrUsed: u32,
+	pub bV5ClrImportant: u32,
+	pub bV5RedMask: u32,
+	pub bV5GreenMask: u32,
+	pub bV5BlueMask: u32,
+	pub bV5AlphaMask: u32,
+	pub bV5CSType: co::LCS,
+	pub bV5Endpoints: CIEXYZTRIPLE,
+	pub bV5GammaRed: u32,
+	pub bV5GammaGreen: u32,
+	pub bV5GammaBlue: u32,
+	pub bV5Intent: co::LCS_GM,
+	pub bV5ProfileData: u32,
+	pub bV5ProfileSize: u32,
+	bV5Reserved: u32,
+}
+
+impl_default_with_size!(BITMAPV5HEADER, bV5Size);
+
+impl BITMAPV5HEADER {
+	pub_fn_serialize!();
+}
+
+/// [`CIEXYZ`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/ns-wingdi-ciexyz)
+/// struct.
+#[repr(C)]
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+pub struct CIEXYZ {
+	pub ciexyzX: i32,
+	pub ciexyzY: i32,
+	pub ciexyzZ: i32,
+}
+
+/// [`CIEXYZTRIPLE`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/ns-wingdi-ciexyztriple)
+/// struct.
+#[repr(C)]
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+pub struct CIEXYZTRIPLE {
+	pub ciexyzRed: CIEXYZ,
+	pub ciexyzGreen: CIEXYZ,
+	pub ciexyzBlue: CIEXYZ,
+}
+
+/// [`EMR`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/ns-wingdi-emr)
+/// struct.
+///
+/// The header of every enhanced metafile record, as parsed by
+/// [`EmfRecord`](crate::EmfRecord).
+#[repr(C)]
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+pub struct EMR {
+	pub iType: co::EMR,
+	pub nSize: u32,
+}
+
+/// [`ENHMETAHEADER`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/ns-wingdi-enhmetaheader)
+/// struct.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct ENHMETAHEADER {
+	pub iType: co::EMR,
+	pub nSize: u32,
+	pub rclBounds: RECT,
+	pub rclFrame: RECT,
+	pub dSignature: u32,
+	pub nVersion: u32,
+	pub nBytes: u32,
+	pub nRecords: u32,
+	pub nHandles: u16,
+	sReserved: u16,
+	pub nDescription: u32,
+	pub offDescription: u32,
+	pub nPalEntries: u32,
+	pub szlDevice: SIZE,
+	pub szlMillimeters: SIZE,
+	pub cbPixelFormat: u32,
+	pub offPixelFormat: u32,
+	pub bOpenGL: u32,
+	pub szlMicrometers: SIZE,
+}
+
+impl_default!(ENHMETAHEADER);
+
+/// [`ENUMLOGFONTEX`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/ns-wingdi-enumlogfontexw)
+/// struct.
+#[repr(C)]
+#[derive(Clone)]
+pub struct ENUMLOGFONTEX {
+	pub elfLogFont: LOGFONT,
+	elfFullName: [u16; LF_FULLFACESIZE],
+	elfStyle: [u16; LF_FACESIZE],
+	elfScript: [u16; LF_FACESIZE],
+}
+
+impl_default!(ENUMLOGFONTEX);
+
+impl ENUMLOGFONTEX {
+	pub_fn_string_arr_get_set!(elfFullName, set_elfFullName);
+	pub_fn_string_arr_get_set!(elfStyle, set_elfStyle);
+	pub_fn_string_arr_get_set!(elfScript, set_elfScript);
+}
+
+/// [`FONTSIGNATURE`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/ns-wingdi-fontsignature)
+/// struct.
+#[repr(C)]
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+pub struct FONTSIGNATURE {
+	pub fsUsb: [u32; 4],
+	pub fsCsb: [u32; 2],
+}
+
+/// [`ICONINFO`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-iconinfo)
+/// struct.
+#[repr(C)]
+pub struct ICONINFO {
+	fIcon: BOOL,
+	pub xHotspot: u32,
+	pub yHotspot: u32,
+	pub hbmMask: HBITMAP,
+	pub hbmColor: HBITMAP,
+}
+
+impl_default!(ICONINFO);
+
+impl ICONINFO {
+	pub_fn_bool_get_set!(fIcon, set_fIcon);
+}
+
 /// [`LOGBRUSH`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/ns-wingdi-logbrush)
 /// struct.
 #[repr(C)]
@@ -120,6 +296,84 @@ pub struct LOGFONT {
 
 impl LOGFONT {
 	pub_fn_string_arr_get_set!(lfFaceName, set_lfFaceName);
+
+	/// Sets the face name, like `"Segoe UI"`.
+	///
+	/// # Examples
+	///
+	/// Creating a 10 pt bold italic font for a window:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let hwnd: w::HWND; // initialized somewhere
+	/// # let hwnd = w::HWND::NULL;
+	///
+	/// let lf = w::LOGFONT::default()
+	///     .face("Segoe UI")
+	///     .point_size(10.0, hwnd.GetDpiForWindow())
+	///     .weight(co::FW::BOLD)
+	///     .italic(true);
+	///
+	/// let hfont = w::HFONT::CreateFontIndirect(&lf)?;
+	/// # Ok::<_, co::ERROR>(())
+	/// ```
+	#[must_use]
+	pub fn face(mut self, face_name: &str) -> Self {
+		self.set_lfFaceName(face_name);
+		self
+	}
+
+	/// Sets the character height in points, for the given DPI. The height is
+	/// stored as a negative `lfHeight`, so it doesn't include the internal
+	/// leading.
+	#[must_use]
+	pub fn point_size(mut self, points: f32, dpi: u32) -> Self {
+		self.lfHeight = -(points * dpi as f32 / 72.0).round() as i32;
+		self
+	}
+
+	/// Sets the weight.
+	#[must_use]
+	pub const fn weight(mut self, weight: co::FW) -> Self {
+		self.lfWeight = weight;
+		self
+	}
+
+	/// Sets whether the font is italic.
+	#[must_use]
+	pub const fn italic(mut self, italic: bool) -> Self {
+		self.lfItalic = italic as _;
+		self
+	}
+
+	/// Sets whether the font is underlined.
+	#[must_use]
+	pub const fn underline(mut self, underline: bool) -> Self {
+		self.lfUnderline = underline as _;
+		self
+	}
+
+	/// Sets whether the font is struck out.
+	#[must_use]
+	pub const fn strike_out(mut self, strike_out: bool) -> Self {
+		self.lfStrikeOut = strike_out as _;
+		self
+	}
+
+	/// Sets the character set.
+	#[must_use]
+	pub const fn char_set(mut self, char_set: co::CHARSET) -> Self {
+		self.lfCharSet = char_set;
+		self
+	}
+
+	/// Sets the output quality.
+	#[must_use]
+	pub const fn quality(mut self, quality: co::QUALITY) -> Self {
+		self.lfQuality = quality;
+		self
+	}
 }
 
 /// [`LOGPALETTE`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/ns-wingdi-logpalette)
@@ -198,6 +452,46 @@ pub struct LOGPEN {
 
 impl_default!(LOGPEN);
 
+/// [`NEWTEXTMETRIC`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/ns-wingdi-newtextmetricw)
+/// struct.
+#[repr(C)]
+#[derive(Default, Clone)]
+pub struct NEWTEXTMETRIC {
+	pub tmHeight: i32,
+	pub tmAscent: i32,
+	pub tmDescent: i32,
+	pub tmInternalLeading: i32,
+	pub tmExternalLeading: i32,
+	pub tmAveCharWidth: i32,
+	pub tmMaxCharWidth: i32,
+	pub tmWeight: i32,
+	pub tmOverhang: i32,
+	pub tmDigitizedAspectX: i32,
+	pub tmDigitizedAspectY: i32,
+	pub tmFirstChar: u16,
+	pub tmLastChar: u16,
+	pub tmDefaultChar: u16,
+	pub tmBreakChar: u16,
+	pub tmItalic: u8,
+	pub tmUnderlined: u8,
+	pub tmStruckOut: u8,
+	pub tmPitchAndFamily: u8,
+	pub tmCharSet: u8,
+	pub ntmFlags: co::NTM,
+	pub ntmSizeEM: u32,
+	pub ntmCellHeight: u32,
+	pub ntmAvgWidth: u32,
+}
+
+/// [`NEWTEXTMETRICEX`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/ns-wingdi-newtextmetricexw)
+/// struct.
+#[repr(C)]
+#[derive(Default, Clone)]
+pub struct NEWTEXTMETRICEX {
+	pub ntmTm: NEWTEXTMETRIC,
+	pub ntmFontSig: FONTSIGNATURE,
+}
+
 /// [`NONCLIENTMETRICS`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-nonclientmetricsw)
 /// struct.
 #[repr(C)]
@@ -257,6 +551,20 @@ pub struct RGBQUAD {
 	rgbReserved: u8,
 }
 
+/// [`RGNDATAHEADER`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/ns-wingdi-rgndataheader)
+/// struct.
+#[repr(C)]
+#[derive(Clone)]
+pub struct RGNDATAHEADER {
+	dwSize: u32,
+	iType: u32,
+	pub nCount: u32,
+	pub nRgnSize: u32,
+	pub rcBound: RECT,
+}
+
+impl_default_with_size!(RGNDATAHEADER, dwSize);
+
 /// [`TEXTMETRIC`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/ns-wingdi-textmetricw)
 /// struct.
 #[repr(C)]
@@ -0,0 +1,736 @@
+use crate::co;
+use crate::decl::*;
+use crate::guard::*;
+use crate::prelude::*;
+
+/// Header version written by [`Dib::to_bmp`](crate::Dib::to_bmp) and
+/// [`Dib::to_packed`](crate::Dib::to_packed).
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum DibHeader {
+	/// [`BITMAPINFOHEADER`](crate::BITMAPINFOHEADER), understood by all
+	/// readers. 32 bpp images are written with
+	/// [`co::BI::RGB`](crate::co::BI::RGB), so most readers will ignore the
+	/// alpha channel.
+	Info,
+	/// [`BITMAPV4HEADER`](crate::BITMAPV4HEADER) with sRGB color space. 32 bpp
+	/// images are written with [`co::BI::BITFIELDS`](crate::co::BI::BITFIELDS)
+	/// and an alpha mask.
+	V4,
+	/// [`BITMAPV5HEADER`](crate::BITMAPV5HEADER) with sRGB color space. 32 bpp
+	/// images are written with [`co::BI::BITFIELDS`](crate::co::BI::BITFIELDS)
+	/// and an alpha mask.
+	V5,
+}
+
+/// An in-memory device-independent bitmap, which can be read from and written
+/// to `.bmp` files and packed DIBs – like the
+/// [`co::CF::DIB`](crate::co::CF::DIB) clipboard format – without calling the
+/// system.
+///
+/// Supports 1, 4, 8, 24 and 32 bpp uncompressed images, and 32 bpp images
+/// with bit fields. `BITMAPINFOHEADER`, `BITMAPV4HEADER` and
+/// `BITMAPV5HEADER` are read, either top-down or bottom-up.
+///
+/// The pixels are always stored top-down, with each row aligned to 4 bytes.
+/// Pixels of 24 and 32 bpp images are stored as BGR and BGRA, respectively.
+///
+/// # Examples
+///
+/// Taking a screenshot and saving to file:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let cx_screen = w::GetSystemMetrics(co::SM::CXSCREEN);
+/// let cy_screen = w::GetSystemMetrics(co::SM::CYSCREEN);
+///
+/// let hdc_screen = w::HWND::DESKTOP.GetDC()?;
+/// let hbmp = hdc_screen.CreateCompatibleBitmap(cx_screen, cy_screen)?;
+/// {
+///     let hdc_mem = hdc_screen.CreateCompatibleDC()?;
+///     let _hbmp_guard = hdc_mem.SelectObject(&*hbmp)?;
+///     hdc_mem.BitBlt(w::POINT::new(0, 0), w::SIZE::new(cx_screen, cy_screen),
+///         &hdc_screen, w::POINT::new(0, 0), co::ROP::SRCCOPY)?;
+/// }
+///
+/// let dib = w::Dib::from_hbitmap(&hdc_screen, &hbmp)?;
+///
+/// let fo = w::File::open("C:\\Temp\\foo.bmp", w::FileAccess::OpenOrCreateRW)?;
+/// fo.write(&dib.to_bmp(w::DibHeader::Info))?;
+/// # Ok::<_, co::ERROR>(())
+/// ```
+#[derive(Clone)]
+pub struct Dib {
+	width: u32,
+	height: u32,
+	bit_count: u16,
+	palette: Vec<RGBQUAD>,
+	bits: Vec<u8>,
+}
+
+impl Dib {
+	/// Creates a new bitmap with all pixels zeroed.
+	///
+	/// For 1, 4 and 8 bpp, the palette must have at least one and at most
+	/// 2^`bit_count` colors; for 24 and 32 bpp it must be empty. Otherwise,
+	/// [`co::ERROR::INVALID_PARAMETER`](crate::co::ERROR::INVALID_PARAMETER) is
+	/// returned.
+	pub fn new(
+		width: u32,
+		height: u32,
+		bit_count: u16,
+		palette: &[RGBQUAD],
+	) -> SysResult<Self>
+	{
+		let is_palette_ok = match bit_count {
+			1 | 4 | 8 => !palette.is_empty() && palette.len() <= 1 << bit_count,
+			24 | 32 => palette.is_empty(),
+			_ => false,
+		};
+		if !is_palette_ok || width == 0 || height == 0
+			|| width > i32::MAX as _ || height > i32::MAX as _
+		{
+			return Err(co::ERROR::INVALID_PARAMETER);
+		}
+
+		let len = Self::calc_stride(width, bit_count)
+			.and_then(|stride| stride.checked_mul(height as _))
+			.ok_or(co::ERROR::INVALID_PARAMETER)?;
+
+		Ok(Self {
+			width,
+			height,
+			bit_count,
+			palette: palette.to_vec(),
+			bits: vec![0; len],
+		})
+	}
+
+	/// Parses the contents of a `.bmp` file, which starts with a
+	/// [`BITMAPFILEHEADER`](crate::BITMAPFILEHEADER).
+	///
+	/// Returns [`co::ERROR::INVALID_DATA`](crate::co::ERROR::INVALID_DATA) if
+	/// the data is malformed, or
+	/// [`co::ERROR::NOT_SUPPORTED`](crate::co::ERROR::NOT_SUPPORTED) if the
+	/// format is not supported.
+	pub fn from_bmp(data: &[u8]) -> SysResult<Self> {
+		let file_hdr_len = std::mem::size_of::<BITMAPFILEHEADER>();
+		if data.len() < file_hdr_len || data[..2] != *b"BM" {
+			return Err(co::ERROR::INVALID_DATA);
+		}
+		let off_bits = (read_u32(data, 10) as usize)
+			.checked_sub(file_hdr_len)
+			.ok_or(co::ERROR::INVALID_DATA)?;
+		Self::parse(&data[file_hdr_len..], Some(off_bits))
+	}
+
+	/// Parses a packed DIB, which is a `.bmp` file without the
+	/// [`BITMAPFILEHEADER`](crate::BITMAPFILEHEADER), like the data returned
+	/// by [`Clipboard::read_dib`](crate::Clipboard::read_dib).
+	///
+	/// Returns [`co::ERROR::INVALID_DATA`](crate::co::ERROR::INVALID_DATA) if
+	/// the data is malformed, or
+	/// [`co::ERROR::NOT_SUPPORTED`](crate::co::ERROR::NOT_SUPPORTED) if the
+	/// format is not supported.
+	pub fn from_packed(data: &[u8]) -> SysResult<Self> {
+		Self::parse(data, None)
+	}
+
+	/// Copies the pixels of the bitmap with
+	/// [`HDC::GetDIBits`](crate::prelude::gdi_Hdc::GetDIBits), converting them
+	/// to 32 bpp.
+	///
+	/// The bitmap must not be selected into a device context.
+	pub fn from_hbitmap(hdc: &HDC, hbmp: &HBITMAP) -> SysResult<Self> {
+		let mut bmp = BITMAP::default();
+		hbmp.GetObject(&mut bmp)?;
+		let mut new_self = Self::new(
+			bmp.bmWidth.unsigned_abs(), bmp.bmHeight.unsigned_abs(), 32, &[])?;
+
+		let mut bi = BITMAPINFO::default();
+		bi.bmiHeader.biWidth = new_self.width as _;
+		bi.bmiHeader.biHeight = -(new_self.height as i32); // negative: top-down
+		bi.bmiHeader.biPlanes = 1;
+		bi.bmiHeader.biBitCount = 32;
+		bi.bmiHeader.biCompression = co::BI::RGB;
+
+		unsafe {
+			hdc.GetDIBits(hbmp, 0, new_self.height, Some(&mut new_self.bits),
+				&mut bi, co::DIB::RGB_COLORS)?;
+		}
+		Ok(new_self)
+	}
+
+	/// Creates a DIB section with
+	/// [`HDC::CreateDIBSection`](crate::prelude::gdi_Hdc::CreateDIBSection),
+	/// with the same format and pixels of this bitmap.
+	pub fn to_hbitmap(&self, hdc: &HDC) -> SysResult<DeleteObjectDibGuard> {
+		#[repr(C)]
+		struct BitmapInfo256 { // BITMAPINFO followed by the whole color table
+			header: BITMAPINFOHEADER,
+			colors: [RGBQUAD; 256],
+		}
+
+		let mut bi = BitmapInfo256 {
+			header: BITMAPINFOHEADER::default(),
+			colors: [RGBQUAD::default(); 256],
+		};
+		bi.header.biWidth = self.width as _;
+		bi.header.biHeight = -(self.height as i32); // negative: top-down
+		bi.header.biPlanes = 1;
+		bi.header.biBitCount = self.bit_count;
+		bi.header.biCompression = co::BI::RGB;
+		bi.header.biClrUsed = self.palette.len() as _;
+		bi.colors[..self.palette.len()].copy_from_slice(&self.palette);
+
+		let mut hbmp = unsafe {
+			hdc.CreateDIBSection( // the whole color table follows the header
+				&*(&bi as *const _ as *const BITMAPINFO),
+				co::DIB::RGB_COLORS,
+			)?
+		};
+		hbmp.bits_mut().copy_from_slice(&self.bits);
+		Ok(hbmp)
+	}
+
+	/// Serializes the bitmap into the contents of a `.bmp` file, stored
+	/// bottom-up.
+	#[must_use]
+	pub fn to_bmp(&self, header: DibHeader) -> Vec<u8> {
+		let packed = self.to_packed(header);
+
+		let file_hdr_len = std::mem::size_of::<BITMAPFILEHEADER>();
+		let mut bfh = BITMAPFILEHEADER::default();
+		bfh.bfSize = (file_hdr_len + packed.len()) as _;
+		bfh.bfOffBits = (file_hdr_len + packed.len() - self.bits.len()) as _;
+
+		let mut buf = Vec::with_capacity(file_hdr_len + packed.len());
+		buf.extend_from_slice(bfh.serialize());
+		buf.extend_from_slice(&packed);
+		buf
+	}
+
+	/// Serializes the bitmap into a packed DIB, stored bottom-up, which can be
+	/// passed to [`Clipboard::write_dib`](crate::Clipboard::write_dib).
+	#[must_use]
+	pub fn to_packed(&self, header: DibHeader) -> Vec<u8> {
+		let has_alpha = self.bit_count == 32 && header != DibHeader::Info;
+
+		let mut bv5 = BITMAPV5HEADER::default(); // the older headers are prefixes of it
+		bv5.bV5Width = self.width as _;
+		bv5.bV5Height = self.height as _; // positive: bottom-up
+		bv5.bV5Planes = 1;
+		bv5.bV5BitCount = self.bit_count;
+		bv5.bV5SizeImage = self.bits.len() as _;
+		bv5.bV5ClrUsed = self.palette.len() as _;
+		bv5.bV5CSType = co::LCS::sRGB;
+		bv5.bV5Intent = co::LCS_GM::IMAGES;
+		if has_alpha {
+			bv5.bV5Compression = co::BI::BITFIELDS;
+			bv5.bV5RedMask = 0x00ff_0000;
+			bv5.bV5GreenMask = 0x0000_ff00;
+			bv5.bV5BlueMask = 0x0000_00ff;
+			bv5.bV5AlphaMask = 0xff00_0000;
+		} else {
+			bv5.bV5Compression = co::BI::RGB;
+		}
+
+		let hdr_len = match header {
+			DibHeader::Info => std::mem::size_of::<BITMAPINFOHEADER>(),
+			DibHeader::V4 => std::mem::size_of::<BITMAPV4HEADER>(),
+			DibHeader::V5 => std::mem::size_of::<BITMAPV5HEADER>(),
+		};
+		let mut buf = Vec::with_capacity(hdr_len + self.palette.len() * 4 + self.bits.len());
+		buf.extend_from_slice(&(hdr_len as u32).to_le_bytes());
+		buf.extend_from_slice(&bv5.serialize()[4..hdr_len]);
+
+		self.palette.iter()
+			.for_each(|c| buf.extend_from_slice(&[c.rgbBlue, c.rgbGreen, c.rgbRed, 0]));
+		(0..self.height).rev()
+			.for_each(|y| buf.extend_from_slice(self.row(y)));
+		buf
+	}
+
+	/// Returns a copy of the bitmap converted to 32 bpp. Pixels of images
+	/// without alpha channel are fully opaque.
+	///
+	/// Returns [`co::ERROR::INVALID_PARAMETER`](crate::co::ERROR::INVALID_PARAMETER)
+	/// if the 32 bpp pixel buffer size overflows.
+	pub fn to_32bpp(&self) -> SysResult<Self> {
+		if self.bit_count == 32 {
+			return Ok(self.clone());
+		}
+
+		let mut new_self = Self::new(self.width, self.height, 32, &[])?;
+		for y in 0..self.height {
+			for x in 0..self.width {
+				let color = self.pixel(x, y);
+				let i = x as usize * 4;
+				new_self.row_mut(y)[i..i + 4].copy_from_slice(
+					&[color.GetBValue(), color.GetGValue(), color.GetRValue(), 0xff]);
+			}
+		}
+		Ok(new_self)
+	}
+
+	/// Returns the width, in pixels.
+	#[must_use]
+	pub const fn width(&self) -> u32 {
+		self.width
+	}
+
+	/// Returns the height, in pixels.
+	#[must_use]
+	pub const fn height(&self) -> u32 {
+		self.height
+	}
+
+	/// Returns the number of bits per pixel: 1, 4, 8, 24 or 32.
+	#[must_use]
+	pub const fn bit_count(&self) -> u16 {
+		self.bit_count
+	}
+
+	/// Returns the color table, which is empty for 24 and 32 bpp.
+	#[must_use]
+	pub fn palette(&self) -> &[RGBQUAD] {
+		&self.palette
+	}
+
+	/// Returns the color table, which is empty for 24 and 32 bpp.
+	#[must_use]
+	pub fn palette_mut(&mut self) -> &mut [RGBQUAD] {
+		&mut self.palette
+	}
+
+	/// Returns the number of bytes of each row, including the padding.
+	#[must_use]
+	pub fn stride(&self) -> usize {
+		self.bits.len() / self.height as usize
+	}
+
+	/// Returns all the pixels, top-down.
+	#[must_use]
+	pub fn bits(&self) -> &[u8] {
+		&self.bits
+	}
+
+	/// Returns all the pixels, top-down.
+	#[must_use]
+	pub fn bits_mut(&mut self) -> &mut [u8] {
+		&mut self.bits
+	}
+
+	/// Returns the pixels of the given row, counted from the top, including
+	/// the padding.
+	///
+	/// # Panics
+	///
+	/// Panics if `y` is out of bounds.
+	#[must_use]
+	pub fn row(&self, y: u32) -> &[u8] {
+		let stride = self.stride();
+		&self.bits[y as usize * stride..][..stride]
+	}
+
+	/// Returns the pixels of the given row, counted from the top, including
+	/// the padding.
+	///
+	/// # Panics
+	///
+	/// Panics if `y` is out of bounds.
+	#[must_use]
+	pub fn row_mut(&mut self, y: u32) -> &mut [u8] {
+		let stride = self.stride();
+		&mut self.bits[y as usize * stride..][..stride]
+	}
+
+	/// Returns the color of the given pixel. For 1, 4 and 8 bpp, the color is
+	/// taken from the palette.
+	///
+	/// # Panics
+	///
+	/// Panics if `x` or `y` is out of bounds.
+	#[must_use]
+	pub fn pixel(&self, x: u32, y: u32) -> COLORREF {
+		match self.bit_count {
+			24 | 32 => {
+				assert!(x < self.width, "Pixel out of bounds.");
+				let i = x as usize * (self.bit_count as usize / 8);
+				let row = self.row(y);
+				COLORREF::new(row[i + 2], row[i + 1], row[i])
+			},
+			_ => {
+				let c = self.palette.get(self.index(x, y) as usize)
+					.copied()
+					.unwrap_or_default(); // index beyond palette is black
+				COLORREF::new(c.rgbRed, c.rgbGreen, c.rgbBlue)
+			},
+		}
+	}
+
+	/// Sets the color of the given pixel. The alpha of 32 bpp images is left
+	/// untouched.
+	///
+	/// # Panics
+	///
+	/// Panics if `x` or `y` is out of bounds, or if the bitmap has a palette –
+	/// in this case, use [`Dib::set_index`](crate::Dib::set_index).
+	pub fn set_pixel(&mut self, x: u32, y: u32, color: COLORREF) {
+		assert!(self.palette.is_empty(), "Bitmap has a palette.");
+		assert!(x < self.width, "Pixel out of bounds.");
+		let i = x as usize * (self.bit_count as usize / 8);
+		self.row_mut(y)[i..i + 3].copy_from_slice(
+			&[color.GetBValue(), color.GetGValue(), color.GetRValue()]);
+	}
+
+	/// Returns the palette index of the given pixel.
+	///
+	/// # Panics
+	///
+	/// Panics if `x` or `y` is out of bounds, or if the bitmap has no palette.
+	#[must_use]
+	pub fn index(&self, x: u32, y: u32) -> u8 {
+		let (byte_idx, shift) = self.index_pos(x);
+		let mask = ((1u16 << self.bit_count) - 1) as u8;
+		(self.row(y)[byte_idx] >> shift) & mask
+	}
+
+	/// Sets the palette index of the given pixel.
+	///
+	/// # Panics
+	///
+	/// Panics if `x` or `y` is out of bounds, if the bitmap has no palette, or
+	/// if `index` doesn't fit the bit count.
+	pub fn set_index(&mut self, x: u32, y: u32, index: u8) {
+		let (byte_idx, shift) = self.index_pos(x);
+		let mask = ((1u16 << self.bit_count) - 1) as u8;
+		assert!(index <= mask, "Index too large for bit count.");
+		let byte = &mut self.row_mut(y)[byte_idx];
+		*byte = (*byte & !(mask << shift)) | (index << shift);
+	}
+
+	fn calc_stride(width: u32, bit_count: u16) -> Option<usize> {
+		(width as usize).checked_mul(bit_count as _)?
+			.checked_add(31)
+			.map(|bits| bits / 32 * 4)
+	}
+
+	/// Returns the byte index within the row, and the bit shift within the
+	/// byte, of an indexed pixel.
+	fn index_pos(&self, x: u32) -> (usize, u32) {
+		assert!(!self.palette.is_empty(), "Bitmap has no palette.");
+		assert!(x < self.width, "Pixel out of bounds.");
+		let per_byte = 8 / self.bit_count as u32;
+		let byte_idx = (x / per_byte) as usize;
+		let shift = (per_byte - 1 - x % per_byte) * self.bit_count as u32; // leftmost pixel in high bits
+		(byte_idx, shift)
+	}
+
+	/// Parses a packed DIB; if `off_bits` is given, the pixels start at this
+	/// offset, otherwise right after the color table.
+	fn parse(dib: &[u8], off_bits: Option<usize>) -> SysResult<Self> {
+		let hdr_len = dib.get(..4)
+			.map(|_| read_u32(dib, 0) as usize)
+			.ok_or(co::ERROR::INVALID_DATA)?;
+		if hdr_len < std::mem::size_of::<BITMAPINFOHEADER>() {
+			return Err(co::ERROR::NOT_SUPPORTED); // BITMAPCOREHEADER
+		} else if hdr_len > dib.len() {
+			return Err(co::ERROR::INVALID_DATA);
+		}
+
+		let mut hdr = BITMAPV5HEADER::default(); // fields not in older headers stay zeroed
+		unsafe {
+			std::ptr::copy_nonoverlapping(
+				dib.as_ptr(),
+				&mut hdr as *mut _ as *mut u8,
+				hdr_len.min(std::mem::size_of::<BITMAPV5HEADER>()),
+			);
+		}
+		if hdr.bV5Width <= 0 || hdr.bV5Height == 0 || hdr.bV5Height == i32::MIN {
+			return Err(co::ERROR::INVALID_DATA);
+		}
+
+		let mut off = hdr_len;
+		let masks = match (hdr.bV5Compression, hdr.bV5BitCount) {
+			(co::BI::RGB, 1 | 4 | 8 | 24 | 32) => None,
+			(co::BI::BITFIELDS, 32) => Some(if hdr_len >= 52 { // masks are within the header
+				[hdr.bV5RedMask, hdr.bV5GreenMask, hdr.bV5BlueMask, hdr.bV5AlphaMask]
+			} else { // masks follow the BITMAPINFOHEADER
+				let m = dib.get(off..off + 12).ok_or(co::ERROR::INVALID_DATA)?;
+				off += 12;
+				[read_u32(m, 0), read_u32(m, 4), read_u32(m, 8), 0]
+			}),
+			_ => return Err(co::ERROR::NOT_SUPPORTED),
+		};
+
+		let bit_count = hdr.bV5BitCount;
+		let num_colors = match hdr.bV5ClrUsed as usize {
+			0 if bit_count <= 8 => 1 << bit_count,
+			n => n,
+		};
+		if bit_count <= 8 && num_colors > 1 << bit_count {
+			return Err(co::ERROR::INVALID_DATA);
+		}
+		let table = num_colors.checked_mul(4)
+			.and_then(|len| dib.get(off..)?.get(..len))
+			.ok_or(co::ERROR::INVALID_DATA)?;
+		off += table.len();
+
+		let palette = if bit_count <= 8 {
+			table.chunks_exact(4)
+				.map(|c| {
+					let mut color = RGBQUAD::default();
+					color.rgbBlue = c[0];
+					color.rgbGreen = c[1];
+					color.rgbRed = c[2];
+					color
+				})
+				.collect::<Vec<_>>()
+		} else {
+			Vec::new() // color table of direct formats is just an optimization hint
+		};
+
+		let is_top_down = hdr.bV5Height < 0;
+		let height = hdr.bV5Height.unsigned_abs();
+		let pixels_off = off_bits.unwrap_or(off);
+		let pixels_len = Self::calc_stride(hdr.bV5Width as _, bit_count)
+			.and_then(|stride| stride.checked_mul(height as _))
+			.ok_or(co::ERROR::INVALID_DATA)?;
+		match pixels_off.checked_add(pixels_len) {
+			Some(end) if end <= dib.len() => {},
+			_ => return Err(co::ERROR::INVALID_DATA), // validate before allocating
+		}
+
+		let mut new_self = Self::new(hdr.bV5Width as _, height, bit_count, &palette)
+			.map_err(|_| co::ERROR::INVALID_DATA)?;
+
+		let stride = new_self.stride();
+		let src = &dib[pixels_off..pixels_off + pixels_len];
+		for (y, src_row) in src.chunks_exact(stride).enumerate() {
+			let y = if is_top_down { y as u32 } else { height - 1 - y as u32 };
+			new_self.row_mut(y).copy_from_slice(src_row);
+		}
+
+		if let Some(masks) = masks {
+			new_self.apply_masks(masks);
+		}
+		Ok(new_self)
+	}
+
+	/// Converts 32 bpp pixels with arbitrary bit fields to BGRA.
+	fn apply_masks(&mut self, [red, green, blue, alpha]: [u32; 4]) {
+		if red == 0x00ff_0000 && green == 0x0000_ff00 && blue == 0x0000_00ff
+			&& (alpha == 0xff00_0000 || alpha == 0)
+		{
+			return; // already BGRA
+		}
+
+		fn channel(val: u32, mask: u32) -> u8 {
+			if mask == 0 {
+				return 0;
+			}
+			let shift = mask.trailing_zeros();
+			let num_bits = (mask >> shift).count_ones();
+			let val = (val & mask) >> shift;
+			if num_bits >= 8 {
+				(val >> (num_bits - 8)) as _
+			} else {
+				(val * 255 / ((1 << num_bits) - 1)) as _
+			}
+		}
+
+		for px in self.bits.chunks_exact_mut(4) {
+			let val = read_u32(px, 0);
+			px.copy_from_slice(&[
+				channel(val, blue),
+				channel(val, green),
+				channel(val, red),
+				channel(val, alpha),
+			]);
+		}
+	}
+}
+
+fn read_u32(data: &[u8], off: usize) -> u32 {
+	u32::from_le_bytes([data[off], data[off + 1], data[off + 2], data[off + 3]])
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const HEADERS: [DibHeader; 3] = [DibHeader::Info, DibHeader::V4, DibHeader::V5];
+
+	/// 5x3 image, so rows of all bit counts have padding.
+	fn sample(bit_count: u16) -> Dib {
+		let palette = if bit_count <= 8 {
+			(0..1u16 << bit_count)
+				.map(|i| {
+					let mut color = RGBQUAD::default();
+					color.rgbBlue = i as u8;
+					color.rgbGreen = 0xff - i as u8;
+					color.rgbRed = (i * 7) as u8;
+					color
+				})
+				.collect::<Vec<_>>()
+		} else {
+			Vec::new()
+		};
+
+		let mut dib = Dib::new(5, 3, bit_count, &palette).unwrap();
+		for y in 0..3 {
+			for x in 0..5 {
+				let n = (y * 5 + x) as u8;
+				match bit_count {
+					1 | 4 | 8 => dib.set_index(x, y, (n as usize % palette.len()) as u8),
+					24 => dib.set_pixel(x, y, COLORREF::new(n, n * 2, n * 3)),
+					_ => dib.row_mut(y)[x as usize * 4..][..4]
+						.copy_from_slice(&[n, n * 2, n * 3, n * 10]),
+				}
+			}
+		}
+		dib
+	}
+
+	fn assert_same(a: &Dib, b: &Dib) {
+		assert_eq!((a.width(), a.height(), a.bit_count()), (b.width(), b.height(), b.bit_count()));
+		assert!(a.palette() == b.palette());
+		assert_eq!(a.bits(), b.bits());
+	}
+
+	/// Converts a bottom-up packed DIB to top-down.
+	fn flip_packed(packed: &[u8], stride: usize, height: usize) -> Vec<u8> {
+		let pixels_off = packed.len() - stride * height;
+		let mut flipped = packed[..pixels_off].to_vec();
+		flipped[8..12].copy_from_slice(&(-(height as i32)).to_le_bytes());
+		packed[pixels_off..].chunks_exact(stride)
+			.rev()
+			.for_each(|row| flipped.extend_from_slice(row));
+		flipped
+	}
+
+	fn info_header(width: i32, height: i32, bit_count: u16) -> Vec<u8> {
+		let mut hdr = Vec::new();
+		hdr.extend_from_slice(&40u32.to_le_bytes());
+		hdr.extend_from_slice(&width.to_le_bytes());
+		hdr.extend_from_slice(&height.to_le_bytes());
+		hdr.extend_from_slice(&1u16.to_le_bytes());
+		hdr.extend_from_slice(&bit_count.to_le_bytes());
+		hdr.resize(40, 0); // BI_RGB, no palette
+		hdr
+	}
+
+	#[test]
+	fn round_trip_packed() {
+		for bit_count in [1, 4, 8, 24, 32] {
+			let dib = sample(bit_count);
+			for header in HEADERS {
+				let parsed = Dib::from_packed(&dib.to_packed(header)).unwrap();
+				assert_same(&dib, &parsed);
+			}
+		}
+	}
+
+	#[test]
+	fn round_trip_bmp() {
+		for bit_count in [1, 4, 8, 24, 32] {
+			let dib = sample(bit_count);
+			for header in HEADERS {
+				let bmp = dib.to_bmp(header);
+				assert_eq!(&bmp[..2], b"BM");
+				assert_eq!(read_u32(&bmp, 2) as usize, bmp.len());
+				assert_same(&dib, &Dib::from_bmp(&bmp).unwrap());
+			}
+		}
+	}
+
+	#[test]
+	fn top_down() {
+		for bit_count in [1, 4, 8, 24, 32] {
+			let dib = sample(bit_count);
+			for header in HEADERS {
+				let packed = dib.to_packed(header);
+				let flipped = flip_packed(&packed, dib.stride(), dib.height() as _);
+				assert_ne!(packed, flipped);
+				assert_same(&dib, &Dib::from_packed(&flipped).unwrap());
+			}
+		}
+	}
+
+	#[test]
+	fn bitfields_after_info_header() {
+		let mut packed = info_header(2, 1, 32);
+		packed[16..20].copy_from_slice(&co::BI::BITFIELDS.raw().to_le_bytes());
+		for mask in [0x0000_00ffu32, 0x0000_ff00, 0x00ff_0000] { // RGBA instead of BGRA
+			packed.extend_from_slice(&mask.to_le_bytes());
+		}
+		packed.extend_from_slice(&[0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+
+		let dib = Dib::from_packed(&packed).unwrap();
+		assert_eq!(dib.pixel(0, 0), COLORREF::new(0x11, 0x22, 0x33));
+		assert_eq!(dib.pixel(1, 0), COLORREF::new(0xaa, 0xbb, 0xcc));
+	}
+
+	#[test]
+	fn truncated() {
+		for bit_count in [1, 8, 24, 32] {
+			let packed = sample(bit_count).to_packed(DibHeader::V5);
+			for len in [0, 3, 39, 124, packed.len() - 1] {
+				assert_eq!(Dib::from_packed(&packed[..len]).err(), Some(co::ERROR::INVALID_DATA));
+			}
+
+			let bmp = sample(bit_count).to_bmp(DibHeader::Info);
+			for len in [0, 13, 20, bmp.len() - 1] {
+				assert_eq!(Dib::from_bmp(&bmp[..len]).err(), Some(co::ERROR::INVALID_DATA));
+			}
+		}
+	}
+
+	#[test]
+	fn huge_dimensions() {
+		let packed = info_header(i32::MAX, i32::MAX, 32); // must fail without allocating
+		assert_eq!(Dib::from_packed(&packed).err(), Some(co::ERROR::INVALID_DATA));
+		let packed = info_header(i32::MAX, i32::MIN + 1, 32); // top-down
+		assert_eq!(Dib::from_packed(&packed).err(), Some(co::ERROR::INVALID_DATA));
+		let packed = info_header(0x10_0000, 0x10_0000, 24);
+		assert_eq!(Dib::from_packed(&packed).err(), Some(co::ERROR::INVALID_DATA));
+	}
+
+	#[test]
+	fn bad_bmp_offset() {
+		let mut bmp = sample(24).to_bmp(DibHeader::Info);
+		let len = bmp.len() as u32;
+		bmp[10..14].copy_from_slice(&len.to_le_bytes()); // past the header, no pixels
+		assert_eq!(Dib::from_bmp(&bmp).err(), Some(co::ERROR::INVALID_DATA));
+		bmp[10..14].copy_from_slice(&u32::MAX.to_le_bytes());
+		assert_eq!(Dib::from_bmp(&bmp).err(), Some(co::ERROR::INVALID_DATA));
+		bmp[..2].copy_from_slice(b"XX");
+		assert_eq!(Dib::from_bmp(&bmp).err(), Some(co::ERROR::INVALID_DATA));
+	}
+
+	#[test]
+	fn unsupported() {
+		let mut core = 12u32.to_le_bytes().to_vec(); // BITMAPCOREHEADER
+		core.resize(16, 0);
+		assert_eq!(Dib::from_packed(&core).err(), Some(co::ERROR::NOT_SUPPORTED));
+
+		let packed = info_header(1, 1, 16);
+		assert_eq!(Dib::from_packed(&packed).err(), Some(co::ERROR::NOT_SUPPORTED));
+	}
+
+	#[test]
+	fn to_32bpp() {
+		for bit_count in [1, 4, 8, 24] {
+			let dib = sample(bit_count);
+			let dib32 = dib.to_32bpp().unwrap();
+			assert_eq!(dib32.bit_count(), 32);
+			for y in 0..dib.height() {
+				for x in 0..dib.width() {
+					assert_eq!(dib.pixel(x, y), dib32.pixel(x, y));
+					assert_eq!(dib32.row(y)[x as usize * 4 + 3], 0xff);
+				}
+			}
+		}
+	}
+}
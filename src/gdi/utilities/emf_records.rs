@@ -0,0 +1,300 @@
+use crate::co;
+use crate::decl::*;
+
+/// Value of [`ENHMETAHEADER`](crate::ENHMETAHEADER) `dSignature`, which is
+/// `" EMF"` in ASCII.
+const ENHMETA_SIGNATURE: u32 = 0x464d_4520;
+
+/// A single record of an enhanced metafile, borrowed from a byte buffer.
+///
+/// Records are returned by [`EmfRecords`](crate::EmfRecords) and
+/// [`HENHMETAFILE::EnumEnhMetaFile`](crate::prelude::gdi_Henhmetafile::EnumEnhMetaFile).
+#[derive(Clone, Copy)]
+pub struct EmfRecord<'a> {
+	data: &'a [u8],
+}
+
+impl<'a> EmfRecord<'a> {
+	/// Parses a single record from the beginning of the buffer, which may
+	/// contain further records.
+	///
+	/// Returns [`co::ERROR::INVALID_DATA`](crate::co::ERROR::INVALID_DATA) if
+	/// the record size is not a multiple of 4, or if it overflows the buffer.
+	#[allow(clippy::manual_is_multiple_of)] // is_multiple_of needs Rust 1.87
+	pub fn parse(data: &'a [u8]) -> SysResult<Self> {
+		if data.len() < std::mem::size_of::<EMR>() {
+			return Err(co::ERROR::INVALID_DATA);
+		}
+		let n_size = read_u32(data, 4) as usize;
+		if n_size < std::mem::size_of::<EMR>() || n_size % 4 != 0 || n_size > data.len() {
+			return Err(co::ERROR::INVALID_DATA);
+		}
+		Ok(Self { data: &data[..n_size] })
+	}
+
+	/// Returns the record header.
+	#[must_use]
+	pub fn emr(&self) -> EMR {
+		EMR {
+			iType: self.kind(),
+			nSize: self.data.len() as _,
+		}
+	}
+
+	/// Returns the record type.
+	#[must_use]
+	pub fn kind(&self) -> co::EMR {
+		unsafe { co::EMR::from_raw(read_u32(self.data, 0)) }
+	}
+
+	/// Returns the whole record, including the header.
+	#[must_use]
+	pub const fn bytes(&self) -> &'a [u8] {
+		self.data
+	}
+
+	/// Returns the record parameters, which follow the header.
+	#[must_use]
+	pub fn params(&self) -> &'a [u8] {
+		&self.data[std::mem::size_of::<EMR>()..]
+	}
+
+	/// If this is a [`co::EMR::HEADER`](crate::co::EMR::HEADER) record,
+	/// returns its contents.
+	///
+	/// Fields not present in older metafiles, which have smaller headers, are
+	/// zeroed.
+	#[must_use]
+	pub fn header(&self) -> Option<ENHMETAHEADER> {
+		if self.kind() != co::EMR::HEADER {
+			return None;
+		}
+
+		let mut emh = ENHMETAHEADER::default();
+		let len = self.data.len().min(std::mem::size_of::<ENHMETAHEADER>());
+		unsafe {
+			std::ptr::copy_nonoverlapping(
+				self.data.as_ptr(),
+				&mut emh as *mut _ as *mut u8,
+				len,
+			);
+		}
+		Some(emh)
+	}
+
+	/// If this is a [`co::EMR::HEADER`](crate::co::EMR::HEADER) record with a
+	/// description, returns the application name and the picture title.
+	#[must_use]
+	pub fn description(&self) -> Option<(String, String)> {
+		let emh = self.header()?;
+		let off = emh.offDescription as usize;
+		let len = emh.nDescription as usize * 2;
+		if emh.nDescription == 0 || off.checked_add(len)? > self.data.len() {
+			return None;
+		}
+
+		let chars = self.data[off..off + len]
+			.chunks_exact(2)
+			.map(|ch| u16::from_le_bytes([ch[0], ch[1]]))
+			.collect::<Vec<_>>();
+		let mut parts = chars.split(|ch| *ch == 0)
+			.map(String::from_utf16_lossy);
+		Some((parts.next()?, parts.next().unwrap_or_default()))
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// Iterator over the records of an enhanced metafile, parsed from a byte
+/// buffer – like the contents of an `.emf` file, or the data returned by
+/// [`HENHMETAFILE::GetEnhMetaFileBits`](crate::prelude::gdi_Henhmetafile::GetEnhMetaFileBits).
+///
+/// The first record must be a valid [`co::EMR::HEADER`](crate::co::EMR::HEADER),
+/// and the iteration stops after the [`co::EMR::EOF`](crate::co::EMR::EOF)
+/// record. If malformed data is found, a
+/// [`co::ERROR::INVALID_DATA`](crate::co::ERROR::INVALID_DATA) error is
+/// returned and the iteration stops.
+///
+/// # Examples
+///
+/// Counting the rectangles of a metafile:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let emf_bytes: Vec<u8>; // initialized somewhere
+/// # let emf_bytes = Vec::<u8>::new();
+///
+/// let mut num_rects = 0;
+/// for rec in w::EmfRecords::new(&emf_bytes) {
+///     let rec = rec?;
+///     if let Some(emh) = rec.header() {
+///         println!("Bounds: {}", emh.rclBounds);
+///     } else if rec.kind() == co::EMR::RECTANGLE {
+///         num_rects += 1;
+///     }
+/// }
+/// # Ok::<_, co::ERROR>(())
+/// ```
+pub struct EmfRecords<'a> {
+	data: &'a [u8],
+	offset: usize,
+	finished: bool,
+}
+
+impl<'a> Iterator for EmfRecords<'a> {
+	type Item = SysResult<EmfRecord<'a>>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.finished {
+			return None;
+		}
+
+		let rec = match EmfRecord::parse(&self.data[self.offset..]) {
+			Ok(rec) => rec,
+			Err(e) => {
+				self.finished = true;
+				return Some(Err(e));
+			},
+		};
+
+		if self.offset == 0 {
+			let is_valid_header = rec.header()
+				.is_some_and(|emh| emh.dSignature == ENHMETA_SIGNATURE);
+			if !is_valid_header {
+				self.finished = true;
+				return Some(Err(co::ERROR::INVALID_DATA));
+			}
+		}
+
+		self.offset += rec.bytes().len();
+		if rec.kind() == co::EMR::EOF || self.offset == self.data.len() {
+			self.finished = true;
+		}
+		Some(Ok(rec))
+	}
+}
+
+impl<'a> EmfRecords<'a> {
+	/// Creates a new iterator over the given buffer.
+	#[must_use]
+	pub const fn new(data: &'a [u8]) -> Self {
+		Self { data, offset: 0, finished: false }
+	}
+}
+
+fn read_u32(data: &[u8], off: usize) -> u32 {
+	u32::from_le_bytes([data[off], data[off + 1], data[off + 2], data[off + 3]])
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn record(kind: co::EMR, params: &[u8]) -> Vec<u8> {
+		let mut rec = Vec::<u8>::default();
+		rec.extend_from_slice(&kind.raw().to_le_bytes());
+		rec.extend_from_slice(&(8 + params.len() as u32).to_le_bytes());
+		rec.extend_from_slice(params);
+		rec
+	}
+
+	fn header(description: &str) -> Vec<u8> {
+		let desc = description.encode_utf16()
+			.flat_map(|ch| ch.to_le_bytes())
+			.collect::<Vec<_>>();
+		let mut params = vec![0u8; 100]; // ENHMETAHEADER without EMR
+		params[32..36].copy_from_slice(&ENHMETA_SIGNATURE.to_le_bytes());
+		if !desc.is_empty() {
+			params[52..56].copy_from_slice(&(desc.len() as u32 / 2).to_le_bytes());
+			params[56..60].copy_from_slice(&108u32.to_le_bytes());
+			params.extend_from_slice(&desc);
+			params.resize(params.len().div_ceil(4) * 4, 0);
+		}
+		record(co::EMR::HEADER, &params)
+	}
+
+	fn kinds(data: &[u8]) -> Vec<SysResult<co::EMR>> {
+		EmfRecords::new(data)
+			.map(|rec| rec.map(|rec| rec.kind()))
+			.collect()
+	}
+
+	#[test]
+	fn well_formed() {
+		let data = [
+			header("App\0Title\0"),
+			record(co::EMR::RECTANGLE, &[0; 16]),
+			record(co::EMR::EOF, &[0; 12]),
+		].concat();
+
+		assert_eq!(kinds(&data), [Ok(co::EMR::HEADER), Ok(co::EMR::RECTANGLE), Ok(co::EMR::EOF)]);
+
+		let first = EmfRecords::new(&data).next().unwrap().unwrap();
+		let emh = first.header().unwrap();
+		assert_eq!(emh.dSignature, ENHMETA_SIGNATURE);
+		assert_eq!(emh.nDescription, 10);
+		assert_eq!(first.description(), Some(("App".to_owned(), "Title".to_owned())));
+
+		let rect = EmfRecord::parse(&data[first.bytes().len()..]).unwrap();
+		assert_eq!(rect.emr().nSize, 24);
+		assert_eq!(rect.params().len(), 16);
+		assert!(rect.header().is_none());
+	}
+
+	#[test]
+	fn stops_at_eof() {
+		let data = [
+			header(""),
+			record(co::EMR::EOF, &[0; 12]),
+			vec![0xff; 7], // trailing garbage is never read
+		].concat();
+		assert_eq!(kinds(&data), [Ok(co::EMR::HEADER), Ok(co::EMR::EOF)]);
+	}
+
+	#[test]
+	fn stops_at_end_without_eof() {
+		let data = [header(""), record(co::EMR::RECTANGLE, &[0; 16])].concat();
+		assert_eq!(kinds(&data), [Ok(co::EMR::HEADER), Ok(co::EMR::RECTANGLE)]);
+	}
+
+	#[test]
+	fn truncated() {
+		let data = [
+			header(""),
+			record(co::EMR::RECTANGLE, &[0; 16]),
+		].concat();
+		let data = &data[..data.len() - 4];
+		assert_eq!(kinds(data), [Ok(co::EMR::HEADER), Err(co::ERROR::INVALID_DATA)]);
+
+		assert_eq!(kinds(&header("")[..50]), [Err(co::ERROR::INVALID_DATA)]);
+		assert_eq!(kinds(&[1, 0, 0]), [Err(co::ERROR::INVALID_DATA)]);
+		assert_eq!(kinds(&[]), [Err(co::ERROR::INVALID_DATA)]);
+	}
+
+	#[test]
+	fn size_not_multiple_of_4() {
+		let mut rect = record(co::EMR::RECTANGLE, &[0; 16]);
+		rect[4..8].copy_from_slice(&22u32.to_le_bytes());
+		let data = [header(""), rect].concat();
+		assert_eq!(kinds(&data), [Ok(co::EMR::HEADER), Err(co::ERROR::INVALID_DATA)]);
+	}
+
+	#[test]
+	fn zero_size() {
+		let mut rect = record(co::EMR::RECTANGLE, &[0; 16]);
+		rect[4..8].copy_from_slice(&0u32.to_le_bytes());
+		let data = [header(""), rect, record(co::EMR::EOF, &[0; 12])].concat();
+		assert_eq!(kinds(&data), [Ok(co::EMR::HEADER), Err(co::ERROR::INVALID_DATA)]);
+	}
+
+	#[test]
+	fn bad_header() {
+		let data = [record(co::EMR::RECTANGLE, &[0; 16]), record(co::EMR::EOF, &[0; 12])].concat();
+		assert_eq!(kinds(&data), [Err(co::ERROR::INVALID_DATA)]);
+
+		let mut data = [header(""), record(co::EMR::EOF, &[0; 12])].concat();
+		data[40] = b'X'; // signature
+		assert_eq!(kinds(&data), [Err(co::ERROR::INVALID_DATA)]);
+	}
+}
@@ -0,0 +1,547 @@
+use crate::co;
+use crate::decl::*;
+use crate::guard::*;
+use crate::prelude::*;
+
+/// Signature of PNG files.
+const PNG_SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+
+/// Contents of an `.ico` or `.cur` file, which is a container of images of
+/// different sizes and bit depths.
+///
+/// The parsing and serialization are implemented in pure Rust, without
+/// calling the system.
+///
+/// # Examples
+///
+/// Loading the icon which best fits a window, considering its DPI:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let hwnd: w::HWND; // initialized somewhere
+/// # let hwnd = w::HWND::NULL;
+/// let ico_bytes: Vec<u8>; // initialized somewhere
+/// # let ico_bytes = Vec::<u8>::new();
+///
+/// let ico = w::IconFile::parse(&ico_bytes)?;
+/// let img = ico.best_image_for_dpi(32, hwnd.GetDpiForWindow())
+///     .ok_or(co::ERROR::NOT_FOUND)?;
+/// let hicon = img.to_hicon()?;
+/// # Ok::<_, co::ERROR>(())
+/// ```
+///
+/// Saving an icon to a file:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let hicon: w::HICON; // initialized somewhere
+/// # let hicon = w::HICON::NULL;
+///
+/// let ico = w::IconFile {
+///     is_cursor: false,
+///     images: vec![w::IconImage::from_hicon(&hicon)?],
+/// };
+///
+/// let fo = w::File::open("C:\\Temp\\foo.ico", w::FileAccess::OpenOrCreateRW)?;
+/// fo.write(&ico.serialize())?;
+/// # Ok::<_, winsafe::co::ERROR>(())
+/// ```
+#[derive(Clone, Default)]
+pub struct IconFile {
+	/// Whether this is a `.cur` file, whose images have hotspots.
+	pub is_cursor: bool,
+	/// The images.
+	pub images: Vec<IconImage>,
+}
+
+impl IconFile {
+	/// Parses the contents of an `.ico` or `.cur` file.
+	///
+	/// Returns [`co::ERROR::INVALID_DATA`](crate::co::ERROR::INVALID_DATA) if
+	/// the data is malformed.
+	pub fn parse(data: &[u8]) -> SysResult<Self> {
+		if data.len() < 6 || read_u16(data, 0) != 0 {
+			return Err(co::ERROR::INVALID_DATA);
+		}
+		let is_cursor = match read_u16(data, 2) {
+			1 => false,
+			2 => true,
+			_ => return Err(co::ERROR::INVALID_DATA),
+		};
+
+		let images = (0..read_u16(data, 4) as usize)
+			.map(|i| {
+				let entry = data.get(6 + i * 16..)
+					.and_then(|entry| entry.get(..16))
+					.ok_or(co::ERROR::INVALID_DATA)?;
+				let (len, off) = (read_u32(entry, 8) as usize, read_u32(entry, 12) as usize);
+				let payload = data.get(off..)
+					.and_then(|payload| payload.get(..len))
+					.ok_or(co::ERROR::INVALID_DATA)?;
+
+				let mut img = if payload.starts_with(&PNG_SIGNATURE) {
+					IconImage::from_png(payload.to_vec())?
+				} else {
+					IconImage::from_dib_payload(payload.to_vec())?
+				};
+				if is_cursor {
+					img.hotspot = (read_u16(entry, 4), read_u16(entry, 6));
+				}
+				Ok(img)
+			})
+			.collect::<SysResult<Vec<_>>>()?;
+
+		Ok(Self { is_cursor, images })
+	}
+
+	/// Serializes the images into the contents of an `.ico` or `.cur` file.
+	#[must_use]
+	pub fn serialize(&self) -> Vec<u8> {
+		let dir_len = 6 + self.images.len() * 16;
+		let mut buf = Vec::with_capacity(
+			dir_len + self.images.iter().map(|img| img.data.len()).sum::<usize>());
+
+		buf.extend_from_slice(&0u16.to_le_bytes());
+		buf.extend_from_slice(&(if self.is_cursor { 2u16 } else { 1u16 }).to_le_bytes());
+		buf.extend_from_slice(&(self.images.len() as u16).to_le_bytes());
+
+		let mut off = dir_len;
+		for img in self.images.iter() {
+			let dim_byte = |dim: u32| if dim >= 256 { 0 } else { dim as u8 }; // zero means 256
+			buf.push(dim_byte(img.width));
+			buf.push(dim_byte(img.height));
+			buf.push(if img.bit_count < 8 { 1 << img.bit_count } else { 0 });
+			buf.push(0);
+			if self.is_cursor {
+				buf.extend_from_slice(&img.hotspot.0.to_le_bytes());
+				buf.extend_from_slice(&img.hotspot.1.to_le_bytes());
+			} else {
+				buf.extend_from_slice(&1u16.to_le_bytes()); // planes
+				buf.extend_from_slice(&img.bit_count.to_le_bytes());
+			}
+			buf.extend_from_slice(&(img.data.len() as u32).to_le_bytes());
+			buf.extend_from_slice(&(off as u32).to_le_bytes());
+			off += img.data.len();
+		}
+
+		self.images.iter()
+			.for_each(|img| buf.extend_from_slice(&img.data));
+		buf
+	}
+
+	/// Returns the image which best fits a square of the given size, in
+	/// pixels.
+	///
+	/// An image of the exact size is preferred, then the smallest larger one,
+	/// which looks better when scaled down, then the largest smaller one.
+	/// Among images of the same size, the one with more bits per pixel is
+	/// chosen.
+	#[must_use]
+	pub fn best_image(&self, size: u32) -> Option<&IconImage> {
+		self.images.iter()
+			.min_by_key(|img| {
+				let img_size = img.width.max(img.height);
+				let distance = if img_size >= size {
+					(false, img_size - size)
+				} else {
+					(true, size - img_size)
+				};
+				(distance, std::cmp::Reverse(img.bit_count))
+			})
+	}
+
+	/// Returns the image which best fits the given size at 96 DPI – like 16
+	/// for small icons and 32 for large ones – scaled to the given DPI.
+	#[must_use]
+	pub fn best_image_for_dpi(&self, size_96: u32, dpi: u32) -> Option<&IconImage> {
+		self.best_image(size_96 * dpi / 96)
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// A single image of an [`IconFile`](crate::IconFile).
+#[derive(Clone)]
+pub struct IconImage {
+	/// Width, in pixels.
+	pub width: u32,
+	/// Height, in pixels.
+	pub height: u32,
+	/// Bits per pixel. PNG images are always 32.
+	pub bit_count: u16,
+	/// Hotspot of cursor images, from the top left corner.
+	pub hotspot: (u16, u16),
+	/// The image itself: either a PNG file, or a packed DIB whose
+	/// `BITMAPINFOHEADER` has twice the height, because the color pixels are
+	/// followed by a 1 bpp AND mask.
+	pub data: Vec<u8>,
+}
+
+impl IconImage {
+	/// Creates an image from the contents of a PNG file, reading its size from
+	/// the `IHDR` chunk.
+	///
+	/// Returns [`co::ERROR::INVALID_DATA`](crate::co::ERROR::INVALID_DATA) if
+	/// the data is not a PNG file.
+	pub fn from_png(data: Vec<u8>) -> SysResult<Self> {
+		if data.len() < 24 || !data.starts_with(&PNG_SIGNATURE) || data[12..16] != *b"IHDR" {
+			return Err(co::ERROR::INVALID_DATA);
+		}
+		let read_u32_be = |off: usize| u32::from_be_bytes([
+			data[off], data[off + 1], data[off + 2], data[off + 3]]);
+
+		Ok(Self {
+			width: read_u32_be(16),
+			height: read_u32_be(20),
+			bit_count: 32,
+			hotspot: (0, 0),
+			data,
+		})
+	}
+
+	/// Creates a 32 bpp image from the bitmap. The AND mask is built from the
+	/// alpha channel; if the bitmap has no alpha, the image is fully opaque.
+	pub fn from_dib(dib: &Dib) -> SysResult<Self> {
+		let mut dib = dib.to_32bpp()?;
+		if dib.bits().chunks_exact(4).all(|px| px[3] == 0) { // no alpha channel
+			dib.bits_mut().chunks_exact_mut(4).for_each(|px| px[3] = 0xff);
+		}
+
+		let (width, height) = (dib.width(), dib.height());
+		let mask_stride = (width as usize).div_ceil(32) * 4;
+
+		let mut bih = BITMAPINFOHEADER::default();
+		bih.biWidth = width as _;
+		bih.biHeight = height as i32 * 2; // color pixels + AND mask
+		bih.biPlanes = 1;
+		bih.biBitCount = 32;
+		bih.biCompression = co::BI::RGB;
+		bih.biSizeImage = ((dib.stride() + mask_stride) * height as usize) as _;
+
+		let mut data = Vec::with_capacity(
+			std::mem::size_of::<BITMAPINFOHEADER>() + bih.biSizeImage as usize);
+		data.extend_from_slice(bih.serialize());
+		(0..height).rev() // bottom-up
+			.for_each(|y| data.extend_from_slice(dib.row(y)));
+		for y in (0..height).rev() {
+			let mut mask_row = vec![0u8; mask_stride];
+			dib.row(y).chunks_exact(4)
+				.enumerate()
+				.filter(|(_, px)| px[3] == 0) // transparent pixels are set in the mask
+				.for_each(|(x, _)| mask_row[x / 8] |= 0x80 >> (x % 8));
+			data.extend_from_slice(&mask_row);
+		}
+
+		Ok(Self { width, height, bit_count: 32, hotspot: (0, 0), data })
+	}
+
+	/// Creates a 32 bpp image from the icon, retrieving its bitmaps with
+	/// [`HICON::GetIconInfo`](crate::prelude::gdi_Hicon::GetIconInfo).
+	pub fn from_hicon(hicon: &HICON) -> SysResult<Self> {
+		Self::from_icon_info(&*hicon.GetIconInfo()?)
+	}
+
+	/// Creates a 32 bpp image from the cursor, including its hotspot,
+	/// retrieving its bitmaps with
+	/// [`HCURSOR::GetIconInfo`](crate::prelude::gdi_Hcursor::GetIconInfo).
+	pub fn from_hcursor(hcursor: &HCURSOR) -> SysResult<Self> {
+		Self::from_icon_info(&*hcursor.GetIconInfo()?)
+	}
+
+	/// Returns `true` if the image is a PNG file, `false` if it's a DIB.
+	#[must_use]
+	pub fn is_png(&self) -> bool {
+		self.data.starts_with(&PNG_SIGNATURE)
+	}
+
+	/// Decodes a DIB image into a 32 bpp bitmap. If the image has no alpha
+	/// channel, it's built from the AND mask.
+	///
+	/// PNG images are not decoded, returning
+	/// [`co::ERROR::NOT_SUPPORTED`](crate::co::ERROR::NOT_SUPPORTED).
+	pub fn to_dib(&self) -> SysResult<Dib> {
+		if self.is_png() {
+			return Err(co::ERROR::NOT_SUPPORTED);
+		}
+
+		let mut color_data = self.data.clone();
+		let height = color_data.get(8..12)
+			.map(|h| i32::from_le_bytes([h[0], h[1], h[2], h[3]]) / 2)
+			.ok_or(co::ERROR::INVALID_DATA)?;
+		color_data[8..12].copy_from_slice(&height.to_le_bytes()); // only the color pixels
+
+		let src = Dib::from_packed(&color_data)?;
+		let has_alpha = src.bit_count() == 32
+			&& src.bits().chunks_exact(4).any(|px| px[3] != 0);
+		let mut dib = src.to_32bpp()?;
+
+		if !has_alpha {
+			let (width, height) = (dib.width(), dib.height());
+			let mask_stride = (width as usize).div_ceil(32) * 4;
+			let mask = self.data.len()
+				.checked_sub(mask_stride * height as usize)
+				.map(|off| &self.data[off..]) // AND mask is at the end
+				.ok_or(co::ERROR::INVALID_DATA)?;
+
+			for y in 0..height {
+				let mask_row = &mask[(height - 1 - y) as usize * mask_stride..][..mask_stride]; // bottom-up
+				let row = dib.row_mut(y);
+				for x in 0..width as usize {
+					let is_transparent = mask_row[x / 8] & (0x80 >> (x % 8)) != 0;
+					row[x * 4 + 3] = if is_transparent { 0 } else { 0xff };
+				}
+			}
+		}
+		Ok(dib)
+	}
+
+	/// Creates an icon with
+	/// [`HICON::CreateIconFromResourceEx`](crate::prelude::gdi_Hicon::CreateIconFromResourceEx),
+	/// at the size of the image.
+	pub fn to_hicon(&self) -> SysResult<DestroyIconGuard> {
+		HICON::CreateIconFromResourceEx(
+			&self.data,
+			SIZE::new(self.width as _, self.height as _),
+			co::LR::DEFAULTCOLOR,
+		)
+	}
+
+	/// Creates a cursor with
+	/// [`HCURSOR::CreateIconFromResourceEx`](crate::prelude::gdi_Hcursor::CreateIconFromResourceEx),
+	/// at the size of the image, with its hotspot.
+	pub fn to_hcursor(&self) -> SysResult<DestroyCursorGuard> {
+		let mut res_bits = Vec::with_capacity(4 + self.data.len());
+		res_bits.extend_from_slice(&self.hotspot.0.to_le_bytes());
+		res_bits.extend_from_slice(&self.hotspot.1.to_le_bytes());
+		res_bits.extend_from_slice(&self.data);
+
+		HCURSOR::CreateIconFromResourceEx(
+			&res_bits,
+			SIZE::new(self.width as _, self.height as _),
+			co::LR::DEFAULTCOLOR,
+		)
+	}
+
+	/// Reads the size and bit count from the `BITMAPINFOHEADER` of a DIB
+	/// payload.
+	fn from_dib_payload(data: Vec<u8>) -> SysResult<Self> {
+		if data.len() < std::mem::size_of::<BITMAPINFOHEADER>() {
+			return Err(co::ERROR::INVALID_DATA);
+		}
+		let width = read_u32(&data, 4) as i32;
+		let height = read_u32(&data, 8) as i32 / 2; // color pixels + AND mask
+		if width <= 0 || height <= 0 {
+			return Err(co::ERROR::INVALID_DATA);
+		}
+
+		Ok(Self {
+			width: width as _,
+			height: height as _,
+			bit_count: read_u16(&data, 14),
+			hotspot: (0, 0),
+			data,
+		})
+	}
+
+	fn from_icon_info(ii: &ICONINFO) -> SysResult<Self> {
+		let hdc = HWND::DESKTOP.GetDC()?;
+		let mask = Dib::from_hbitmap(&hdc, &ii.hbmMask)?;
+
+		let mut color = match ii.hbmColor.as_opt() {
+			Some(hbm) => Dib::from_hbitmap(&hdc, hbm)?,
+			None => { // monochrome: mask has the AND bits on top, XOR bits at bottom
+				let height = mask.height() / 2;
+				let mut color = Dib::new(mask.width(), height, 32, &[])?;
+				(0..height).for_each(|y| color.row_mut(y).copy_from_slice(mask.row(y + height)));
+				color
+			},
+		};
+
+		if color.bits().chunks_exact(4).all(|px| px[3] == 0) { // no alpha channel
+			for y in 0..color.height() {
+				for x in 0..color.width() {
+					let is_transparent = mask.pixel(x, y).GetRValue() != 0;
+					color.row_mut(y)[x as usize * 4 + 3] = if is_transparent { 0 } else { 0xff };
+				}
+			}
+		}
+
+		let mut img = Self::from_dib(&color)?;
+		img.hotspot = (ii.xHotspot as _, ii.yHotspot as _);
+		Ok(img)
+	}
+}
+
+fn read_u16(data: &[u8], off: usize) -> u16 {
+	u16::from_le_bytes([data[off], data[off + 1]])
+}
+
+fn read_u32(data: &[u8], off: usize) -> u32 {
+	u32::from_le_bytes([data[off], data[off + 1], data[off + 2], data[off + 3]])
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	/// 5x3 bitmap with a transparent first column.
+	fn sample_dib() -> Dib {
+		let mut dib = Dib::new(5, 3, 32, &[]).unwrap();
+		for y in 0..3 {
+			for x in 0..5 {
+				let n = (y * 5 + x) as u8;
+				let alpha = if x == 0 { 0 } else { 0x80 + n };
+				dib.row_mut(y)[x as usize * 4..][..4].copy_from_slice(&[n, n * 2, n * 3, alpha]);
+			}
+		}
+		dib
+	}
+
+	/// Minimal PNG file, with just the signature and the IHDR chunk.
+	fn sample_png(width: u32, height: u32) -> Vec<u8> {
+		let mut png = PNG_SIGNATURE.to_vec();
+		png.extend_from_slice(&13u32.to_be_bytes());
+		png.extend_from_slice(b"IHDR");
+		png.extend_from_slice(&width.to_be_bytes());
+		png.extend_from_slice(&height.to_be_bytes());
+		png.extend_from_slice(&[8, 6, 0, 0, 0, 0, 0, 0, 0]); // rest of IHDR + CRC
+		png
+	}
+
+	fn sample_ico() -> IconFile {
+		IconFile {
+			is_cursor: false,
+			images: vec![
+				IconImage::from_dib(&sample_dib()).unwrap(),
+				IconImage::from_png(sample_png(256, 256)).unwrap(),
+			],
+		}
+	}
+
+	fn assert_same(a: &IconFile, b: &IconFile) {
+		assert_eq!(a.is_cursor, b.is_cursor);
+		assert_eq!(a.images.len(), b.images.len());
+		for (a, b) in a.images.iter().zip(b.images.iter()) {
+			assert_eq!((a.width, a.height, a.bit_count, a.hotspot), (b.width, b.height, b.bit_count, b.hotspot));
+			assert_eq!(a.data, b.data);
+		}
+	}
+
+	#[test]
+	fn round_trip_ico() {
+		let ico = sample_ico();
+		let bytes = ico.serialize();
+		assert_eq!(&bytes[..6], &[0, 0, 1, 0, 2, 0]);
+		assert_eq!(bytes[6 + 16], 0); // 256 is written as zero
+
+		let parsed = IconFile::parse(&bytes).unwrap();
+		assert_same(&ico, &parsed);
+		assert!(!parsed.images[0].is_png());
+		assert!(parsed.images[1].is_png());
+		assert_eq!(parsed.serialize(), bytes);
+	}
+
+	#[test]
+	fn round_trip_cur() {
+		let mut cur = sample_ico();
+		cur.is_cursor = true;
+		cur.images[0].hotspot = (2, 1);
+		cur.images[1].hotspot = (200, 100);
+
+		let bytes = cur.serialize();
+		assert_eq!(read_u16(&bytes, 2), 2);
+		assert_same(&cur, &IconFile::parse(&bytes).unwrap());
+	}
+
+	#[test]
+	fn round_trip_dib() {
+		let dib = sample_dib();
+		let img = IconImage::from_dib(&dib).unwrap();
+		assert_eq!((img.width, img.height, img.bit_count), (5, 3, 32));
+		assert_eq!(read_u32(&img.data, 8), 6); // color pixels + AND mask
+
+		let decoded = img.to_dib().unwrap();
+		assert_eq!((decoded.width(), decoded.height()), (5, 3));
+		assert_eq!(decoded.bits(), dib.bits());
+	}
+
+	#[test]
+	fn and_mask() {
+		let mut img = IconImage::from_dib(&sample_dib()).unwrap();
+		let pixels = &mut img.data[std::mem::size_of::<BITMAPINFOHEADER>()..][..5 * 4 * 3];
+		pixels.chunks_exact_mut(4).for_each(|px| px[3] = 0); // drop alpha, keep mask
+
+		let decoded = img.to_dib().unwrap();
+		for y in 0..3 {
+			for x in 0..5 {
+				let alpha = decoded.row(y)[x * 4 + 3];
+				assert_eq!(alpha, if x == 0 { 0 } else { 0xff });
+			}
+		}
+	}
+
+	#[test]
+	fn opaque_dib() {
+		let mut dib = Dib::new(2, 2, 24, &[]).unwrap();
+		dib.set_pixel(1, 1, COLORREF::new(1, 2, 3));
+		let decoded = IconImage::from_dib(&dib).unwrap().to_dib().unwrap();
+		assert!(decoded.bits().chunks_exact(4).all(|px| px[3] == 0xff));
+		assert_eq!(decoded.pixel(1, 1), COLORREF::new(1, 2, 3));
+	}
+
+	#[test]
+	fn bad_header() {
+		for bytes in [&[][..], &[0, 0, 1, 0], &[1, 0, 1, 0, 0, 0], &[0, 0, 3, 0, 0, 0]] {
+			assert_eq!(IconFile::parse(bytes).err(), Some(co::ERROR::INVALID_DATA));
+		}
+		assert_eq!(IconFile::parse(&[0, 0, 1, 0, 0, 0]).unwrap().images.len(), 0);
+	}
+
+	#[test]
+	fn entry_past_end() {
+		let bytes = sample_ico().serialize();
+		let entry = 6 + 16; // second image
+		let patches: [(usize, u32); 4] = [
+			(8, bytes.len() as u32), // size
+			(12, bytes.len() as u32), // offset
+			(12, bytes.len() as u32 - 10), // offset + size
+			(12, u32::MAX), // offset + size overflows
+		];
+		for (field, val) in patches {
+			let mut bad = bytes.clone();
+			bad[entry + field..][..4].copy_from_slice(&val.to_le_bytes());
+			assert_eq!(IconFile::parse(&bad).err(), Some(co::ERROR::INVALID_DATA));
+		}
+	}
+
+	#[test]
+	fn count_mismatch() {
+		let mut bytes = sample_ico().serialize();
+		bytes[4..6].copy_from_slice(&1000u16.to_le_bytes()); // directory past the end
+		assert_eq!(IconFile::parse(&bytes).err(), Some(co::ERROR::INVALID_DATA));
+
+		let ico = sample_ico().serialize();
+		for len in [6 + 15, 6 + 16 + 15] { // truncated directory
+			assert_eq!(IconFile::parse(&ico[..len]).err(), Some(co::ERROR::INVALID_DATA));
+		}
+	}
+
+	#[test]
+	fn bad_payload() {
+		let mut ico = sample_ico();
+		ico.images[0].data.truncate(20); // shorter than BITMAPINFOHEADER
+		assert!(IconFile::parse(&ico.serialize()).is_err());
+
+		let mut huge = IconImage::from_dib(&sample_dib()).unwrap();
+		huge.data[4..8].copy_from_slice(&i32::MAX.to_le_bytes()); // width
+		huge.data[8..12].copy_from_slice(&(i32::MAX - 1).to_le_bytes()); // height
+		assert_eq!(huge.to_dib().err(), Some(co::ERROR::INVALID_DATA));
+
+		let png = IconImage::from_png(sample_png(16, 16)).unwrap();
+		assert_eq!((png.width, png.height), (16, 16));
+		assert_eq!(png.to_dib().err(), Some(co::ERROR::NOT_SUPPORTED));
+		assert!(IconImage::from_png(sample_png(16, 16)[..20].to_vec()).is_err());
+	}
+}
@@ -0,0 +1,7 @@
+mod dib;
+mod emf_records;
+mod icon_file;
+
+pub use dib::{Dib, DibHeader};
+pub use emf_records::{EmfRecord, EmfRecords};
+pub use icon_file::{IconFile, IconImage};
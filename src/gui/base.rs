@@ -1,3 +1,4 @@
+use std::cell::{Cell, RefCell};
 use std::ptr::NonNull;
 
 use crate::co;
@@ -21,6 +22,7 @@ pub(in crate::gui) struct Base {
 	user_events: WindowEventsAll, // ordinary window events, inserted by user: only last added is executed (overwrite previous)
 	privileged_events: WindowEventsAll, // inserted internally to automate tasks: all will be executed
 	layout_arranger: LayoutArranger,
+	dpi: Cell<u32>, // DPI the window and its children are scaled to; zero means system DPI
 }
 
 impl Base {
@@ -46,6 +48,7 @@ impl Base {
 			user_events: WindowEventsAll::new(),
 			privileged_events: WindowEventsAll::new(),
 			layout_arranger: LayoutArranger::new(),
+			dpi: Cell::new(0),
 		};
 		new_self.default_message_handlers();
 		new_self
@@ -127,6 +130,165 @@ impl Base {
 		Ok(())
 	}
 
+	/// Returns the DPI the window and its children are currently scaled to.
+	pub(in crate::gui) fn current_dpi(&self) -> SysResult<u32> {
+		match self.dpi.get() {
+			0 => system_dpi(),
+			dpi => Ok(dpi),
+		}
+	}
+
+	/// Scales a top-level window, right after its creation, if it was placed
+	/// on a monitor whose DPI differs from the system DPI, which was used to
+	/// calculate its size and the coordinates of its children.
+	pub(in crate::gui) fn adjust_creation_dpi(&self) -> SysResult<()> {
+		let (old_dpi, new_dpi) = (self.current_dpi()?, dpi_for_window(&self.hwnd));
+		if new_dpi == 0 || new_dpi == old_dpi {
+			return Ok(()); // same DPI, or not per-monitor aware
+		}
+
+		// Child windows rescale their own layouts, as they would when the
+		// system changes the DPI.
+		self.hwnd.EnumChildWindows(|hchild| {
+			hchild.SendMessage(WndMsg::new(co::WM::DPICHANGED_BEFOREPARENT, 0, 0));
+			true
+		});
+
+		let rc_client = self.hwnd.GetClientRect()?;
+		let mut rc_new = RECT {
+			left: 0,
+			top: 0,
+			right: MulDiv(rc_client.right, new_dpi as _, old_dpi as _),
+			bottom: MulDiv(rc_client.bottom, new_dpi as _, old_dpi as _),
+		};
+		adjust_window_rect_for_dpi(
+			&mut rc_new,
+			unsafe { co::WS::from_raw(self.hwnd.GetWindowLongPtr(co::GWLP::STYLE) as _) },
+			self.hwnd.GetMenu().is_some(),
+			unsafe { co::WS_EX::from_raw(self.hwnd.GetWindowLongPtr(co::GWLP::EXSTYLE) as _) },
+			new_dpi,
+		)?;
+
+		let rc_cur = self.hwnd.GetWindowRect()?;
+		self.rescale_dpi(new_dpi, &RECT {
+			left: rc_cur.left,
+			top: rc_cur.top,
+			right: rc_cur.left + rc_new.right - rc_new.left,
+			bottom: rc_cur.top + rc_new.bottom - rc_new.top,
+		})
+	}
+
+	/// Scales the positions, sizes and fonts of all child windows to the new
+	/// DPI, then moves the window to the given rectangle, in screen
+	/// coordinates. Used with top-level windows.
+	pub(in crate::gui) fn rescale_dpi(&self,
+		new_dpi: u32,
+		rc_window: &RECT,
+	) -> SysResult<()>
+	{
+		let old_dpi = self.current_dpi()?;
+		if new_dpi != old_dpi {
+			let hfont_old = ui_font_for_dpi(old_dpi)?;
+			let hfont_new = ui_font_for_dpi(new_dpi)?;
+			let scale = |val: i32| MulDiv(val, new_dpi as _, old_dpi as _);
+
+			let hchildren = RefCell::new(Vec::<HWND>::default());
+			self.hwnd.EnumChildWindows(|hchild| { // all descendants
+				hchildren.borrow_mut().push(hchild);
+				true
+			});
+			let hchildren = hchildren.into_inner();
+
+			// Calculate all coordinates before moving anything, because a
+			// resized child window may rearrange its own children.
+			let rcs = hchildren.iter()
+				.map(|hchild| {
+					let mut rc = hchild.GetWindowRect()?;
+					hchild.GetParent()?.ScreenToClientRc(&mut rc)?;
+					Ok(RECT {
+						left: scale(rc.left),
+						top: scale(rc.top),
+						right: scale(rc.right),
+						bottom: scale(rc.bottom),
+					})
+				})
+				.collect::<SysResult<Vec<_>>>()?;
+
+			for (hchild, rc) in hchildren.iter().zip(rcs.iter()) {
+				hchild.SetWindowPos(
+					HwndPlace::None,
+					POINT::new(rc.left, rc.top),
+					SIZE::new(rc.right - rc.left, rc.bottom - rc.top),
+					co::SWP::NOZORDER | co::SWP::NOACTIVATE,
+				)?;
+				Self::replace_ui_font(hchild, &hfont_old, &hfont_new);
+			}
+
+			self.layout_arranger.rescale(old_dpi, new_dpi);
+			self.dpi.set(new_dpi);
+		}
+
+		self.hwnd.SetWindowPos( // will trigger WM_SIZE, so the layout is rearranged
+			HwndPlace::None,
+			POINT::new(rc_window.left, rc_window.top),
+			SIZE::new(rc_window.right - rc_window.left, rc_window.bottom - rc_window.top),
+			co::SWP::NOZORDER | co::SWP::NOACTIVATE,
+		)
+	}
+
+	/// Records the DPI of a dialog right after its creation, because the
+	/// dialog manager already scales it to the monitor where it's placed.
+	/// Returns the DPI.
+	pub(in crate::gui) fn init_dialog_dpi(&self) -> SysResult<u32> {
+		let dpi = dpi_for_window(&self.hwnd);
+		if dpi != 0 {
+			self.dpi.set(dpi);
+		}
+		self.current_dpi()
+	}
+
+	/// Scales the stored layout and the UI fonts of a dialog to the new DPI.
+	/// The positions and sizes of the controls are scaled by the dialog
+	/// manager itself.
+	pub(in crate::gui) fn rescale_dialog_dpi(&self, new_dpi: u32) -> SysResult<()> {
+		let old_dpi = self.current_dpi()?;
+		if new_dpi == 0 || new_dpi == old_dpi {
+			return Ok(());
+		}
+
+		let hfont_old = ui_font_for_dpi(old_dpi)?;
+		let hfont_new = ui_font_for_dpi(new_dpi)?;
+		Self::replace_ui_font(&self.hwnd, &hfont_old, &hfont_new);
+		self.hwnd.EnumChildWindows(|hchild| {
+			Self::replace_ui_font(&hchild, &hfont_old, &hfont_new);
+			true
+		});
+
+		self.layout_arranger.rescale(old_dpi, new_dpi);
+		self.dpi.set(new_dpi);
+		Ok(())
+	}
+
+	/// Scales the stored layout of a child window, whose DPI has been changed
+	/// along with its top-level parent.
+	pub(in crate::gui) fn rescale_layout_dpi(&self) -> SysResult<()> {
+		let (old_dpi, new_dpi) = (self.current_dpi()?, dpi_for_window(&self.hwnd));
+		if new_dpi != 0 && new_dpi != old_dpi {
+			self.layout_arranger.rescale(old_dpi, new_dpi);
+			self.dpi.set(new_dpi);
+		}
+		Ok(())
+	}
+
+	fn replace_ui_font(hwnd: &HWND, hfont_old: &HFONT, hfont_new: &HFONT) {
+		if hwnd.SendMessage(wm::GetFont {}) == Some(unsafe { hfont_old.raw_copy() }) { // don't touch fonts set by the user
+			hwnd.SendMessage(wm::SetFont {
+				hfont: unsafe { hfont_new.raw_copy() },
+				redraw: true,
+			});
+		}
+	}
+
 	pub(in crate::gui) fn spawn_new_thread<F>(&self, func: F)
 		where F: FnOnce() -> AnyResult<()> + Send + 'static,
 	{
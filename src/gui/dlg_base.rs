@@ -138,20 +138,33 @@ impl DlgBase {
 		let ref_self = unsafe { &mut *ptr_self };
 		let at_least_one_privileged = ref_self.base.process_privileged_messages(wm_any)?;
 
-		if wm_any.msg_id == co::WM::INITDIALOG {
-			// Child controls are created in privileged closures, so we set the
-			// system font only now.
-			ref_self.base.hwnd().SendMessage(wm::SetFont { // on the window itself
-				hfont: unsafe { ui_font().raw_copy() },
-				redraw: false,
-			});
-			ref_self.base.hwnd().EnumChildWindows(|hchild| {
-				hchild.SendMessage(wm::SetFont { // on each child control
-					hfont: unsafe { ui_font().raw_copy() },
+		match wm_any.msg_id {
+			co::WM::INITDIALOG => {
+				// Child controls are created in privileged closures, so we set
+				// the system font only now, scaled to the dialog monitor.
+				let hfont = ui_font_for_dpi(ref_self.base.init_dialog_dpi()?)?;
+				ref_self.base.hwnd().SendMessage(wm::SetFont { // on the window itself
+					hfont: unsafe { hfont.raw_copy() },
 					redraw: false,
 				});
-				true
-			});
+				ref_self.base.hwnd().EnumChildWindows(|hchild| {
+					hchild.SendMessage(wm::SetFont { // on each child control
+						hfont: unsafe { hfont.raw_copy() },
+						redraw: false,
+					});
+					true
+				});
+			},
+			// The dialog manager scales the controls when moved to another
+			// monitor, so we only keep the layout and the fonts in sync.
+			co::WM::DPICHANGED => {
+				let wm_dpi = wm::DpiChanged::from_generic_wm(wm_any);
+				ref_self.base.rescale_dialog_dpi(wm_dpi.new_dpi)?;
+			},
+			co::WM::DPICHANGED_BEFOREPARENT => {
+				ref_self.base.rescale_dialog_dpi(dpi_for_window(&hwnd))?;
+			},
+			_ => {},
 		}
 
 		// Execute user closure, if any.
@@ -150,6 +150,43 @@ pub trait GuiEvents {
 		/// message.
 	}
 
+	fn_wm_noparm_noret! { wm_clipboard_update, co::WM::CLIPBOARDUPDATE;
+		/// [`WM_CLIPBOARDUPDATE`](https://learn.microsoft.com/en-us/windows/win32/dataxchg/wm-clipboardupdate)
+		/// message.
+		///
+		/// Sent only after the window has been registered with
+		/// [`HWND::AddClipboardFormatListener`](crate::prelude::user_Hwnd::AddClipboardFormatListener),
+		/// usually in [`wm_create`](crate::prelude::GuiEvents::wm_create).
+		///
+		/// # Examples
+		///
+		/// ```no_run
+		/// use winsafe::{self as w, prelude::*, gui};
+		///
+		/// let wnd: gui::WindowMain; // initialized somewhere
+		/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+		///
+		/// wnd.on().wm_create({
+		///     let wnd = wnd.clone();
+		///     move |_| {
+		///         wnd.hwnd().AddClipboardFormatListener()?;
+		///         Ok(0)
+		///     }
+		/// });
+		///
+		/// wnd.on().wm_clipboard_update({
+		///     let wnd = wnd.clone();
+		///     move || {
+		///         let clip = w::Clipboard::open(wnd.hwnd())?;
+		///         if let Some(text) = clip.read_text()? {
+		///             println!("Copied: {}", text);
+		///         }
+		///         Ok(())
+		///     }
+		/// });
+		/// ```
+	}
+
 	fn_wm_noparm_noret! { wm_close, co::WM::CLOSE;
 		/// [`WM_CLOSE`](https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-close)
 		/// message.
@@ -263,6 +300,31 @@ pub trait GuiEvents {
 		/// message.
 	}
 
+	fn_wm_withparm_noret! { wm_dpi_changed, co::WM::DPICHANGED, wm::DpiChanged;
+		/// [`WM_DPICHANGED`](https://learn.microsoft.com/en-us/windows/win32/hidpi/wm-dpichanged)
+		/// message.
+		///
+		/// Ordinary windows are automatically moved to the suggested rectangle,
+		/// with their child controls and fonts scaled to the new DPI, before this
+		/// event is fired. Dialog windows are scaled by the system.
+		///
+		/// # Examples
+		///
+		/// ```no_run
+		/// use winsafe::{self as w, prelude::*, gui, msg};
+		///
+		/// let wnd: gui::WindowMain; // initialized somewhere
+		/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+		///
+		/// wnd.on().wm_dpi_changed(
+		///     move |p: msg::wm::DpiChanged| -> w::AnyResult<()> {
+		///         println!("New scale: {}%", p.new_dpi * 100 / 96);
+		///         Ok(())
+		///     },
+		/// );
+		/// ```
+	}
+
 	fn_wm_withparm_noret! { wm_drop_files, co::WM::DROPFILES, wm::DropFiles;
 		/// [`WM_DROPFILES`](https://learn.microsoft.com/en-us/windows/win32/shell/wm-dropfiles)
 		/// message.
@@ -387,6 +449,50 @@ pub trait GuiEvents {
 		/// message.
 	}
 
+	fn_wm_withparm_noret! { wm_hotkey, co::WM::HOTKEY, wm::Hotkey;
+		/// [`WM_HOTKEY`](https://learn.microsoft.com/en-us/windows/win32/inputdev/wm-hotkey)
+		/// message.
+		///
+		/// Sent only for hotkeys registered with
+		/// [`HWND::RegisterHotKey`](crate::prelude::user_Hwnd::RegisterHotKey).
+		///
+		/// # Examples
+		///
+		/// ```no_run
+		/// use std::cell::RefCell;
+		/// use std::rc::Rc;
+		/// use winsafe::{self as w, prelude::*, co, gui, msg};
+		///
+		/// let wnd: gui::WindowMain; // initialized somewhere
+		/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+		///
+		/// const ID_CAPTURE: i32 = 1;
+		/// let hotkey = Rc::new(RefCell::new(None)); // keeps the guard alive
+		///
+		/// wnd.on().wm_create({
+		///     let wnd = wnd.clone();
+		///     let hotkey = hotkey.clone();
+		///     move |_| {
+		///         *hotkey.borrow_mut() = Some(wnd.hwnd().RegisterHotKey(
+		///             ID_CAPTURE,
+		///             co::MOD::CONTROL | co::MOD::SHIFT | co::MOD::NOREPEAT,
+		///             co::VK::CHAR_S,
+		///         )?);
+		///         Ok(0)
+		///     }
+		/// });
+		///
+		/// wnd.on().wm_hotkey(
+		///     move |p: msg::wm::Hotkey| -> w::AnyResult<()> {
+		///         if p.id == ID_CAPTURE {
+		///             println!("Capture!");
+		///         }
+		///         Ok(())
+		///     },
+		/// );
+		/// ```
+	}
+
 	fn_wm_withparm_noret! { wm_h_scroll, co::WM::HSCROLL, wm::HScroll;
 		/// [`WM_HSCROLL`](https://learn.microsoft.com/en-us/windows/win32/controls/wm-hscroll)
 		/// message.
@@ -440,6 +546,57 @@ pub trait GuiEvents {
 		/// ```
 	}
 
+	/// [`WM_INPUT`](https://learn.microsoft.com/en-us/windows/win32/inputdev/wm-input)
+	/// message.
+	///
+	/// Sent only for devices registered with
+	/// [`RegisterRawInputDevices`](crate::RegisterRawInputDevices). The record
+	/// is retrieved with
+	/// [`HRAWINPUT::GetRawInputData`](crate::prelude::user_Hrawinput::GetRawInputData)
+	/// and handed to the closure already decoded.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co, gui};
+	///
+	/// let wnd: gui::WindowMain; // initialized somewhere
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	///
+	/// wnd.on().wm_create({
+	///     let wnd = wnd.clone();
+	///     move |_| {
+	///         w::RegisterRawInputDevices(&[
+	///             w::RAWINPUTDEVICE {
+	///                 usUsagePage: 0x01, // generic desktop
+	///                 usUsage: 0x06, // keyboard
+	///                 dwFlags: co::RIDEV::NoValue,
+	///                 hwndTarget: unsafe { wnd.hwnd().raw_copy() },
+	///             },
+	///         ])?;
+	///         Ok(0)
+	///     }
+	/// });
+	///
+	/// wnd.on().wm_input(
+	///     move |raw: w::RawInput| -> w::AnyResult<()> {
+	///         if let w::RawInputData::Keyboard(kb) = raw.data {
+	///             println!("Key {} from device {}", kb.VKey, raw.header.hDevice);
+	///         }
+	///         Ok(())
+	///     },
+	/// );
+	/// ```
+	fn wm_input<F>(&self, func: F)
+		where F: Fn(RawInput) -> AnyResult<()> + 'static,
+	{
+		self.wm(co::WM::INPUT, move |p| {
+			let p = wm::Input::from_generic_wm(p);
+			func(p.hrawinput.GetRawInputData()?)?;
+			Ok(None)
+		});
+	}
+
 	fn_wm_withparm_noret! { wm_key_down, co::WM::KEYDOWN, wm::KeyDown;
 		/// [`WM_KEYDOWN`](https://learn.microsoft.com/en-us/windows/win32/inputdev/wm-keydown)
 		/// message.
@@ -690,6 +847,29 @@ pub trait GuiEvents {
 		/// message.
 	}
 
+	fn_wm_withparm_noret! { wm_setting_change, co::WM::SETTINGCHANGE, wm::SettingChange;
+		/// [`WM_SETTINGCHANGE`](https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-settingchange)
+		/// message.
+		///
+		/// # Examples
+		///
+		/// ```no_run
+		/// use winsafe::{self as w, prelude::*, gui, msg};
+		///
+		/// let wnd: gui::WindowMain; // initialized somewhere
+		/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+		///
+		/// wnd.on().wm_setting_change(
+		///     move |p: msg::wm::SettingChange| -> w::AnyResult<()> {
+		///         if unsafe { p.section_name() }.as_deref() == Some("ImmersiveColorSet") {
+		///             println!("Light/dark theme changed.");
+		///         }
+		///         Ok(())
+		///     },
+		/// );
+		/// ```
+	}
+
 	fn_wm_withparm_noret! { wm_show_window, co::WM::SHOWWINDOW, wm::ShowWindow;
 		/// [`WM_SHOWWINDOW`](https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-showwindow)
 		/// message.
@@ -99,6 +99,26 @@ impl LayoutArranger {
 		Ok(())
 	}
 
+	/// Scales the stored original coordinates after the DPI of the parent
+	/// window has changed.
+	pub(in crate::gui) fn rescale(&self, old_dpi: u32, new_dpi: u32) {
+		let scale = |val: i32| MulDiv(val, new_dpi as _, old_dpi as _);
+
+		if let Some(sz) = unsafe { &mut *self.0.sz_parent_orig.get() } {
+			sz.cx = scale(sz.cx);
+			sz.cy = scale(sz.cy);
+		}
+
+		for ctrl in unsafe { &mut *self.0.ctrls.get() }.iter_mut() {
+			if let Some(rc) = &mut ctrl.rc_orig {
+				rc.left = scale(rc.left);
+				rc.top = scale(rc.top);
+				rc.right = scale(rc.right);
+				rc.bottom = scale(rc.bottom);
+			}
+		}
+	}
+
 	/// Rearranges all child controls to fit the new width/height of parent
 	/// window.
 	pub(in crate::gui) fn rearrange(&self, p: &wm::Size) -> SysResult<()> {
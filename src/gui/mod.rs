@@ -24,6 +24,7 @@ mod raw_control;
 mod raw_main;
 mod raw_modal;
 mod raw_modeless;
+mod thumbnail_view;
 mod window_control;
 mod window_main;
 mod window_modal;
@@ -54,9 +55,10 @@ pub use msg_error::MsgError;
 pub use native_controls::*;
 pub use raw_base::{Brush, Cursor, Icon};
 pub use raw_control::WindowControlOpts;
-pub use raw_main::WindowMainOpts;
+pub use raw_main::{DarkMode, WindowMainOpts};
 pub use raw_modal::WindowModalOpts;
 pub use raw_modeless::WindowModelessOpts;
+pub use thumbnail_view::{ThumbnailView, ThumbnailViewOpts};
 pub use window_control::WindowControl;
 pub use window_main::WindowMain;
 pub use window_modal::WindowModal;
@@ -1,11 +1,13 @@
 //! Global objects used within `gui` module.
 
+use std::cell::RefCell;
 use std::error::Error;
 
 use crate::co;
 use crate::decl::*;
 use crate::guard::*;
 use crate::gui::{*, privs::*};
+use crate::kernel::{ffi_types::*, privs::*};
 use crate::msg::*;
 use crate::prelude::*;
 
@@ -27,6 +29,12 @@ pub(in crate::gui) fn post_quit_error(
 /// Global UI font object.
 static mut UI_HFONT: Option<DeleteObjectGuard<HFONT>> = None;
 
+thread_local! {
+	/// UI font objects for DPIs other than the system one, created on demand
+	/// when windows are moved to other monitors.
+	static UI_HFONTS_DPI: RefCell<Vec<(u32, DeleteObjectGuard<HFONT>)>> = RefCell::new(Vec::default());
+}
+
 /// Creates the global UI font object.
 pub(in crate::gui) fn create_ui_font() -> SysResult<()> {
 	let mut ncm = NONCLIENTMETRICS::default();
@@ -45,6 +53,7 @@ pub(in crate::gui) fn create_ui_font() -> SysResult<()> {
 /// Frees the global UI font object.
 pub(in crate::gui) fn delete_ui_font() {
 	unsafe { UI_HFONT = None; } // https://users.rust-lang.org/t/why-drop-trait-not-called-when-use-global-static
+	UI_HFONTS_DPI.with(|hfonts| hfonts.borrow_mut().clear());
 }
 
 /// Retrieves the global UI font object, or panics if not created yet.
@@ -57,6 +66,141 @@ pub(in crate::gui) fn ui_font() -> &'static HFONT {
 	}
 }
 
+/// Retrieves the global UI font object scaled to the given DPI, creating it if
+/// needed.
+pub(in crate::gui) fn ui_font_for_dpi(dpi: u32) -> SysResult<HFONT> {
+	if dpi == system_dpi()? {
+		return Ok(unsafe { ui_font().raw_copy() });
+	}
+
+	UI_HFONTS_DPI.with(|hfonts| {
+		let mut hfonts = hfonts.borrow_mut();
+		if let Some((_, hfont)) = hfonts.iter().find(|(font_dpi, _)| *font_dpi == dpi) {
+			return Ok(unsafe { hfont.raw_copy() });
+		}
+
+		let mut ncm = NONCLIENTMETRICS::default();
+		DPI_PROCS.with(|procs| match procs {
+			Some(procs) => bool_to_sysresult(unsafe {
+				(procs.SystemParametersInfoForDpi)(
+					co::SPI::GETNONCLIENTMETRICS.raw(),
+					std::mem::size_of::<NONCLIENTMETRICS>() as _,
+					&mut ncm as *mut _ as _,
+					co::SPIF::NoValue.raw(),
+					dpi,
+				)
+			}),
+			None => unsafe { // before Windows 10 1607, there's only the system DPI
+				SystemParametersInfo(
+					co::SPI::GETNONCLIENTMETRICS,
+					std::mem::size_of::<NONCLIENTMETRICS>() as _,
+					&mut ncm,
+					co::SPIF::NoValue,
+				)
+			},
+		})?;
+		let hfont = HFONT::CreateFontIndirect(&ncm.lfMenuFont)?;
+		let hfont_copy = unsafe { hfont.raw_copy() };
+		hfonts.push((dpi, hfont));
+		Ok(hfont_copy)
+	})
+}
+
+//------------------------------------------------------------------------------
+
+proc_table! {
+	/// DPI functions of User32.dll which exist only on Windows 10 version 1607
+	/// and later, so they're loaded at runtime.
+	struct DpiProcs;
+
+	AdjustWindowRectExForDpi: unsafe extern "system" fn(*mut RECT, u32, BOOL, u32, u32) -> BOOL;
+	GetDpiForWindow: unsafe extern "system" fn(*mut std::ffi::c_void) -> u32;
+	SetProcessDpiAwarenessContext: unsafe extern "system" fn(isize) -> BOOL;
+	SystemParametersInfoForDpi: unsafe extern "system" fn(u32, u32, *mut std::ffi::c_void, u32, u32) -> BOOL;
+}
+
+proc_table! {
+	/// DPI function of Shcore.dll, which exists only on Windows 8.1 and later.
+	struct ShcoreProcs;
+
+	SetProcessDpiAwareness: unsafe extern "system" fn(u32) -> u32;
+}
+
+thread_local! {
+	/// The User32.dll DPI functions, or `None` on older systems. User32.dll is
+	/// never unloaded, so its handle is leaked to be borrowed for `'static`.
+	static DPI_PROCS: Option<DpiProcs<'static>> =
+		HINSTANCE::GetModuleHandle(Some("user32.dll"))
+			.and_then(|hinst| DpiProcs::load(Box::leak(Box::new(hinst))))
+			.ok();
+}
+
+/// Makes the process per-monitor v2 DPI aware. On systems older than Windows
+/// 10 version 1703, falls back to `SetProcessDpiAwareness`, then to
+/// `SetProcessDPIAware`. Does nothing if the awareness was already set, like
+/// in the application manifest.
+pub(in crate::gui) fn set_process_dpi_awareness() -> SysResult<()> {
+	let v2_res = DPI_PROCS.with(|procs| procs.as_ref().map(|procs| {
+		bool_to_sysresult(unsafe {
+			(procs.SetProcessDpiAwarenessContext)(
+				co::DPI_AWARENESS_CONTEXT::PER_MONITOR_AWARE_V2.raw())
+		})
+	}));
+	let has_procs = match v2_res {
+		Some(Ok(_) | Err(co::ERROR::ACCESS_DENIED)) => return Ok(()), // already set, possibly by the manifest
+		Some(Err(_)) => true, // Windows 10 1607, without per-monitor v2
+		None => false, // before Windows 10 1607
+	};
+
+	if let Ok(hshcore) = HINSTANCE::LoadLibrary("shcore.dll") {
+		if let Ok(procs) = ShcoreProcs::load(&hshcore) {
+			// Without GetDpiForWindow() the windows can't follow their own
+			// monitors, so on Windows 8.1 we settle for system DPI awareness.
+			let awareness = if has_procs {
+				2 // PROCESS_PER_MONITOR_DPI_AWARE
+			} else {
+				1 // PROCESS_SYSTEM_DPI_AWARE
+			};
+			match unsafe { co::HRESULT::from_raw((procs.SetProcessDpiAwareness)(awareness)) } {
+				co::HRESULT::S_OK | co::HRESULT::E_ACCESSDENIED => return Ok(()),
+				_ => {},
+			}
+		}
+	}
+
+	SetProcessDPIAware() // before Windows 8.1
+}
+
+/// Returns the DPI of the window with
+/// [`HWND::GetDpiForWindow`](crate::prelude::user_Hwnd::GetDpiForWindow), or
+/// zero if the function is not available.
+pub(in crate::gui) fn dpi_for_window(hwnd: &HWND) -> u32 {
+	DPI_PROCS.with(|procs| match procs {
+		Some(procs) => unsafe { (procs.GetDpiForWindow)(hwnd.ptr()) },
+		None => 0, // before Windows 10 1607
+	})
+}
+
+/// Calls [`AdjustWindowRectExForDpi`](crate::AdjustWindowRectExForDpi), or
+/// [`AdjustWindowRectEx`](crate::AdjustWindowRectEx) if the former is not
+/// available.
+pub(in crate::gui) fn adjust_window_rect_for_dpi(
+	rc: &mut RECT,
+	style: co::WS,
+	has_menu: bool,
+	ex_style: co::WS_EX,
+	dpi: u32,
+) -> SysResult<()>
+{
+	DPI_PROCS.with(|procs| match procs {
+		Some(procs) => bool_to_sysresult(unsafe {
+			(procs.AdjustWindowRectExForDpi)(
+				rc, style.raw(), has_menu as _, ex_style.raw(), dpi)
+		}),
+		None => AdjustWindowRectEx(rc, style, has_menu, ex_style), // before Windows 10 1607
+	})
+}
+
 //------------------------------------------------------------------------------
 
 static mut BASE_CTRL_ID: u16 = 20_000; // in-between Visual Studio Resource Editor values
@@ -74,19 +218,29 @@ pub(in crate::gui) fn auto_ctrl_id() -> u16 {
 
 static mut DPI: POINT = POINT::new(0, 0);
 
+/// Returns the current system DPI, which is cached.
+pub(in crate::gui) fn system_dpi() -> SysResult<u32> {
+	unsafe {
+		if DPI.x == 0 { // DPI not cached yet?
+			let screen_dc = HWND::NULL.GetDC()?;
+			DPI.x = screen_dc.GetDeviceCaps(co::GDC::LOGPIXELSX); // cache
+			DPI.y = screen_dc.GetDeviceCaps(co::GDC::LOGPIXELSY);
+		}
+		Ok(DPI.x as _)
+	}
+}
+
 /// Multiplies the given coordinates by current system DPI.
 pub(in crate::gui) fn multiply_dpi(
 	pt: Option<&mut POINT>,
 	sz: Option<&mut SIZE>,
 ) -> SysResult<()>
 {
-	unsafe {
-		if (pt.is_some() || sz.is_some()) && DPI.x == 0 { // DPI not cached yet?
-			let screen_dc = HWND::NULL.GetDC()?;
-			DPI.x = screen_dc.GetDeviceCaps(co::GDC::LOGPIXELSX); // cache
-			DPI.y = screen_dc.GetDeviceCaps(co::GDC::LOGPIXELSY);
-		}
+	if pt.is_some() || sz.is_some() {
+		system_dpi()?;
+	}
 
+	unsafe {
 		if let Some(pt) = pt {
 			pt.x = MulDiv(pt.x, DPI.x, 96);
 			pt.y = MulDiv(pt.y, DPI.y, 96);
@@ -100,7 +254,8 @@ pub(in crate::gui) fn multiply_dpi(
 }
 
 /// If parent is a dialog, converts Dialog Template Units to pixels; otherwise
-/// multiplies by current DPI factor.
+/// multiplies by the DPI factor the parent is currently scaled to, which may
+/// differ from the system DPI after the window is moved to another monitor.
 pub(in crate::gui) fn multiply_dpi_or_dtu(
 	parent_base: &Base,
 	pt: Option<&mut POINT>,
@@ -130,7 +285,15 @@ pub(in crate::gui) fn multiply_dpi_or_dtu(
 		});
 
 	} else {
-		multiply_dpi(pt, sz)?;
+		let dpi = parent_base.current_dpi()? as i32;
+		if let Some(pt) = pt {
+			pt.x = MulDiv(pt.x, dpi, 96);
+			pt.y = MulDiv(pt.y, dpi, 96);
+		}
+		if let Some(sz) = sz {
+			sz.cx = MulDiv(sz.cx, dpi, 96);
+			sz.cy = MulDiv(sz.cy, dpi, 96);
+		}
 	}
 
 	Ok(())
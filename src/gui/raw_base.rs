@@ -235,6 +235,9 @@ impl RawBase {
 			)?;
 		}
 
+		if !styles.has(co::WS::CHILD) {
+			self.base.adjust_creation_dpi()?;
+		}
 		Ok(())
 	}
 
@@ -285,6 +288,16 @@ impl RawBase {
 		let ref_self = unsafe { &mut *ptr_self };
 		let at_least_one_privileged = ref_self.base.process_privileged_messages(wm_any)?;
 
+		// Scale the window and its children when moved to another monitor.
+		match wm_any.msg_id {
+			co::WM::DPICHANGED => {
+				let wm_dpi = wm::DpiChanged::from_generic_wm(wm_any);
+				ref_self.base.rescale_dpi(wm_dpi.new_dpi, wm_dpi.suggested_rect)?;
+			},
+			co::WM::DPICHANGED_BEFOREPARENT => ref_self.base.rescale_layout_dpi()?,
+			_ => {},
+		}
+
 		// Execute user closure, if any.
 		let process_result = ref_self.base.process_user_message(wm_any)?;
 
@@ -124,7 +124,58 @@ impl RawMain {
 		Base::run_main_loop(opts.accel_table.as_deref()) // blocks until window is closed
 	}
 
+	/// Applies the DWM attributes of the title bar and the backdrop. They're
+	/// not supported on older systems, so errors are ignored.
+	fn set_dwm_attributes(&self, is_creation: bool) {
+		let opts = &self.0.opts;
+		let hwnd = self.hwnd();
+
+		let dark = match opts.dark_mode {
+			DarkMode::Off => false,
+			DarkMode::On => true,
+			DarkMode::System => HKEY::CURRENT_USER.RegGetValue(
+				Some("Software\\Microsoft\\Windows\\CurrentVersion\\Themes\\Personalize"),
+				Some("AppsUseLightTheme"),
+			).is_ok_and(|val| matches!(val, RegistryValue::Dword(0))),
+		};
+		if dark || !is_creation {
+			let _ = hwnd.DwmSetWindowAttribute(DwmAttr::UseImmersiveDarkMode(dark));
+		}
+		if !is_creation {
+			return; // the other attributes don't follow the system
+		}
+
+		if opts.corner_preference != co::DWMWCP::DEFAULT {
+			let _ = hwnd.DwmSetWindowAttribute(DwmAttr::WindowCornerPreference(opts.corner_preference));
+		}
+		if let Some(color) = opts.caption_color {
+			let _ = hwnd.DwmSetWindowAttribute(DwmAttr::CaptionColor(color));
+		}
+		if let Some(color) = opts.border_color {
+			let _ = hwnd.DwmSetWindowAttribute(DwmAttr::BorderColor(color));
+		}
+		if opts.backdrop != co::DWMSBT::AUTO {
+			let _ = hwnd.DwmSetWindowAttribute(DwmAttr::SystemBackdropType(opts.backdrop));
+		}
+	}
+
 	fn default_message_handlers(&self) {
+		let self2 = self.clone();
+		self.privileged_on().wm_create(move |_| {
+			self2.set_dwm_attributes(true); // before the window is painted
+			Ok(0) // not meaningful
+		});
+
+		let self2 = self.clone();
+		self.privileged_on().wm_setting_change(move |p| {
+			if self2.0.opts.dark_mode == DarkMode::System
+				&& unsafe { p.section_name() }.as_deref() == Some("ImmersiveColorSet") // light/dark setting changed
+			{
+				self2.set_dwm_attributes(false);
+			}
+			Ok(())
+		});
+
 		let self2 = self.clone();
 		self.privileged_on().wm_activate(move |p| {
 			if !p.is_minimized {
@@ -157,6 +208,20 @@ impl RawMain {
 
 //------------------------------------------------------------------------------
 
+/// Whether the title bar of a [`WindowMain`](crate::gui::WindowMain) is dark,
+/// set with
+/// [`DwmAttr::UseImmersiveDarkMode`](crate::DwmAttr::UseImmersiveDarkMode).
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DarkMode {
+	/// Light title bar, the system default.
+	Off,
+	/// Dark title bar.
+	On,
+	/// Follows the light/dark app mode setting of the system, also when it's
+	/// changed while the window is open.
+	System,
+}
+
 /// Options to create a [`WindowMain`](crate::gui::WindowMain) programmatically
 /// with [`WindowMain::new`](crate::gui::WindowMain::new).
 pub struct WindowMainOpts {
@@ -230,6 +295,33 @@ pub struct WindowMainOpts {
 	///
 	/// Defaults to `None`.
 	pub accel_table: Option<DestroyAcceleratorTableGuard>,
+
+	/// Whether the title bar is dark. Requires Windows 10 20H1 or later.
+	///
+	/// Defaults to `gui::DarkMode::Off`.
+	pub dark_mode: DarkMode,
+	/// Rounded corners preference. Requires Windows 11.
+	///
+	/// Defaults to `co::DWMWCP::DEFAULT`.
+	pub corner_preference: co::DWMWCP,
+	/// Color of the title bar. Requires Windows 11.
+	///
+	/// Defaults to `None`, which keeps the system color.
+	pub caption_color: Option<COLORREF>,
+	/// Color of the window border. Requires Windows 11.
+	///
+	/// Defaults to `None`, which keeps the system color.
+	pub border_color: Option<COLORREF>,
+	/// System backdrop material, like Mica or Acrylic. Requires Windows 11
+	/// 22H2.
+	///
+	/// The backdrop is visible only where the window is not painted: with an
+	/// opaque `class_bg_brush`, only the title bar shows it. Call
+	/// [`HWND::DwmExtendFrameIntoClientArea`](crate::prelude::dwm_Hwnd::DwmExtendFrameIntoClientArea)
+	/// to extend it into the client area.
+	///
+	/// Defaults to `co::DWMSBT::AUTO`.
+	pub backdrop: co::DWMSBT,
 }
 
 impl Default for WindowMainOpts {
@@ -246,6 +338,11 @@ impl Default for WindowMainOpts {
 			ex_style: co::WS_EX::LEFT,
 			menu: HMENU::NULL,
 			accel_table: None,
+			dark_mode: DarkMode::Off,
+			corner_preference: co::DWMWCP::DEFAULT,
+			caption_color: None,
+			border_color: None,
+			backdrop: co::DWMSBT::AUTO,
 		}
 	}
 }
@@ -0,0 +1,403 @@
+use std::any::Any;
+use std::cell::RefCell;
+use std::marker::PhantomPinned;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use crate::co;
+use crate::decl::*;
+use crate::gui::{*, events::*, privs::*};
+use crate::guard::*;
+use crate::prelude::*;
+
+struct Obj { // actual fields of ThumbnailView
+	raw: RawControl,
+	opacity: u8,
+	source_client_area_only: bool,
+	keep_aspect_ratio: bool,
+	hwnd_source: RefCell<HWND>,
+	hthumb: RefCell<Option<DwmUnregisterThumbnailGuard>>,
+	_pin: PhantomPinned,
+}
+
+//------------------------------------------------------------------------------
+
+/// A custom control which shows a live
+/// [DWM thumbnail](https://learn.microsoft.com/en-us/windows/win32/dwm/thumbnail-ovw)
+/// of another window, like the previews of the taskbar.
+///
+/// The thumbnail is rendered by the DWM into the top-level window, over the
+/// area of the control, and it follows the control when it's moved or
+/// resized by the parent – including the
+/// [`Horz`](crate::gui::Horz)/[`Vert`](crate::gui::Vert) resizing behavior.
+/// The thumbnail is hidden while the control is hidden or disabled.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, gui};
+///
+/// let wnd: gui::WindowMain; // initialized somewhere
+/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+///
+/// let thumb = gui::ThumbnailView::new(
+///     &wnd,
+///     gui::ThumbnailViewOpts {
+///         position: (10, 10),
+///         size: (300, 200),
+///         resize_behavior: (gui::Horz::Resize, gui::Vert::Resize),
+///         ..Default::default()
+///     },
+/// );
+///
+/// let thumb2 = thumb.clone();
+/// wnd.on().wm_create(move |_| {
+///     if let Some(hwnd) = w::HWND::FindWindow(
+///         Some(w::AtomStr::from_str("Notepad")), None)?
+///     {
+///         thumb2.set_source(Some(&hwnd))?;
+///     }
+///     Ok(0)
+/// });
+/// ```
+#[derive(Clone)]
+pub struct ThumbnailView(Pin<Arc<Obj>>);
+
+unsafe impl Send for ThumbnailView {}
+
+impl GuiWindow for ThumbnailView {
+	fn hwnd(&self) -> &HWND {
+		self.0.raw.hwnd()
+	}
+
+	fn as_any(&self) -> &dyn Any {
+		self
+	}
+}
+
+impl GuiChild for ThumbnailView {
+	fn ctrl_id(&self) -> u16 {
+		self.0.raw.ctrl_id()
+	}
+}
+
+impl GuiNativeControlEvents<WindowEventsAll> for ThumbnailView {
+	fn on(&self) -> &WindowEventsAll {
+		self.0.raw.on()
+	}
+}
+
+impl ThumbnailView {
+	/// Instantiates a new `ThumbnailView` object, to be created with
+	/// [`HWND::CreateWindowEx`](crate::prelude::user_Hwnd::CreateWindowEx).
+	///
+	/// # Panics
+	///
+	/// Panics if the parent window was already created – that is, you cannot
+	/// dynamically create a `ThumbnailView` in an event closure.
+	#[must_use]
+	pub fn new(parent: &impl GuiParent, opts: ThumbnailViewOpts) -> Self {
+		if *parent.hwnd() != HWND::NULL {
+			panic!("Cannot create a custom child control after the parent window is created.");
+		}
+
+		let parent_ref = unsafe { Base::from_guiparent(parent) };
+		let new_self = Self(
+			Arc::pin(
+				Obj {
+					raw: RawControl::new(parent_ref, WindowControlOpts {
+						class_bg_brush: opts.class_bg_brush,
+						position: opts.position,
+						size: opts.size,
+						style: opts.style,
+						ex_style: opts.ex_style,
+						ctrl_id: opts.ctrl_id,
+						resize_behavior: opts.resize_behavior,
+						..Default::default()
+					}),
+					opacity: opts.opacity,
+					source_client_area_only: opts.source_client_area_only,
+					keep_aspect_ratio: opts.keep_aspect_ratio,
+					hwnd_source: RefCell::new(HWND::NULL),
+					hthumb: RefCell::new(None),
+					_pin: PhantomPinned,
+				},
+			),
+		);
+		new_self.default_message_handlers();
+		new_self
+	}
+
+	fn default_message_handlers(&self) {
+		let self2 = self.clone();
+		self.0.raw.privileged_on().wm_create(move |_| {
+			self2.register()?; // source may have been set before creation
+			Ok(0) // not meaningful
+		});
+
+		let self2 = self.clone();
+		self.0.raw.privileged_on().wm_move(move |_| {
+			self2.update_properties()?;
+			Ok(())
+		});
+
+		let self2 = self.clone();
+		self.0.raw.privileged_on().wm_size(move |_| {
+			self2.update_properties()?;
+			Ok(())
+		});
+
+		let self2 = self.clone();
+		self.0.raw.privileged_on().wm_show_window(move |p| {
+			// Sent before the visibility changes, so use the new state.
+			self2.update_properties_with(p.being_shown && self2.hwnd().IsWindowEnabled())?;
+			Ok(())
+		});
+
+		let self2 = self.clone();
+		self.0.raw.privileged_on().wm_enable(move |p| {
+			self2.update_properties_with(p.has_been_enabled && self2.hwnd().IsWindowVisible())?;
+			Ok(())
+		});
+
+		let self2 = self.clone();
+		self.0.raw.privileged_on().wm_destroy(move || {
+			*self2.0.hthumb.borrow_mut() = None; // unregister before the top-level window is gone
+			Ok(())
+		});
+	}
+
+	/// Returns the window whose thumbnail is being shown, if any.
+	#[must_use]
+	pub fn source(&self) -> Option<HWND> {
+		let hwnd_source = self.0.hwnd_source.borrow();
+		hwnd_source.as_opt().map(|h| unsafe { h.raw_copy() })
+	}
+
+	/// Returns the size of the window whose thumbnail is being shown, with
+	/// [`HTHUMBNAIL::DwmQueryThumbnailSourceSize`](crate::prelude::dwm_Hthumbnail::DwmQueryThumbnailSourceSize),
+	/// or `None` if there's no source.
+	#[must_use]
+	pub fn source_size(&self) -> HrResult<Option<SIZE>> {
+		self.0.hthumb.borrow()
+			.as_ref()
+			.map(|hthumb| hthumb.DwmQueryThumbnailSourceSize())
+			.transpose()
+	}
+
+	/// Sets the window whose thumbnail will be shown, replacing the current
+	/// one. `None` removes the thumbnail.
+	///
+	/// If the control is not created yet, the thumbnail will be registered
+	/// when it is.
+	pub fn set_source(&self, hwnd_source: Option<&HWND>) -> HrResult<()> {
+		*self.0.hthumb.borrow_mut() = None; // unregister the current one, if any
+		*self.0.hwnd_source.borrow_mut() = hwnd_source
+			.map_or(HWND::NULL, |h| unsafe { h.raw_copy() });
+		if *self.hwnd() != HWND::NULL {
+			self.register()?;
+		}
+		Ok(())
+	}
+
+	/// Recalculates the thumbnail area. Call this method when the aspect ratio
+	/// of the source window changes, or when an ancestor of the control is
+	/// moved within the top-level window.
+	pub fn update(&self) -> HrResult<()> {
+		self.update_properties()
+	}
+
+	fn register(&self) -> HrResult<()> {
+		let hwnd_source = self.0.hwnd_source.borrow();
+		if *hwnd_source == HWND::NULL {
+			return Ok(());
+		}
+
+		let hwnd_root = self.hwnd().GetAncestor(co::GA::ROOT)
+			.ok_or(co::HRESULT::E_UNEXPECTED)?;
+		let hthumb = hwnd_root.DwmRegisterThumbnail(&hwnd_source)?;
+		*self.0.hthumb.borrow_mut() = Some(hthumb);
+		drop(hwnd_source);
+		self.update_properties()
+	}
+
+	fn update_properties(&self) -> HrResult<()> {
+		self.update_properties_with(
+			self.hwnd().IsWindowVisible() && self.hwnd().IsWindowEnabled())
+	}
+
+	fn update_properties_with(&self, visible: bool) -> HrResult<()> {
+		let hthumb = self.0.hthumb.borrow();
+		let hthumb = match hthumb.as_ref() {
+			Some(hthumb) => hthumb,
+			None => return Ok(()), // no source
+		};
+
+		let hwnd_root = self.hwnd().GetAncestor(co::GA::ROOT)
+			.ok_or(co::HRESULT::E_UNEXPECTED)?;
+		let mut rc = self.hwnd().GetClientRect()
+			.map_err(|err| err.to_hresult())?;
+		self.hwnd().MapWindowPoints(&hwnd_root, PtsRc::Rc(&mut rc))
+			.map_err(|err| err.to_hresult())?;
+
+		if self.0.keep_aspect_ratio {
+			rc = Self::fit_aspect_ratio(rc, hthumb.DwmQueryThumbnailSourceSize()?);
+		}
+
+		hthumb.DwmUpdateThumbnailProperties(
+			&DWM_THUMBNAIL_PROPERTIES::default()
+				.destination(rc)
+				.opacity(self.0.opacity)
+				.visible(visible)
+				.source_client_area_only(self.0.source_client_area_only),
+		)
+	}
+
+	/// Shrinks the rectangle to the aspect ratio of the source, centered.
+	fn fit_aspect_ratio(rc: RECT, sz_src: SIZE) -> RECT {
+		let (cx, cy) = (rc.right - rc.left, rc.bottom - rc.top);
+		if sz_src.cx <= 0 || sz_src.cy <= 0 || cx <= 0 || cy <= 0 {
+			return rc;
+		}
+
+		let (new_cx, new_cy) = if cx as i64 * sz_src.cy as i64 > cy as i64 * sz_src.cx as i64 {
+			((cy as i64 * sz_src.cx as i64 / sz_src.cy as i64) as i32, cy) // too wide
+		} else {
+			(cx, (cx as i64 * sz_src.cy as i64 / sz_src.cx as i64) as i32) // too tall
+		};
+
+		let left = rc.left + (cx - new_cx) / 2;
+		let top = rc.top + (cy - new_cy) / 2;
+		RECT { left, top, right: left + new_cx, bottom: top + new_cy }
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// Options to create a [`ThumbnailView`](crate::gui::ThumbnailView)
+/// programmatically with [`ThumbnailView::new`](crate::gui::ThumbnailView::new).
+pub struct ThumbnailViewOpts {
+	/// Window background brush to be
+	/// [registered](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-registerclassexw),
+	/// visible where the thumbnail doesn't cover the control.
+	///
+	/// Defaults to `gui::Brush::Color(co::COLOR::BTNFACE)`.
+	pub class_bg_brush: Brush,
+
+	/// Left and top position coordinates of control within parent's client
+	/// area, to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// If the parent window is a dialog, the values are in Dialog Template
+	/// Units; otherwise in pixels, which will be multiplied to match current
+	/// system DPI.
+	///
+	/// Defaults to `(0, 0)`.
+	pub position: (i32, i32),
+	/// Width and height of window to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// If the parent window is a dialog, the values are in Dialog Template
+	/// Units; otherwise in pixels, which will be multiplied to match current
+	/// system DPI.
+	///
+	/// Defaults to `(160, 120)`.
+	pub size: (u32, u32),
+	/// Window styles to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to `WS::CHILD | WS::VISIBLE | WS::CLIPSIBLINGS`.
+	pub style: co::WS,
+	/// Extended window styles to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to `WS_EX::LEFT`.
+	pub ex_style: co::WS_EX,
+
+	/// The control ID.
+	///
+	/// Defaults to an auto-generated ID.
+	pub ctrl_id: u16,
+	/// Horizontal and vertical behavior of the control when the parent window
+	/// is resized.
+	///
+	/// Defaults to `(gui::Horz::None, gui::Vert::None)`.
+	pub resize_behavior: (Horz, Vert),
+
+	/// Opacity of the thumbnail, from `0` (transparent) to `255` (opaque).
+	///
+	/// Defaults to `255`.
+	pub opacity: u8,
+	/// Whether only the client area of the source window is shown.
+	///
+	/// Defaults to `false`.
+	pub source_client_area_only: bool,
+	/// Whether the thumbnail keeps the aspect ratio of the source window,
+	/// centered within the control. Otherwise, it's stretched to fill the
+	/// control.
+	///
+	/// Defaults to `true`.
+	pub keep_aspect_ratio: bool,
+}
+
+impl Default for ThumbnailViewOpts {
+	fn default() -> Self {
+		Self {
+			class_bg_brush: Brush::Color(co::COLOR::BTNFACE),
+			position: (0, 0),
+			size: (160, 120),
+			style: co::WS::CHILD | co::WS::VISIBLE | co::WS::CLIPSIBLINGS,
+			ex_style: co::WS_EX::LEFT,
+			ctrl_id: 0,
+			resize_behavior: (Horz::None, Vert::None),
+			opacity: 255,
+			source_client_area_only: false,
+			keep_aspect_ratio: true,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn rc(left: i32, top: i32, right: i32, bottom: i32) -> RECT {
+		RECT { left, top, right, bottom }
+	}
+
+	fn sz(cx: i32, cy: i32) -> SIZE {
+		SIZE { cx, cy }
+	}
+
+	#[test]
+	fn fit_too_wide() {
+		let fit = ThumbnailView::fit_aspect_ratio(rc(0, 0, 400, 100), sz(200, 100));
+		assert_eq!(fit, rc(100, 0, 300, 100));
+	}
+
+	#[test]
+	fn fit_too_tall() {
+		let fit = ThumbnailView::fit_aspect_ratio(rc(0, 0, 100, 400), sz(200, 100));
+		assert_eq!(fit, rc(0, 175, 100, 225));
+	}
+
+	#[test]
+	fn fit_same_ratio() {
+		let fit = ThumbnailView::fit_aspect_ratio(rc(0, 0, 320, 240), sz(640, 480));
+		assert_eq!(fit, rc(0, 0, 320, 240));
+	}
+
+	#[test]
+	fn fit_offset_rect() {
+		let fit = ThumbnailView::fit_aspect_ratio(rc(10, 20, 110, 70), sz(100, 100));
+		assert_eq!(fit, rc(35, 20, 85, 70));
+	}
+
+	#[test]
+	fn fit_empty_sizes() {
+		let r = rc(10, 20, 110, 70);
+		assert_eq!(ThumbnailView::fit_aspect_ratio(r, sz(0, 100)), r);
+		assert_eq!(ThumbnailView::fit_aspect_ratio(r, sz(100, -1)), r);
+		assert_eq!(ThumbnailView::fit_aspect_ratio(rc(10, 20, 10, 70), sz(100, 100)), rc(10, 20, 10, 70));
+	}
+}
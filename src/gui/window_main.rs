@@ -106,13 +106,17 @@ impl WindowMain {
 	/// The `cmd_show` parameter defaults to
 	/// [`co::SW::SHOW`](crate::co::SW::SHOW).
 	///
+	/// The process is made
+	/// [per-monitor v2](crate::co::DPI_AWARENESS_CONTEXT::PER_MONITOR_AWARE_V2)
+	/// DPI aware, unless the awareness was already set, like in the
+	/// application manifest. On systems older than Windows 10 version 1703,
+	/// it's made per-monitor or system DPI aware, whichever is available.
+	///
 	/// # Panics
 	///
 	/// Panics if the window is already created.
 	pub fn run_main(&self, cmd_show: Option<co::SW>) -> AnyResult<i32> {
-		if IsWindowsVistaOrGreater().unwrap() {
-			SetProcessDPIAware().unwrap();
-		}
+		set_process_dpi_awareness()?;
 
 		InitCommonControls();
 
@@ -137,6 +137,58 @@ const_bitflag! { CREATE: u32;
 	INHERIT_PARENT_AFFINITY 0x0001_0000
 }
 
+const_bitflag! { CRED_ENUMERATE: u32;
+	/// [`CredEnumerate`](crate::CredEnumerate) `flags` (`u32`).
+	=>
+	=>
+	/// None of the actual values (zero).
+	NoValue 0
+	ALL_CREDENTIALS 0x1
+}
+
+const_bitflag! { CRED_FLAGS: u32;
+	/// [`CREDENTIAL`](crate::CREDENTIAL) `Flags` (`u32`).
+	=>
+	=>
+	/// None of the actual values (zero).
+	NoValue 0
+	PROMPT_NOW 0x2
+	USERNAME_TARGET 0x4
+}
+
+const_ordinary! { CRED_PERSIST: u32;
+	/// [`CREDENTIAL`](crate::CREDENTIAL) `Persist` (`u32`).
+	=>
+	=>
+	NONE 0
+	SESSION 1
+	LOCAL_MACHINE 2
+	ENTERPRISE 3
+}
+
+const_ordinary! { CRED_TYPE: u32;
+	/// [`CREDENTIAL`](crate::CREDENTIAL) `Type` (`u32`).
+	=>
+	=>
+	GENERIC 1
+	DOMAIN_PASSWORD 2
+	DOMAIN_CERTIFICATE 3
+	DOMAIN_VISIBLE_PASSWORD 4
+	GENERIC_CERTIFICATE 5
+	DOMAIN_EXTENDED 6
+}
+
+const_bitflag! { CRED_WRITE: u32;
+	/// [`CredWrite`](crate::CredWrite) `flags` (`u32`).
+	///
+	/// Originally has `CRED` prefix.
+	=>
+	=>
+	/// None of the actual values (zero).
+	NoValue 0
+	PRESERVE_CREDENTIAL_BLOB 0x1
+}
+
 const_ordinary! { DISPOSITION: u32;
 	/// [`HFILE::CreateFile`](crate::prelude::kernel_Hfile::CreateFile)
 	/// `creation_disposition` (`u32`).
@@ -217,6 +269,18 @@ const_ordinary! { EVENTLOG: u16;
 	WARNING_TYPE 0x0002
 }
 
+const_ordinary! { FILE_ACTION: u32;
+	/// [`FILE_NOTIFY_INFORMATION`](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-file_notify_information)
+	/// `Action` (`u32`).
+	=>
+	=>
+	ADDED 0x0000_0001
+	REMOVED 0x0000_0002
+	MODIFIED 0x0000_0003
+	RENAMED_OLD_NAME 0x0000_0004
+	RENAMED_NEW_NAME 0x0000_0005
+}
+
 const_bitflag! { FILE_ATTRIBUTE: u32;
 	/// File
 	/// [attributes](https://learn.microsoft.com/en-us/windows/win32/fileio/file-attribute-constants)
@@ -291,6 +355,23 @@ const_bitflag! { FILE_MAP: u32;
 	TARGETS_INVALID 0x4000_0000
 }
 
+const_bitflag! { FILE_NOTIFY_CHANGE: u32;
+	/// [`HFILE::ReadDirectoryChanges`](crate::prelude::kernel_Hfile::ReadDirectoryChanges)
+	/// and
+	/// [`HFINDCHANGE::FindFirstChangeNotification`](crate::prelude::kernel_Hfindchange::FindFirstChangeNotification)
+	/// `notify_filter` (`u32`).
+	=>
+	=>
+	FILE_NAME 0x0000_0001
+	DIR_NAME 0x0000_0002
+	ATTRIBUTES 0x0000_0004
+	SIZE 0x0000_0008
+	LAST_WRITE 0x0000_0010
+	LAST_ACCESS 0x0000_0020
+	CREATION 0x0000_0040
+	SECURITY 0x0000_0100
+}
+
 const_bitflag! { FILE_SHARE: u32;
 	/// [`HFILE::CreateFile`](crate::prelude::kernel_Hfile::CreateFile)
 	/// `share_mode` (`u32`).
@@ -1146,6 +1227,13 @@ const_ordinary! { RID: u32;
 	SECURITY_AUTHENTICATION_KEY_TRUST 0x0000_0004
 	SECURITY_AUTHENTICATION_KEY_PROPERTY_MFA 0x0000_0005
 	SECURITY_AUTHENTICATION_KEY_PROPERTY_ATTESTATION 0x0000_0006
+	SECURITY_MANDATORY_UNTRUSTED 0x0000_0000
+	SECURITY_MANDATORY_LOW 0x0000_1000
+	SECURITY_MANDATORY_MEDIUM 0x0000_2000
+	SECURITY_MANDATORY_MEDIUM_PLUS 0x0000_2100
+	SECURITY_MANDATORY_HIGH 0x0000_3000
+	SECURITY_MANDATORY_SYSTEM 0x0000_4000
+	SECURITY_MANDATORY_PROTECTED_PROCESS 0x0000_5000
 	SECURITY_PROCESS_PROTECTION_TYPE_FULL 0x0000_0400
 	SECURITY_PROCESS_PROTECTION_TYPE_LITE 0x0000_0200
 	SECURITY_PROCESS_PROTECTION_TYPE_NONE 0x0000_0000
@@ -1249,6 +1337,23 @@ const_bitflag! { SE: u16;
 	SELF_RELATIVE 0x8000
 }
 
+const_bitflag! { SE_GROUP: u32;
+	/// [`SID_AND_ATTRIBUTES`](crate::SID_AND_ATTRIBUTES) `Attributes` of
+	/// [token groups](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-token_groups)
+	/// (`u32`).
+	=>
+	=>
+	MANDATORY 0x0000_0001
+	ENABLED_BY_DEFAULT 0x0000_0002
+	ENABLED 0x0000_0004
+	OWNER 0x0000_0008
+	USE_FOR_DENY_ONLY 0x0000_0010
+	INTEGRITY 0x0000_0020
+	INTEGRITY_ENABLED 0x0000_0040
+	RESOURCE 0x2000_0000
+	LOGON_ID 0xc000_0000
+}
+
 const_bitflag! { SE_PRIV_ATTR: u32;
 	/// [Privilege attributes](https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-adjusttokenprivileges)
 	/// (`u32`).
@@ -12,6 +12,22 @@ pub enum DisabPriv<'a> {
 	Privs(&'a TOKEN_PRIVILEGES)
 }
 
+/// Information to be set with
+/// [`HHEAP::HeapSetInformation`](crate::prelude::kernel_Hheap::HeapSetInformation).
+///
+/// Originally a `HEAP_INFORMATION_CLASS` enum plus the data it carries.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HeapInformation {
+	/// `HeapCompatibilityInformation`, with the heap type: `2` enables the
+	/// [low-fragmentation heap](https://learn.microsoft.com/en-us/windows/win32/memory/low-fragmentation-heap).
+	Compatibility(u32),
+	/// `HeapEnableTerminationOnCorruption`, which applies to all the heaps of
+	/// the process, therefore the heap handle is ignored.
+	EnableTerminationOnCorruption,
+	/// `HeapOptimizeResources`, which decommits the free memory of the heap.
+	OptimizeResources,
+}
+
 /// A resource identifier.
 ///
 /// Variable parameter for:
@@ -26,7 +42,7 @@ pub enum DisabPriv<'a> {
 /// * [`HUPDATERSRC::UpdateResource`](crate::prelude::kernel_Hupdatersrc::UpdateResource);
 /// * [`BmpIdbRes`](crate::BmpIdbRes);
 /// * [`ResStrs`](crate::ResStrs).
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 pub enum IdStr {
 	/// A resource ID.
 	Id(u16),
@@ -9,6 +9,11 @@ extern_sys! { "advapi32";
 	ConvertStringSidToSidW(PCSTR, *mut *mut u8) -> BOOL
 	CopySid(u32, PVOID, PCVOID) -> BOOL
 	CreateWellKnownSid(u32, PCVOID, PVOID, *mut u32) -> BOOL
+	CredDeleteW(PCSTR, u32, u32) -> BOOL
+	CredEnumerateW(PCSTR, u32, *mut u32, *mut PVOID) -> BOOL
+	CredFree(PVOID)
+	CredReadW(PCSTR, u32, u32, *mut PVOID) -> BOOL
+	CredWriteW(PCVOID, u32) -> BOOL
 	DecryptFileW(PCSTR, u32) -> BOOL
 	DeregisterEventSource(HANDLE) -> BOOL
 	DuplicateToken(HANDLE, u32, *mut HANDLE) -> BOOL
@@ -78,10 +83,12 @@ extern_sys! { "advapi32";
 
 extern_sys! { "kernel32";
 	BeginUpdateResourceW(PCSTR, BOOL) -> HANDLE
+	CancelIoEx(HANDLE, PVOID) -> BOOL
 	CheckRemoteDebuggerPresent(HANDLE, *mut BOOL) -> BOOL
 	CloseHandle(HANDLE) -> BOOL
 	CopyFileW(PCSTR, PCSTR, BOOL) -> BOOL
 	CreateDirectoryW(PCSTR, PVOID) -> BOOL
+	CreateEventW(PVOID, BOOL, BOOL, PCSTR) -> HANDLE
 	CreateFileMappingFromApp(HANDLE, PVOID, u32, u64, PCSTR) -> HANDLE
 	CreateFileW(PCSTR, u32, u32, PVOID, u32, u32, HANDLE) -> HANDLE
 	CreatePipe(*mut HANDLE, *mut HANDLE, PVOID, u32) -> BOOL
@@ -89,6 +96,7 @@ extern_sys! { "kernel32";
 	CreateThread(PVOID, usize, PVOID, PVOID, u32, *mut u32) -> HANDLE
 	CreateToolhelp32Snapshot(u32, u32) -> HANDLE
 	DeleteFileW(PCSTR) -> BOOL
+	DeleteVolumeMountPointW(PCSTR) -> BOOL
 	EndUpdateResourceW(HANDLE, BOOL) -> BOOL
 	EnumResourceLanguagesW(HANDLE, PCSTR, PCSTR, PFUNC, isize) -> BOOL
 	EnumResourceNamesW(HANDLE, PCSTR, PFUNC, isize) -> BOOL
@@ -98,10 +106,19 @@ extern_sys! { "kernel32";
 	ExpandEnvironmentStringsW(PCSTR, PSTR, u32) -> u32
 	FileTimeToSystemTime(PCVOID, PVOID) -> BOOL
 	FindClose(HANDLE) -> BOOL
+	FindCloseChangeNotification(HANDLE) -> BOOL
+	FindFirstChangeNotificationW(PCSTR, BOOL, u32) -> HANDLE
 	FindFirstFileW(PCSTR, PVOID) -> HANDLE
+	FindFirstVolumeMountPointW(PCSTR, PSTR, u32) -> HANDLE
+	FindFirstVolumeW(PSTR, u32) -> HANDLE
+	FindNextChangeNotification(HANDLE) -> BOOL
 	FindNextFileW(HANDLE, PVOID) -> BOOL
+	FindNextVolumeMountPointW(HANDLE, PSTR, u32) -> BOOL
+	FindNextVolumeW(HANDLE, PSTR, u32) -> BOOL
 	FindResourceExW(HANDLE, PCSTR, PCSTR, u16) -> HANDLE
 	FindResourceW(HANDLE, PCSTR, PCSTR) -> HANDLE
+	FindVolumeClose(HANDLE) -> BOOL
+	FindVolumeMountPointClose(HANDLE) -> BOOL
 	FlushConsoleInputBuffer(HANDLE) -> BOOL
 	FlushInstructionCache(HANDLE, PCVOID, usize) -> BOOL
 	FlushProcessWriteBuffers()
@@ -138,6 +155,7 @@ extern_sys! { "kernel32";
 	GetModuleFileNameW(HANDLE, PSTR, u32) -> u32
 	GetModuleHandleW(PCSTR) -> HANDLE
 	GetNativeSystemInfo(PVOID)
+	GetOverlappedResult(HANDLE, PVOID, *mut u32, BOOL) -> BOOL
 	GetPriorityClass(HANDLE) -> u32
 	GetProcAddress(HANDLE, *const u8) -> PCVOID
 	GetProcessHandleCount(HANDLE, &mut u32) -> BOOL
@@ -161,7 +179,9 @@ extern_sys! { "kernel32";
 	GetThreadTimes(HANDLE, PVOID, PVOID, PVOID, PVOID) -> BOOL
 	GetTickCount64() -> u64
 	GetVolumeInformationW(PCSTR, PSTR, u32, *mut u32, *mut u32, *mut u32, PSTR, u32) -> BOOL
+	GetVolumeNameForVolumeMountPointW(PCSTR, PSTR, u32) -> BOOL
 	GetVolumePathNameW(PCSTR, PSTR, u32) -> BOOL
+	GetVolumePathNamesForVolumeNameW(PCSTR, PSTR, u32, *mut u32) -> BOOL
 	GlobalAlloc(u32, usize) -> HANDLE
 	GlobalFlags(HANDLE) -> u32
 	GlobalFree(HANDLE) -> HANDLE
@@ -179,6 +199,7 @@ extern_sys! { "kernel32";
 	HeapFree(HANDLE, u32, PVOID) -> BOOL
 	HeapLock(HANDLE) -> BOOL
 	HeapReAlloc(HANDLE, u32, PVOID, usize) -> PVOID
+	HeapSetInformation(HANDLE, u32, PVOID, usize) -> BOOL
 	HeapSize(HANDLE, u32, PVOID) -> usize
 	HeapUnlock(HANDLE) -> BOOL
 	HeapValidate(HANDLE, u32, PVOID) -> BOOL
@@ -214,12 +235,15 @@ extern_sys! { "kernel32";
 	QueryPerformanceFrequency(*mut i64) -> BOOL
 	QueryProcessAffinityUpdateMode(HANDLE, *mut u32) -> BOOL
 	ReadConsoleW(HANDLE, PVOID, u32, *mut u32, PVOID) -> BOOL
+	ReadDirectoryChangesW(HANDLE, PVOID, u32, BOOL, u32, *mut u32, PVOID, PVOID) -> BOOL
 	ReadFile(HANDLE, PVOID, u32, *mut u32, PVOID) -> BOOL
 	ReplaceFileW(PCSTR, PCSTR, PCSTR, u32, PVOID, PVOID) -> BOOL
+	ResetEvent(HANDLE) -> BOOL
 	ResumeThread(HANDLE) -> u32
 	SetConsoleMode(HANDLE, u32) -> BOOL
 	SetCurrentDirectoryW(PCSTR) -> BOOL
 	SetEndOfFile(HANDLE) -> BOOL
+	SetEvent(HANDLE) -> BOOL
 	SetFileAttributesW(PCSTR, u32) -> BOOL
 	SetFilePointerEx(HANDLE, i64, *mut i64, u32) -> BOOL
 	SetFileTime(HANDLE, PCVOID, PCVOID, PCVOID) -> BOOL
@@ -231,6 +255,7 @@ extern_sys! { "kernel32";
 	SetThreadIdealProcessorEx(HANDLE, PCVOID, PVOID) -> BOOL
 	SetThreadPriorityBoost(HANDLE, BOOL) -> BOOL
 	SetThreadStackGuarantee(*mut u32) -> BOOL
+	SetVolumeMountPointW(PCSTR, PCSTR) -> BOOL
 	SizeofResource(HANDLE, HANDLE) -> u32
 	Sleep(u32)
 	SuspendThread(HANDLE) -> u32
@@ -215,6 +215,126 @@ pub fn CreateWellKnownSid(
 	}
 }
 
+/// [`CredDelete`](https://learn.microsoft.com/en-us/windows/win32/api/wincred/nf-wincred-creddeletew)
+/// function.
+pub fn CredDelete(target_name: &str, cred_type: co::CRED_TYPE) -> SysResult<()> {
+	bool_to_sysresult(
+		unsafe {
+			ffi::CredDeleteW(
+				WString::from_str(target_name).as_ptr(),
+				cred_type.raw(),
+				0,
+			)
+		},
+	)
+}
+
+/// [`CredEnumerate`](https://learn.microsoft.com/en-us/windows/win32/api/wincred/nf-wincred-credenumeratew)
+/// function.
+///
+/// The `filter` is a target name prefix, followed by an asterisk. If no
+/// credentials match, an empty list is returned.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let creds = w::CredEnumerate(Some("MyApp/*"), co::CRED_ENUMERATE::NoValue)?;
+/// for cred in creds.credentials() {
+///     println!("{} {}",
+///         cred.TargetName().unwrap_or_default(),
+///         cred.UserName().unwrap_or_default());
+/// }
+/// # Ok::<_, co::ERROR>(())
+/// ```
+#[must_use]
+pub fn CredEnumerate(
+	filter: Option<&str>,
+	flags: co::CRED_ENUMERATE,
+) -> SysResult<CredFreeListGuard>
+{
+	let mut count = u32::default();
+	let mut pcreds = std::ptr::null_mut();
+
+	match bool_to_sysresult(
+		unsafe {
+			ffi::CredEnumerateW(
+				WString::from_opt_str(filter).as_ptr(),
+				flags.raw(),
+				&mut count,
+				&mut pcreds,
+			)
+		},
+	) {
+		Ok(_) => Ok(unsafe { CredFreeListGuard::new(pcreds, count as _) }),
+		Err(co::ERROR::NOT_FOUND) => Ok(unsafe { CredFreeListGuard::new(std::ptr::null_mut(), 0) }),
+		Err(e) => Err(e),
+	}
+}
+
+/// [`CredRead`](https://learn.microsoft.com/en-us/windows/win32/api/wincred/nf-wincred-credreadw)
+/// function.
+///
+/// If the credential doesn't exist,
+/// [`co::ERROR::NOT_FOUND`](crate::co::ERROR::NOT_FOUND) is returned.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let cred = w::CredRead("MyApp/token", co::CRED_TYPE::GENERIC)?;
+/// let token = cred.credential().CredentialBlob().unwrap_or_default().to_vec();
+/// # Ok::<_, co::ERROR>(())
+/// ```
+#[must_use]
+pub fn CredRead(
+	target_name: &str,
+	cred_type: co::CRED_TYPE,
+) -> SysResult<CredFreeGuard>
+{
+	let mut pcred = std::ptr::null_mut();
+	bool_to_sysresult(
+		unsafe {
+			ffi::CredReadW(
+				WString::from_str(target_name).as_ptr(),
+				cred_type.raw(),
+				0,
+				&mut pcred,
+			)
+		},
+	).map(|_| unsafe { CredFreeGuard::new(pcred) })
+}
+
+/// [`CredWrite`](https://learn.microsoft.com/en-us/windows/win32/api/wincred/nf-wincred-credwritew)
+/// function.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let mut target = w::WString::from_str("MyApp/token");
+/// let mut user = w::WString::from_str("john");
+/// let mut blob = b"secret token".to_vec();
+///
+/// let mut cred = w::CREDENTIAL::default();
+/// cred.Type = co::CRED_TYPE::GENERIC;
+/// cred.Persist = co::CRED_PERSIST::LOCAL_MACHINE;
+/// cred.set_TargetName(Some(&mut target));
+/// cred.set_UserName(Some(&mut user));
+/// cred.set_CredentialBlob(Some(&mut blob));
+///
+/// w::CredWrite(&cred, co::CRED_WRITE::NoValue)?;
+/// # Ok::<_, co::ERROR>(())
+/// ```
+pub fn CredWrite(credential: &CREDENTIAL, flags: co::CRED_WRITE) -> SysResult<()> {
+	bool_to_sysresult(
+		unsafe { ffi::CredWriteW(credential as *const _ as _, flags.raw()) },
+	)
+}
+
 /// [`DeleteFile`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-deletefilew)
 /// function.
 pub fn DeleteFile(file_name: &str) -> SysResult<()> {
@@ -223,6 +343,20 @@ pub fn DeleteFile(file_name: &str) -> SysResult<()> {
 	)
 }
 
+/// [`DeleteVolumeMountPoint`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-deletevolumemountpointw)
+/// function.
+///
+/// The mount point must end with a trailing backslash.
+pub fn DeleteVolumeMountPoint(volume_mount_point: &str) -> SysResult<()> {
+	bool_to_sysresult(
+		unsafe {
+			ffi::DeleteVolumeMountPointW(
+				WString::from_str(volume_mount_point).as_ptr(),
+			)
+		},
+	)
+}
+
 /// [`DecryptFile`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-decryptfilew)
 /// function.
 pub fn DecryptFile(file_name: &str) -> SysResult<()> {
@@ -933,6 +1067,38 @@ pub fn GetVolumeInformation(
 	})
 }
 
+/// [`GetVolumeNameForVolumeMountPoint`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-getvolumenameforvolumemountpointw)
+/// function.
+///
+/// Returns the volume GUID path, in the form `\\?\Volume{GUID}\`. The mount
+/// point – a drive root or a folder – must end with a trailing backslash.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let guid_path = w::GetVolumeNameForVolumeMountPoint("C:\\")?;
+/// println!("{}", guid_path);
+/// # Ok::<_, winsafe::co::ERROR>(())
+/// ```
+#[must_use]
+pub fn GetVolumeNameForVolumeMountPoint(
+	volume_mount_point: &str,
+) -> SysResult<String>
+{
+	let mut buf = WString::new_alloc_buf(MAX_PATH + 1);
+	bool_to_sysresult(
+		unsafe {
+			ffi::GetVolumeNameForVolumeMountPointW(
+				WString::from_str(volume_mount_point).as_ptr(),
+				buf.as_mut_ptr(),
+				buf.buf_len() as _,
+			)
+		},
+	).map(|_| buf.to_string())
+}
+
 /// [`GetVolumePathName`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-getvolumepathnamew)
 /// function.
 #[must_use]
@@ -949,6 +1115,50 @@ pub fn GetVolumePathName(file_name: &str) -> SysResult<String> {
 	).map(|_| buf.to_string())
 }
 
+/// [`GetVolumePathNamesForVolumeName`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-getvolumepathnamesforvolumenamew)
+/// function.
+///
+/// Returns the drive letters and folder paths where the volume is mounted.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// for guid_path in w::HFINDVOLUME::iter_volumes() {
+///     let guid_path = guid_path?;
+///     let paths = w::GetVolumePathNamesForVolumeName(&guid_path)?;
+///     println!("{}: {}", guid_path, paths.join(", "));
+/// }
+/// # Ok::<_, winsafe::co::ERROR>(())
+/// ```
+#[must_use]
+pub fn GetVolumePathNamesForVolumeName(
+	volume_name: &str,
+) -> SysResult<Vec<String>>
+{
+	let wvolume_name = WString::from_str(volume_name);
+	let mut len = MAX_PATH as u32 + 1;
+
+	loop {
+		let mut buf = WString::new_alloc_buf(len as usize + 1); // room for terminating null
+		match unsafe {
+			ffi::GetVolumePathNamesForVolumeNameW(
+				wvolume_name.as_ptr(),
+				buf.as_mut_ptr(),
+				len,
+				&mut len, // receives the required length
+			)
+		} {
+			0 => match GetLastError() {
+				co::ERROR::MORE_DATA => continue, // buffer too small, try again
+				err => return Err(err),
+			},
+			_ => return Ok(parse_multi_z_str(buf.as_ptr())),
+		}
+	}
+}
+
 /// [`GetWindowsAccountDomainSid`](https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-getwindowsaccountdomainsid)
 /// function.
 #[must_use]
@@ -1608,6 +1818,38 @@ pub fn SetThreadStackGuarantee(stack_size_in_bytes: u32) -> SysResult<u32> {
 		.map(|_| sz)
 }
 
+/// [`SetVolumeMountPoint`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-setvolumemountpointw)
+/// function.
+///
+/// Mounts the volume, given by its GUID path, at a drive root or at an empty
+/// folder. Both paths must end with a trailing backslash.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// w::SetVolumeMountPoint(
+///     "C:\\Mnt\\Backup\\",
+///     "\\\\?\\Volume{26a21bda-a627-11d7-9931-806e6f6e6963}\\",
+/// )?;
+/// # Ok::<_, winsafe::co::ERROR>(())
+/// ```
+pub fn SetVolumeMountPoint(
+	volume_mount_point: &str,
+	volume_name: &str,
+) -> SysResult<()>
+{
+	bool_to_sysresult(
+		unsafe {
+			ffi::SetVolumeMountPointW(
+				WString::from_str(volume_mount_point).as_ptr(),
+				WString::from_str(volume_name).as_ptr(),
+			)
+		},
+	)
+}
+
 /// [`Sleep`](https://learn.microsoft.com/en-us/windows/win32/api/synchapi/nf-synchapi-sleep)
 /// function.
 pub fn Sleep(milliseconds: u32) {
@@ -5,6 +5,66 @@ use crate::decl::*;
 use crate::kernel::ffi;
 use crate::prelude::*;
 
+/// RAII implementation for [`HACCESSTOKEN`](crate::HACCESSTOKEN) privileges,
+/// which automatically calls
+/// [`AdjustTokenPrivileges`](https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-adjusttokenprivileges)
+/// to restore their previous state when the object goes out of scope.
+pub struct AdjustTokenPrivilegesGuard<'a, H>
+	where H: kernel_Haccesstoken,
+{
+	htoken: &'a H,
+	prev_state: TokenPrivilegesGuard,
+}
+
+impl<'a, H> Drop for AdjustTokenPrivilegesGuard<'a, H>
+	where H: kernel_Haccesstoken,
+{
+	fn drop(&mut self) {
+		if let Some(h) = self.htoken.as_opt() {
+			if !self.prev_state.Privileges().is_empty() {
+				unsafe {
+					ffi::AdjustTokenPrivileges( // ignore errors
+						h.ptr(),
+						0,
+						&*self.prev_state as *const _ as _,
+						0,
+						std::ptr::null_mut(),
+						std::ptr::null_mut(),
+					);
+				}
+			}
+		}
+	}
+}
+
+impl<'a, H> AdjustTokenPrivilegesGuard<'a, H>
+	where H: kernel_Haccesstoken,
+{
+	/// Constructs the guard by taking ownership of the previous state.
+	///
+	/// # Safety
+	///
+	/// Be sure the privileges must be restored with
+	/// [`AdjustTokenPrivileges`](https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-adjusttokenprivileges)
+	/// at the end of scope.
+	///
+	/// This method is used internally by the library, and not intended to be
+	/// used externally.
+	#[must_use]
+	pub const unsafe fn new(htoken: &'a H, prev_state: TokenPrivilegesGuard) -> Self {
+		Self { htoken, prev_state }
+	}
+
+	/// Returns the privileges which were changed, with their previous
+	/// attributes, which will be restored.
+	#[must_use]
+	pub fn prev_state(&self) -> &TOKEN_PRIVILEGES {
+		&self.prev_state
+	}
+}
+
+//------------------------------------------------------------------------------
+
 /// RAII implementation for a [`Handle`](crate::prelude::Handle) which
 /// automatically calls
 /// [`CloseHandle`](https://learn.microsoft.com/en-us/windows/win32/api/handleapi/nf-handleapi-closehandle)
@@ -139,6 +199,99 @@ impl CloseHandlePiGuard {
 
 //------------------------------------------------------------------------------
 
+/// RAII implementation for [`CREDENTIAL`](crate::CREDENTIAL) which
+/// automatically calls
+/// [`CredFree`](https://learn.microsoft.com/en-us/windows/win32/api/wincred/nf-wincred-credfree)
+/// when the object goes out of scope.
+///
+/// The credential is accessed with
+/// [`credential`](crate::guard::CredFreeGuard::credential), whose borrowed
+/// data cannot outlive the guard.
+pub struct CredFreeGuard {
+	pcred: *mut std::ffi::c_void,
+}
+
+impl Drop for CredFreeGuard {
+	fn drop(&mut self) {
+		if !self.pcred.is_null() {
+			unsafe { ffi::CredFree(self.pcred); }
+		}
+	}
+}
+
+impl CredFreeGuard {
+	/// Constructs the guard by taking ownership of the pointer.
+	///
+	/// # Safety
+	///
+	/// Be sure the pointer is a [`CREDENTIAL`](crate::CREDENTIAL) allocated by
+	/// the system, which must be freed with
+	/// [`CredFree`](https://learn.microsoft.com/en-us/windows/win32/api/wincred/nf-wincred-credfree).
+	///
+	/// This method is used internally by the library, and not intended to be
+	/// used externally.
+	#[must_use]
+	pub const unsafe fn new(pcred: *mut std::ffi::c_void) -> Self {
+		Self { pcred }
+	}
+
+	/// Returns the credential, which is valid while the guard is alive.
+	#[must_use]
+	pub fn credential(&self) -> &CREDENTIAL<'_, '_, '_, '_, '_, '_> {
+		unsafe { &*(self.pcred as *const _) }
+	}
+}
+
+/// RAII implementation for an array of [`CREDENTIAL`](crate::CREDENTIAL)
+/// pointers which automatically calls
+/// [`CredFree`](https://learn.microsoft.com/en-us/windows/win32/api/wincred/nf-wincred-credfree)
+/// when the object goes out of scope.
+///
+/// The credentials are accessed with
+/// [`credentials`](crate::guard::CredFreeListGuard::credentials), whose
+/// borrowed data cannot outlive the guard.
+pub struct CredFreeListGuard {
+	pcreds: *mut std::ffi::c_void,
+	count: usize,
+}
+
+impl Drop for CredFreeListGuard {
+	fn drop(&mut self) {
+		if !self.pcreds.is_null() {
+			unsafe { ffi::CredFree(self.pcreds); }
+		}
+	}
+}
+
+impl CredFreeListGuard {
+	/// Constructs the guard by taking ownership of the pointer.
+	///
+	/// # Safety
+	///
+	/// Be sure the pointer is an array of [`CREDENTIAL`](crate::CREDENTIAL)
+	/// pointers allocated by the system, which must be freed with
+	/// [`CredFree`](https://learn.microsoft.com/en-us/windows/win32/api/wincred/nf-wincred-credfree).
+	///
+	/// This method is used internally by the library, and not intended to be
+	/// used externally.
+	#[must_use]
+	pub const unsafe fn new(pcreds: *mut std::ffi::c_void, count: usize) -> Self {
+		Self { pcreds, count }
+	}
+
+	/// Returns the credentials, which are valid while the guard is alive.
+	#[must_use]
+	pub fn credentials(&self) -> &[&CREDENTIAL<'_, '_, '_, '_, '_, '_>] {
+		if self.pcreds.is_null() {
+			&[]
+		} else {
+			unsafe { std::slice::from_raw_parts(self.pcreds as _, self.count) }
+		}
+	}
+}
+
+//------------------------------------------------------------------------------
+
 handle_guard! { DeregisterEventSourceGuard: HEVENTLOG;
 	ffi::DeregisterEventSource;
 	/// RAII implementation for [`HEVENTLOG`](crate::HEVENTLOG) which
@@ -209,6 +362,14 @@ impl EndUpdateResourceGuard {
 
 //------------------------------------------------------------------------------
 
+handle_guard! { FindCloseChangeNotificationGuard: HFINDCHANGE;
+	ffi::FindCloseChangeNotification;
+	/// RAII implementation for [`HFINDCHANGE`](crate::HFINDCHANGE) which
+	/// automatically calls
+	/// [`FindCloseChangeNotification`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-findclosechangenotification)
+	/// when the object goes out of scope.
+}
+
 handle_guard! { FindCloseGuard: HFINDFILE;
 	ffi::FindClose;
 	/// RAII implementation for [`HFINDFILE`](crate::HFINDFILE) which
@@ -217,6 +378,23 @@ handle_guard! { FindCloseGuard: HFINDFILE;
 	/// when the object goes out of scope.
 }
 
+handle_guard! { FindVolumeCloseGuard: HFINDVOLUME;
+	ffi::FindVolumeClose;
+	/// RAII implementation for [`HFINDVOLUME`](crate::HFINDVOLUME) which
+	/// automatically calls
+	/// [`FindVolumeClose`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-findvolumeclose)
+	/// when the object goes out of scope.
+}
+
+handle_guard! { FindVolumeMountPointCloseGuard: HFINDVOLUMEMOUNTPOINT;
+	ffi::FindVolumeMountPointClose;
+	/// RAII implementation for
+	/// [`HFINDVOLUMEMOUNTPOINT`](crate::HFINDVOLUMEMOUNTPOINT) which
+	/// automatically calls
+	/// [`FindVolumeMountPointClose`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-findvolumemountpointclose)
+	/// when the object goes out of scope.
+}
+
 handle_guard! { FreeLibraryGuard: HINSTANCE;
 	ffi::FreeLibrary;
 	/// RAII implementation for [`HINSTANCE`](crate::HINSTANCE) which
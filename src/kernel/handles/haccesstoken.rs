@@ -23,6 +23,221 @@ impl kernel_Haccesstoken for HACCESSTOKEN {}
 /// use winsafe::prelude::*;
 /// ```
 pub trait kernel_Haccesstoken: Handle {
+	/// Enables or disables a privilege, by calling
+	/// [`LookupPrivilegeValue`](crate::LookupPrivilegeValue) and
+	/// [`HACCESSTOKEN::AdjustTokenPrivileges`](crate::prelude::kernel_Haccesstoken::AdjustTokenPrivileges).
+	/// The previous state is restored when the returned guard goes out of
+	/// scope.
+	///
+	/// The token must have been opened with
+	/// [`co::TOKEN::ADJUST_PRIVILEGES`](crate::co::TOKEN::ADJUST_PRIVILEGES)
+	/// and [`co::TOKEN::QUERY`](crate::co::TOKEN::QUERY) access rights. If the
+	/// token doesn't hold the privilege,
+	/// [`co::ERROR::NOT_ALL_ASSIGNED`](crate::co::ERROR::NOT_ALL_ASSIGNED) is
+	/// returned.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let htoken = w::HPROCESS::GetCurrentProcess()
+	///     .OpenProcessToken(co::TOKEN::ADJUST_PRIVILEGES | co::TOKEN::QUERY)?;
+	///
+	/// {
+	///     let _priv = htoken.adjust_privilege(co::SE_PRIV::BACKUP_NAME, true)?;
+	///
+	///     // read files regardless of their ACLs...
+	///
+	///     // previous state of SeBackupPrivilege restored automatically
+	/// }
+	/// # Ok::<_, co::ERROR>(())
+	/// ```
+	#[must_use]
+	fn adjust_privilege(&self,
+		privilege: co::SE_PRIV,
+		enable: bool,
+	) -> SysResult<AdjustTokenPrivilegesGuard<'_, Self>>
+	{
+		let luid = LookupPrivilegeValue(None, privilege)?;
+		let new_state = TOKEN_PRIVILEGES::new(&[
+			LUID_AND_ATTRIBUTES::new(
+				luid,
+				if enable { co::SE_PRIV_ATTR::ENABLED } else { co::SE_PRIV_ATTR::NoValue },
+			),
+		]);
+
+		// Previous state has at most the single privilege being changed.
+		let mut prev_buf = HeapBlock::alloc(std::mem::size_of::<TOKEN_PRIVILEGES>())?;
+		let mut ret_len = u32::default();
+
+		SetLastError(co::ERROR::SUCCESS);
+		bool_to_sysresult(
+			unsafe {
+				ffi::AdjustTokenPrivileges(
+					self.ptr(),
+					0,
+					&*new_state as *const _ as _,
+					prev_buf.as_slice().len() as _,
+					prev_buf.as_mut_ptr(),
+					&mut ret_len,
+				)
+			},
+		)?;
+		match GetLastError() {
+			co::ERROR::SUCCESS => {},
+			err => return Err(err), // NOT_ALL_ASSIGNED
+		}
+
+		let prev_state = unsafe { &*(prev_buf.as_ptr() as *const TOKEN_PRIVILEGES) };
+		Ok(unsafe {
+			AdjustTokenPrivilegesGuard::new(
+				self,
+				TOKEN_PRIVILEGES::new(prev_state.Privileges()),
+			)
+		})
+	}
+
+	/// Returns the elevation type of the token, by calling
+	/// [`HACCESSTOKEN::GetTokenInformation`](crate::prelude::kernel_Haccesstoken::GetTokenInformation).
+	#[must_use]
+	fn elevation_type(&self) -> SysResult<co::TOKEN_ELEVATION_TYPE> {
+		let mut elevation_type = co::TOKEN_ELEVATION_TYPE::default();
+		unsafe {
+			self.GetTokenInformation(
+				co::TOKEN_INFORMATION_CLASS::ElevationType,
+				&mut elevation_type,
+			)
+		}.map(|_| elevation_type)
+	}
+
+	/// Returns the groups of the token, with their names resolved with
+	/// [`LookupAccountSid`](crate::LookupAccountSid).
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let htoken = w::HPROCESS::GetCurrentProcess()
+	///     .OpenProcessToken(co::TOKEN::QUERY)?;
+	///
+	/// for group in htoken.groups()? {
+	///     match &group.account {
+	///         Some((name, domain, _)) => println!("{}\\{}", domain, name),
+	///         None => println!("{}", group.sid),
+	///     }
+	/// }
+	/// # Ok::<_, co::ERROR>(())
+	/// ```
+	#[must_use]
+	fn groups(&self) -> SysResult<Vec<TokenGroup>> {
+		let buf = token_information_buf(self.ptr(), co::TOKEN_INFORMATION_CLASS::Groups)?;
+		let groups = unsafe { &*(buf.as_ptr() as *const TOKEN_GROUPS) };
+
+		groups.Groups().iter()
+			.filter_map(|sa| sa.Sid().map(|sid| (sid, sa.Attributes)))
+			.map(|(sid, attrs)| Ok(TokenGroup {
+				sid: ConvertSidToStringSid(sid)?,
+				account: LookupAccountSid(None, sid).ok(),
+				attributes: unsafe { co::SE_GROUP::from_raw(attrs) },
+			}))
+			.collect()
+	}
+
+	/// Returns the
+	/// [integrity level](https://learn.microsoft.com/en-us/windows/win32/secauthz/mandatory-integrity-control)
+	/// of the token, which is one of the `co::RID::SECURITY_MANDATORY_*`
+	/// constants, like
+	/// [`co::RID::SECURITY_MANDATORY_MEDIUM`](crate::co::RID::SECURITY_MANDATORY_MEDIUM).
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let htoken = w::HACCESSTOKEN::GetCurrentProcessToken();
+	///
+	/// if htoken.integrity_level()? == co::RID::SECURITY_MANDATORY_HIGH {
+	///     println!("Running with high integrity.");
+	/// }
+	/// # Ok::<_, co::ERROR>(())
+	/// ```
+	#[must_use]
+	fn integrity_level(&self) -> SysResult<co::RID> {
+		let buf = token_information_buf(self.ptr(), co::TOKEN_INFORMATION_CLASS::IntegrityLevel)?;
+		let label = unsafe { &*(buf.as_ptr() as *const TOKEN_MANDATORY_LABEL) };
+
+		label.Label.Sid()
+			.and_then(|sid| sid.SubAuthority().last().copied())
+			.ok_or(co::ERROR::INVALID_SID)
+	}
+
+	/// Returns true if the token belongs to the Administrators group.
+	///
+	/// If the token is a UAC filtered token – where the group is present, but
+	/// for deny only –, the membership is checked on its linked token, so
+	/// this method returns true even if the process is not elevated.
+	///
+	/// The token must have been opened with
+	/// [`co::TOKEN::QUERY`](crate::co::TOKEN::QUERY) and
+	/// [`co::TOKEN::DUPLICATE`](crate::co::TOKEN::DUPLICATE) access rights.
+	#[must_use]
+	fn is_admin_member(&self) -> SysResult<bool> {
+		let admins_sid = CreateWellKnownSid(co::WELL_KNOWN_SID_TYPE::BuiltinAdministrators, None)?;
+		let htoken_check = match self.elevation_type()? {
+			co::TOKEN_ELEVATION_TYPE::Limited => self.linked_token()?,
+			_ => self.DuplicateToken(co::SECURITY_IMPERSONATION::Identification)?, // membership requires an impersonation token
+		};
+		htoken_check.CheckTokenMembership(&admins_sid)
+	}
+
+	/// Returns true if the token is elevated, by calling
+	/// [`HACCESSTOKEN::GetTokenInformation`](crate::prelude::kernel_Haccesstoken::GetTokenInformation).
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// if w::HACCESSTOKEN::GetCurrentProcessToken().is_elevated()? {
+	///     println!("Running as administrator.");
+	/// }
+	/// # Ok::<_, co::ERROR>(())
+	/// ```
+	#[must_use]
+	fn is_elevated(&self) -> SysResult<bool> {
+		let mut elevation = TOKEN_ELEVATION::default();
+		unsafe {
+			self.GetTokenInformation(
+				co::TOKEN_INFORMATION_CLASS::Elevation,
+				&mut elevation,
+			)
+		}.map(|_| elevation.TokenIsElevated())
+	}
+
+	/// Returns the token linked to this one, by calling
+	/// [`HACCESSTOKEN::GetTokenInformation`](crate::prelude::kernel_Haccesstoken::GetTokenInformation).
+	///
+	/// With UAC, an administrator logs on with a split token: a filtered one,
+	/// with elevation type
+	/// [`co::TOKEN_ELEVATION_TYPE::Limited`](crate::co::TOKEN_ELEVATION_TYPE::Limited),
+	/// and a full one, with elevation type
+	/// [`co::TOKEN_ELEVATION_TYPE::Full`](crate::co::TOKEN_ELEVATION_TYPE::Full),
+	/// each one linked to the other. Tokens of other users have no linked
+	/// token, and an error is returned.
+	#[must_use]
+	fn linked_token(&self) -> SysResult<CloseHandleGuard<HACCESSTOKEN>> {
+		let mut linked = TOKEN_LINKED_TOKEN::default();
+		unsafe {
+			self.GetTokenInformation(
+				co::TOKEN_INFORMATION_CLASS::Linked,
+				&mut linked,
+			)?;
+			Ok(CloseHandleGuard::new(linked.LinkedToken))
+		}
+	}
+
 	/// [`AdjustTokenPrivileges`](https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-adjusttokenprivileges)
 	/// function.
 	///
@@ -192,3 +407,40 @@ pub trait kernel_Haccesstoken: Handle {
 		}
 	}
 }
+
+/// Retrieves variable-sized token information with
+/// [`GetTokenInformation`](https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-gettokeninformation)
+/// into an allocated buffer.
+fn token_information_buf(
+	htoken: HANDLE,
+	information_class: co::TOKEN_INFORMATION_CLASS,
+) -> SysResult<HeapBlock>
+{
+	let mut ret_len = u32::default();
+	unsafe {
+		ffi::GetTokenInformation( // retrieve needed buffer size
+			htoken,
+			information_class.raw(),
+			std::ptr::null_mut(),
+			0,
+			&mut ret_len,
+		);
+	}
+	match GetLastError() {
+		co::ERROR::INSUFFICIENT_BUFFER | co::ERROR::BAD_LENGTH => {},
+		err => return Err(err),
+	}
+
+	let mut buf = HeapBlock::alloc(ret_len as _)?;
+	bool_to_sysresult(
+		unsafe {
+			ffi::GetTokenInformation(
+				htoken,
+				information_class.raw(),
+				buf.as_mut_ptr(),
+				ret_len,
+				&mut ret_len,
+			)
+		},
+	).map(|_| buf)
+}
@@ -26,6 +26,22 @@ impl kernel_Hfile for HFILE {}
 /// use winsafe::prelude::*;
 /// ```
 pub trait kernel_Hfile: Handle {
+	/// [`CancelIoEx`](https://learn.microsoft.com/en-us/windows/win32/fileio/cancelioex-func)
+	/// function.
+	///
+	/// If `overlapped` is `None`, all pending I/O operations issued by the
+	/// calling process for this handle are cancelled.
+	fn CancelIoEx(&self, overlapped: Option<&mut OVERLAPPED>) -> SysResult<()> {
+		bool_to_sysresult(
+			unsafe {
+				ffi::CancelIoEx(
+					self.ptr(),
+					overlapped.map_or(std::ptr::null_mut(), |lp| lp as *mut _ as _),
+				)
+			},
+		)
+	}
+
 	/// [`CreateFile`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-createfilew)
 	/// function.
 	///
@@ -188,6 +204,30 @@ pub trait kernel_Hfile: Handle {
 		}
 	}
 
+	/// [`GetOverlappedResult`](https://learn.microsoft.com/en-us/windows/win32/api/ioapiset/nf-ioapiset-getoverlappedresult)
+	/// function.
+	///
+	/// Returns the number of bytes transferred. If `wait` is `false` and the
+	/// operation is still pending, fails with
+	/// [`co::ERROR::IO_INCOMPLETE`](crate::co::ERROR::IO_INCOMPLETE).
+	fn GetOverlappedResult(&self,
+		overlapped: &mut OVERLAPPED,
+		wait: bool,
+	) -> SysResult<u32>
+	{
+		let mut bytes_transferred = u32::default();
+		bool_to_sysresult(
+			unsafe {
+				ffi::GetOverlappedResult(
+					self.ptr(),
+					overlapped as *mut _ as _,
+					&mut bytes_transferred,
+					wait as _,
+				)
+			},
+		).map(|_| bytes_transferred)
+	}
+
 	/// [`LockFile`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-lockfile)
 	/// function.
 	///
@@ -237,6 +277,45 @@ pub trait kernel_Hfile: Handle {
 		}
 	}
 
+	/// [`ReadDirectoryChanges`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-readdirectorychangesw)
+	/// function.
+	///
+	/// The handle must be a directory opened with
+	/// [`co::FILE_FLAG::BACKUP_SEMANTICS`](crate::co::FILE_FLAG::BACKUP_SEMANTICS).
+	/// The buffer will receive a sequence of
+	/// [`FILE_NOTIFY_INFORMATION`](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-file_notify_information)
+	/// records, and it must be `DWORD`-aligned.
+	///
+	/// Returns the number of bytes written to the buffer. For an asynchronous
+	/// call, this value is undefined; retrieve it with
+	/// [`HFILE::GetOverlappedResult`](crate::prelude::kernel_Hfile::GetOverlappedResult).
+	///
+	/// Unless you need something specific, consider using the
+	/// [`DirWatcher`](crate::DirWatcher) high-level abstraction.
+	fn ReadDirectoryChanges(&self,
+		buffer: &mut [u8],
+		watch_subtree: bool,
+		notify_filter: co::FILE_NOTIFY_CHANGE,
+		overlapped: Option<&mut OVERLAPPED>,
+	) -> SysResult<u32>
+	{
+		let mut bytes_returned = u32::default();
+		bool_to_sysresult(
+			unsafe {
+				ffi::ReadDirectoryChangesW(
+					self.ptr(),
+					buffer.as_mut_ptr() as _,
+					buffer.len() as _,
+					watch_subtree as _,
+					notify_filter.raw(),
+					&mut bytes_returned,
+					overlapped.map_or(std::ptr::null_mut(), |lp| lp as *mut _ as _),
+					std::ptr::null_mut(),
+				)
+			},
+		).map(|_| bytes_returned)
+	}
+
 	/// [`ReadFile`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-readfile)
 	/// function.
 	///
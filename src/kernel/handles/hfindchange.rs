@@ -0,0 +1,95 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::decl::*;
+use crate::guard::*;
+use crate::kernel::{ffi, privs::*};
+use crate::prelude::*;
+
+impl_handle! { HFINDCHANGE;
+	/// Handle to a
+	/// [change notification](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-findfirstchangenotificationw).
+	/// Originally just a `HANDLE`.
+	///
+	/// Unless you need something specific, consider using the
+	/// [`DirWatcher`](crate::DirWatcher) high-level abstraction.
+}
+
+impl kernel_Hfindchange for HFINDCHANGE {}
+
+/// This trait is enabled with the `kernel` feature, and provides methods for
+/// [`HFINDCHANGE`](crate::HFINDCHANGE).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait kernel_Hfindchange: Handle {
+	/// [`FindFirstChangeNotification`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-findfirstchangenotificationw)
+	/// function.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let hchange = w::HFINDCHANGE::FindFirstChangeNotification(
+	///     "C:\\Temp",
+	///     true,
+	///     co::FILE_NOTIFY_CHANGE::FILE_NAME | co::FILE_NOTIFY_CHANGE::LAST_WRITE,
+	/// )?;
+	///
+	/// loop {
+	///     hchange.WaitForSingleObject(None)?;
+	///     println!("Something changed.");
+	///     hchange.FindNextChangeNotification()?;
+	/// }
+	/// # Ok::<_, co::ERROR>(())
+	/// ```
+	#[must_use]
+	fn FindFirstChangeNotification(
+		path_name: &str,
+		watch_subtree: bool,
+		notify_filter: co::FILE_NOTIFY_CHANGE,
+	) -> SysResult<FindCloseChangeNotificationGuard>
+	{
+		unsafe {
+			match HFINDCHANGE(
+				ffi::FindFirstChangeNotificationW(
+					WString::from_str(path_name).as_ptr(),
+					watch_subtree as _,
+					notify_filter.raw(),
+				),
+			) {
+				HFINDCHANGE::NULL | HFINDCHANGE::INVALID => Err(GetLastError()),
+				handle => Ok(FindCloseChangeNotificationGuard::new(handle)),
+			}
+		}
+	}
+
+	/// [`FindNextChangeNotification`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-findnextchangenotification)
+	/// function.
+	fn FindNextChangeNotification(&self) -> SysResult<()> {
+		bool_to_sysresult(unsafe { ffi::FindNextChangeNotification(self.ptr()) })
+	}
+
+	/// [`WaitForSingleObject`](https://learn.microsoft.com/en-us/windows/win32/api/synchapi/nf-synchapi-waitforsingleobject)
+	/// function.
+	fn WaitForSingleObject(&self,
+		milliseconds: Option<u32>,
+	) -> SysResult<co::WAIT>
+	{
+		match unsafe {
+			co::WAIT::from_raw(
+				ffi::WaitForSingleObject(
+					self.ptr(),
+					milliseconds.unwrap_or(INFINITE),
+				),
+			)
+		} {
+			co::WAIT::FAILED => Err(GetLastError()),
+			wait => Ok(wait),
+		}
+	}
+}
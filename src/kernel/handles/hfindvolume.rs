@@ -0,0 +1,93 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::decl::*;
+use crate::guard::*;
+use crate::kernel::{ffi, iterators::*, privs::*};
+use crate::prelude::*;
+
+impl_handle! { HFINDVOLUME;
+	/// Handle to a
+	/// [volume search](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-findfirstvolumew).
+	/// Originally just a `HANDLE`.
+	///
+	/// Unless you need something specific, consider using the
+	/// [`Volume`](crate::Volume) high-level abstraction.
+}
+
+impl kernel_Hfindvolume for HFINDVOLUME {}
+
+/// This trait is enabled with the `kernel` feature, and provides methods for
+/// [`HFINDVOLUME`](crate::HFINDVOLUME).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait kernel_Hfindvolume: Handle {
+	/// [`FindFirstVolume`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-findfirstvolumew)
+	/// function.
+	///
+	/// Returns the search handle and the GUID path of the first volume.
+	///
+	/// This method is rather tricky, consider using
+	/// [`HFINDVOLUME::iter_volumes`](crate::prelude::kernel_Hfindvolume::iter_volumes).
+	#[must_use]
+	fn FindFirstVolume() -> SysResult<(FindVolumeCloseGuard, String)> {
+		let mut buf = WString::new_alloc_buf(MAX_PATH + 1);
+		unsafe {
+			match HFINDVOLUME(
+				ffi::FindFirstVolumeW(buf.as_mut_ptr(), buf.buf_len() as _),
+			) {
+				HFINDVOLUME::NULL | HFINDVOLUME::INVALID => Err(GetLastError()),
+				handle => Ok((FindVolumeCloseGuard::new(handle), buf.to_string())),
+			}
+		}
+	}
+
+	/// [`FindNextVolume`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-findnextvolumew)
+	/// function.
+	///
+	/// Returns the GUID path of the next volume, or `None` if there are no
+	/// more volumes.
+	///
+	/// This method is rather tricky, consider using
+	/// [`HFINDVOLUME::iter_volumes`](crate::prelude::kernel_Hfindvolume::iter_volumes).
+	#[must_use]
+	fn FindNextVolume(&self) -> SysResult<Option<String>> {
+		let mut buf = WString::new_alloc_buf(MAX_PATH + 1);
+		match unsafe {
+			ffi::FindNextVolumeW(self.ptr(), buf.as_mut_ptr(), buf.buf_len() as _)
+		} {
+			0 => match GetLastError() {
+				co::ERROR::NO_MORE_FILES => Ok(None), // not an error, no further volumes found
+				err => Err(err),
+			},
+			_ => Ok(Some(buf.to_string())),
+		}
+	}
+
+	/// Returns an iterator over the GUID paths of all volumes in the system,
+	/// in the form `\\?\Volume{GUID}\`. Calls
+	/// [`HFINDVOLUME::FindFirstVolume`](crate::prelude::kernel_Hfindvolume::FindFirstVolume)
+	/// and then
+	/// [`HFINDVOLUME::FindNextVolume`](crate::prelude::kernel_Hfindvolume::FindNextVolume)
+	/// consecutively.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// for guid_path in w::HFINDVOLUME::iter_volumes() {
+	///     let guid_path = guid_path?;
+	///     println!("{}", guid_path);
+	/// }
+	/// # Ok::<_, winsafe::co::ERROR>(())
+	/// ```
+	#[must_use]
+	fn iter_volumes() -> Box<dyn Iterator<Item = SysResult<String>>> {
+		Box::new(HfindvolumeIter::new())
+	}
+}
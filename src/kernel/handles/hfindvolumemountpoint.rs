@@ -0,0 +1,118 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::decl::*;
+use crate::guard::*;
+use crate::kernel::{ffi, iterators::*, privs::*};
+use crate::prelude::*;
+
+impl_handle! { HFINDVOLUMEMOUNTPOINT;
+	/// Handle to a
+	/// [volume mount point search](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-findfirstvolumemountpointw).
+	/// Originally just a `HANDLE`.
+	///
+	/// Unless you need something specific, consider using the
+	/// [`Volume`](crate::Volume) high-level abstraction.
+}
+
+impl kernel_Hfindvolumemountpoint for HFINDVOLUMEMOUNTPOINT {}
+
+/// This trait is enabled with the `kernel` feature, and provides methods for
+/// [`HFINDVOLUMEMOUNTPOINT`](crate::HFINDVOLUMEMOUNTPOINT).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait kernel_Hfindvolumemountpoint: Handle {
+	/// [`FindFirstVolumeMountPoint`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-findfirstvolumemountpointw)
+	/// function.
+	///
+	/// Returns the search handle and the first mount point, if any. The mount
+	/// point is a folder path relative to the root of the volume.
+	///
+	/// This method is rather tricky, consider using
+	/// [`HFINDVOLUMEMOUNTPOINT::iter_mount_points`](crate::prelude::kernel_Hfindvolumemountpoint::iter_mount_points).
+	#[must_use]
+	fn FindFirstVolumeMountPoint(
+		root_path_name: &str,
+	) -> SysResult<(FindVolumeMountPointCloseGuard, Option<String>)>
+	{
+		let mut buf = WString::new_alloc_buf(MAX_PATH + 1);
+		unsafe {
+			match HFINDVOLUMEMOUNTPOINT(
+				ffi::FindFirstVolumeMountPointW(
+					WString::from_str(root_path_name).as_ptr(),
+					buf.as_mut_ptr(),
+					buf.buf_len() as _,
+				),
+			) {
+				HFINDVOLUMEMOUNTPOINT::NULL | HFINDVOLUMEMOUNTPOINT::INVALID => {
+					match GetLastError() {
+						co::ERROR::NO_MORE_FILES => Ok(( // not an error, no mount points
+							FindVolumeMountPointCloseGuard::new(HFINDVOLUMEMOUNTPOINT::NULL),
+							None,
+						)),
+						err => Err(err),
+					}
+				},
+				handle => Ok((
+					FindVolumeMountPointCloseGuard::new(handle),
+					Some(buf.to_string()),
+				)),
+			}
+		}
+	}
+
+	/// [`FindNextVolumeMountPoint`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-findnextvolumemountpointw)
+	/// function.
+	///
+	/// Returns the next mount point, or `None` if there are no more mount
+	/// points.
+	///
+	/// This method is rather tricky, consider using
+	/// [`HFINDVOLUMEMOUNTPOINT::iter_mount_points`](crate::prelude::kernel_Hfindvolumemountpoint::iter_mount_points).
+	#[must_use]
+	fn FindNextVolumeMountPoint(&self) -> SysResult<Option<String>> {
+		let mut buf = WString::new_alloc_buf(MAX_PATH + 1);
+		match unsafe {
+			ffi::FindNextVolumeMountPointW(
+				self.ptr(), buf.as_mut_ptr(), buf.buf_len() as _)
+		} {
+			0 => match GetLastError() {
+				co::ERROR::NO_MORE_FILES => Ok(None), // not an error, no further mount points found
+				err => Err(err),
+			},
+			_ => Ok(Some(buf.to_string())),
+		}
+	}
+
+	/// Returns an iterator over the folder mount points hosted on the given
+	/// volume, relative to the volume root. Calls
+	/// [`HFINDVOLUMEMOUNTPOINT::FindFirstVolumeMountPoint`](crate::prelude::kernel_Hfindvolumemountpoint::FindFirstVolumeMountPoint)
+	/// and then
+	/// [`HFINDVOLUMEMOUNTPOINT::FindNextVolumeMountPoint`](crate::prelude::kernel_Hfindvolumemountpoint::FindNextVolumeMountPoint)
+	/// consecutively.
+	///
+	/// Enumerating mount points usually requires administrative rights.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// for mount_point in w::HFINDVOLUMEMOUNTPOINT::iter_mount_points("C:\\") {
+	///     let mount_point = mount_point?;
+	///     println!("C:\\{}", mount_point);
+	/// }
+	/// # Ok::<_, winsafe::co::ERROR>(())
+	/// ```
+	#[must_use]
+	fn iter_mount_points<'a>(
+		root_path_name: &'a str,
+	) -> Box<dyn Iterator<Item = SysResult<String>> + 'a>
+	{
+		Box::new(HfindvolumemountpointIter::new(root_path_name))
+	}
+}
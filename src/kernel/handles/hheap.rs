@@ -219,6 +219,52 @@ pub trait kernel_Hheap: Handle {
 		})
 	}
 
+	/// [`HeapSetInformation`](https://learn.microsoft.com/en-us/windows/win32/api/heapapi/nf-heapapi-heapsetinformation)
+	/// function.
+	///
+	/// # Examples
+	///
+	/// Enabling the low-fragmentation heap:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let heap = w::HHEAP::HeapCreate(None, 0, 0)?;
+	/// heap.HeapSetInformation(w::HeapInformation::Compatibility(2))?;
+	/// # Ok::<_, winsafe::co::ERROR>(())
+	/// ```
+	fn HeapSetInformation(&self, information: HeapInformation) -> SysResult<()> {
+		#[repr(C)]
+		struct HEAP_OPTIMIZE_RESOURCES_INFORMATION {
+			Version: u32,
+			Flags: u32,
+		}
+
+		let mut compat = match information {
+			HeapInformation::Compatibility(heap_type) => heap_type,
+			_ => 0,
+		};
+		let mut optimize = HEAP_OPTIMIZE_RESOURCES_INFORMATION { Version: 1, Flags: 0 };
+
+		let (class, pdata, sz) = match information {
+			HeapInformation::Compatibility(_) => (
+				0,
+				&mut compat as *mut _ as *mut std::ffi::c_void,
+				std::mem::size_of::<u32>(),
+			),
+			HeapInformation::EnableTerminationOnCorruption => (1, std::ptr::null_mut(), 0),
+			HeapInformation::OptimizeResources => (
+				3,
+				&mut optimize as *mut _ as _,
+				std::mem::size_of::<HEAP_OPTIMIZE_RESOURCES_INFORMATION>(),
+			),
+		};
+
+		bool_to_sysresult(
+			unsafe { ffi::HeapSetInformation(self.ptr(), class, pdata, sz) },
+		)
+	}
+
 	/// [`HeapSize`](https://learn.microsoft.com/en-us/windows/win32/api/heapapi/nf-heapapi-heapsize)
 	/// function.
 	#[must_use]
@@ -23,6 +23,195 @@ impl kernel_Hinstance for HINSTANCE {}
 /// use winsafe::prelude::*;
 /// ```
 pub trait kernel_Hinstance: Handle {
+	/// Returns all the strings of the
+	/// [`RT_STRING`](https://learn.microsoft.com/en-us/windows/win32/menurc/stringtable-resource)
+	/// resources of the module, as ID/string pairs, sorted by ID.
+	///
+	/// If the module has no string table, an empty `Vec` is returned.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let hexe = w::HINSTANCE::LoadLibrary("hand.exe")?;
+	///
+	/// for (id, text) in hexe.list_res_strings(None)? {
+	///     println!("{}: {}", id, text);
+	/// }
+	/// # Ok::<_, winsafe::co::ERROR>(())
+	/// ```
+	#[must_use]
+	fn list_res_strings(&self,
+		language: Option<LANGID>,
+	) -> SysResult<Vec<(u16, String)>>
+	{
+		let block_ids = std::cell::RefCell::new(Vec::<u16>::default());
+		match self.EnumResourceNames(RtStr::Rt(co::RT::STRING), |name| {
+			if let IdStr::Id(block_id) = name { // string blocks are always numbered
+				block_ids.borrow_mut().push(block_id);
+			}
+			true
+		}) {
+			Ok(_) => {},
+			Err(co::ERROR::RESOURCE_TYPE_NOT_FOUND) => return Ok(Vec::default()),
+			Err(e) => return Err(e),
+		}
+
+		let mut block_ids = block_ids.into_inner();
+		block_ids.sort_unstable();
+
+		let mut strs = Vec::<(u16, String)>::default();
+		for block_id in block_ids.iter() {
+			match self.load_string_block(*block_id, language) {
+				Ok(block) => strs.extend(
+					block.iter().map(|(id, text)| (id, text.to_owned())),
+				),
+				Err(co::ERROR::RESOURCE_LANG_NOT_FOUND) => {}, // block not translated
+				Err(e) => return Err(e),
+			}
+		}
+		Ok(strs)
+	}
+
+	/// Loads and decodes an
+	/// [`RT_GROUP_ICON`](https://learn.microsoft.com/en-us/windows/win32/menurc/newheader)
+	/// resource or, if `is_cursor` is true, an `RT_GROUP_CURSOR` resource.
+	///
+	/// For an example, see [`ResIconGroup`](crate::ResIconGroup).
+	#[must_use]
+	fn load_icon_group(&self,
+		resource_id: IdStr,
+		is_cursor: bool,
+		language: Option<LANGID>,
+	) -> SysResult<ResIconGroup>
+	{
+		let rt = if is_cursor { co::RT::GROUP_CURSOR } else { co::RT::GROUP_ICON };
+		ResIconGroup::parse(
+			self.load_resource_data(resource_id, RtStr::Rt(rt), language)?,
+		)
+	}
+
+	/// Loads an
+	/// [`RT_MANIFEST`](https://learn.microsoft.com/en-us/windows/win32/sbscs/application-manifests)
+	/// resource as text, detecting its encoding.
+	///
+	/// Executables usually embed their manifest with ID 1, while DLLs use ID 2.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let hexe = w::HINSTANCE::GetModuleHandle(None)?;
+	/// let xml = hexe.load_manifest(w::IdStr::Id(1), None)?;
+	/// println!("{}", xml);
+	/// # Ok::<_, winsafe::co::ERROR>(())
+	/// ```
+	#[must_use]
+	fn load_manifest(&self,
+		resource_id: IdStr,
+		language: Option<LANGID>,
+	) -> SysResult<String>
+	{
+		WString::parse(
+			self.load_resource_data(resource_id, RtStr::Rt(co::RT::MANIFEST), language)?,
+		).map(|wstr| wstr.to_string())
+	}
+
+	/// Loads and decodes an
+	/// [`RT_MESSAGETABLE`](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-message_resource_data)
+	/// resource.
+	///
+	/// For an example, see [`ResMessageTable`](crate::ResMessageTable).
+	#[must_use]
+	fn load_message_table(&self,
+		resource_id: IdStr,
+		language: Option<LANGID>,
+	) -> SysResult<ResMessageTable>
+	{
+		ResMessageTable::parse(
+			self.load_resource_data(resource_id, RtStr::Rt(co::RT::MESSAGETABLE), language)?,
+		)
+	}
+
+	/// Loads a string from the
+	/// [`RT_STRING`](https://learn.microsoft.com/en-us/windows/win32/menurc/stringtable-resource)
+	/// resources, by decoding its block.
+	///
+	/// Unlike
+	/// [`HINSTANCE::LoadString`](crate::prelude::user_Hinstance::LoadString),
+	/// the language can be chosen, and strings with embedded nulls are
+	/// returned in full. If the string doesn't exist or it's empty,
+	/// [`co::ERROR::RESOURCE_NAME_NOT_FOUND`](crate::co::ERROR::RESOURCE_NAME_NOT_FOUND)
+	/// is returned.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let hexe = w::HINSTANCE::LoadLibrary("hand.exe")?;
+	/// let text = hexe.load_res_string(
+	///     101,
+	///     Some(w::LANGID::new(co::LANG::PORTUGUESE, co::SUBLANG::PORTUGUESE_BRAZILIAN)),
+	/// )?;
+	/// # Ok::<_, co::ERROR>(())
+	/// ```
+	#[must_use]
+	fn load_res_string(&self,
+		id: u16,
+		language: Option<LANGID>,
+	) -> SysResult<String>
+	{
+		self.load_string_block(ResStringBlock::block_id(id), language)?
+			.get(id)
+			.map(|text| text.to_owned())
+			.ok_or(co::ERROR::RESOURCE_NAME_NOT_FOUND)
+	}
+
+	/// Finds, loads and locks a resource, returning its raw data, by calling
+	/// [`HINSTANCE::FindResource`](crate::prelude::kernel_Hinstance::FindResource)
+	/// – or
+	/// [`HINSTANCE::FindResourceEx`](crate::prelude::kernel_Hinstance::FindResourceEx),
+	/// if a language is given –,
+	/// [`HINSTANCE::LoadResource`](crate::prelude::kernel_Hinstance::LoadResource)
+	/// and
+	/// [`HINSTANCE::LockResource`](crate::prelude::kernel_Hinstance::LockResource).
+	///
+	/// The data lives as long as the module is loaded.
+	#[must_use]
+	fn load_resource_data(&self,
+		resource_id: IdStr,
+		resource_type: RtStr,
+		language: Option<LANGID>,
+	) -> SysResult<&[u8]>
+	{
+		let hres = match language {
+			Some(language) => self.FindResourceEx(resource_id, resource_type, Some(language))?,
+			None => self.FindResource(resource_id, resource_type)?,
+		};
+		let hres_load = self.LoadResource(&hres)?;
+		self.LockResource(&hres, &hres_load)
+	}
+
+	/// Loads and decodes a block of 16 strings of the
+	/// [`RT_STRING`](https://learn.microsoft.com/en-us/windows/win32/menurc/stringtable-resource)
+	/// resources.
+	///
+	/// For an example, see [`ResStringBlock`](crate::ResStringBlock).
+	#[must_use]
+	fn load_string_block(&self,
+		block_id: u16,
+		language: Option<LANGID>,
+	) -> SysResult<ResStringBlock>
+	{
+		ResStringBlock::parse(
+			block_id,
+			self.load_resource_data(IdStr::Id(block_id), RtStr::Rt(co::RT::STRING), language)?,
+		)
+	}
+
 	/// [`EnumResourceLanguages`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-enumresourcelanguagesw)
 	/// function.
 	fn EnumResourceLanguages<F>(&self,
@@ -223,6 +412,9 @@ pub trait kernel_Hinstance: Handle {
 
 	/// [`GetProcAddress`](https://learn.microsoft.com/en-us/windows/win32/api/libloaderapi/nf-libloaderapi-getprocaddress)
 	/// function.
+	///
+	/// To resolve several functions into typed function pointers, consider
+	/// using the [`proc_table!`](crate::proc_table) macro.
 	#[must_use]
 	fn GetProcAddress(&self,
 		proc_name: &str,
@@ -35,6 +35,11 @@ pub trait kernel_Hprocess: Handle {
 
 	/// [`CreateProcess`](https://learn.microsoft.com/en-us/windows/win32/api/processthreadsapi/nf-processthreadsapi-createprocessw)
 	/// function.
+	///
+	/// If `environment` is given, the variables are sorted and serialized as
+	/// an [`Environment`](crate::Environment) block, and
+	/// [`co::CREATE::UNICODE_ENVIRONMENT`](crate::co::CREATE::UNICODE_ENVIRONMENT)
+	/// is automatically added to `creation_flags`.
 	#[must_use]
 	fn CreateProcess(
 		application_name: Option<&str>,
@@ -49,6 +54,11 @@ pub trait kernel_Hprocess: Handle {
 	) -> SysResult<CloseHandlePiGuard>
 	{
 		let mut buf_cmd_line = WString::from_opt_str(command_line);
+		let buf_env = environment.map(|environment| {
+			let mut env = Environment::default();
+			environment.iter().for_each(|(name, val)| env.set(name, val));
+			env.to_block()
+		});
 		let mut pi = PROCESS_INFORMATION::default();
 
 		unsafe {
@@ -59,14 +69,11 @@ pub trait kernel_Hprocess: Handle {
 					process_attrs.map_or(std::ptr::null_mut(), |lp| lp as *mut _ as _),
 					thread_attrs.map_or(std::ptr::null_mut(), |lp| lp as *mut _ as _),
 					inherit_handles as _,
-					creation_flags.raw(),
-					environment.map_or(std::ptr::null_mut(), |environment| {
-						WString::from_str_vec(
-							&environment.iter()
-								.map(|(name, val)| format!("{}={}", name, val))
-								.collect::<Vec<_>>()
-						).as_ptr() as _
-					}),
+					match buf_env {
+						Some(_) => (creation_flags | co::CREATE::UNICODE_ENVIRONMENT).raw(),
+						None => creation_flags.raw(),
+					},
+					buf_env.as_ref().map_or(std::ptr::null_mut(), |buf| buf.as_ptr() as _),
 					WString::from_opt_str(current_dir).as_ptr(),
 					si as *mut _ as _,
 					&mut pi as *mut _ as _,
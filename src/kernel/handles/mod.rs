@@ -1,10 +1,14 @@
 mod haccesstoken;
 mod handle_traits;
+mod hevent;
 mod heventlog;
 mod hfile;
 mod hfilemap;
 mod hfilemapview;
+mod hfindchange;
 mod hfindfile;
+mod hfindvolume;
+mod hfindvolumemountpoint;
 mod hglobal;
 mod hheap;
 mod hinstance;
@@ -20,11 +24,15 @@ mod hupdatesrc;
 
 pub mod decl {
 	pub use super::haccesstoken::HACCESSTOKEN;
+	pub use super::hevent::HEVENT;
 	pub use super::heventlog::HEVENTLOG;
 	pub use super::hfile::HFILE;
 	pub use super::hfilemap::HFILEMAP;
 	pub use super::hfilemapview::HFILEMAPVIEW;
+	pub use super::hfindchange::HFINDCHANGE;
 	pub use super::hfindfile::HFINDFILE;
+	pub use super::hfindvolume::HFINDVOLUME;
+	pub use super::hfindvolumemountpoint::HFINDVOLUMEMOUNTPOINT;
 	pub use super::hglobal::HGLOBAL;
 	pub use super::hheap::HHEAP;
 	pub use super::hinstance::HINSTANCE;
@@ -38,12 +46,6 @@ pub mod decl {
 	pub use super::htransaction::HTRANSACTION;
 	pub use super::hupdatesrc::HUPDATERSRC;
 
-	impl_handle! { HEVENT;
-		/// Handle to an
-		/// [event](https://learn.microsoft.com/en-us/windows/win32/api/synchapi/nf-synchapi-createeventw).
-		/// Originally just a `HANDLE`.
-	}
-
 	impl_handle! { HRSRC;
 		/// Handle to a
 		/// [resource](https://learn.microsoft.com/en-us/windows/win32/api/libloaderapi/nf-libloaderapi-findresourcew).
@@ -66,11 +68,15 @@ pub mod decl {
 pub mod traits {
 	pub use super::haccesstoken::kernel_Haccesstoken;
 	pub use super::handle_traits::*;
+	pub use super::hevent::kernel_Hevent;
 	pub use super::heventlog::kernel_Heventlog;
 	pub use super::hfile::kernel_Hfile;
 	pub use super::hfilemap::kernel_Hfilemap;
 	pub use super::hfilemapview::kernel_Hfilemapview;
+	pub use super::hfindchange::kernel_Hfindchange;
 	pub use super::hfindfile::kernel_Hfindfile;
+	pub use super::hfindvolume::kernel_Hfindvolume;
+	pub use super::hfindvolumemountpoint::kernel_Hfindvolumemountpoint;
 	pub use super::hglobal::kernel_Hglobal;
 	pub use super::hheap::kernel_Hheap;
 	pub use super::hinstance::kernel_Hinstance;
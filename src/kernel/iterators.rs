@@ -1,8 +1,113 @@
 use crate::co;
 use crate::decl::*;
+use crate::guard::*;
 use crate::kernel::ffi;
 use crate::prelude::*;
 
+pub(in crate::kernel) struct HfindvolumeIter {
+	hfind: Option<FindVolumeCloseGuard>,
+	no_more: bool,
+}
+
+impl Iterator for HfindvolumeIter {
+	type Item = SysResult<String>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.no_more {
+			return None;
+		}
+
+		let found = match &self.hfind {
+			None => { // first pass
+				match HFINDVOLUME::FindFirstVolume() {
+					Err(e) => Err(e),
+					Ok((hfind, guid_path)) => {
+						self.hfind = Some(hfind);
+						Ok(Some(guid_path))
+					},
+				}
+			},
+			Some(hfind) => hfind.FindNextVolume(), // subsequent passes
+		};
+
+		match found {
+			Err(e) => {
+				self.no_more = true; // prevent further iterations
+				Some(Err(e))
+			},
+			Ok(None) => {
+				self.no_more = true; // no further volumes
+				None
+			},
+			Ok(Some(guid_path)) => Some(Ok(guid_path)),
+		}
+	}
+}
+
+impl HfindvolumeIter {
+	pub(in crate::kernel) const fn new() -> Self {
+		Self {
+			hfind: None,
+			no_more: false,
+		}
+	}
+}
+
+//------------------------------------------------------------------------------
+
+pub(in crate::kernel) struct HfindvolumemountpointIter<'a> {
+	root_path_name: &'a str,
+	hfind: Option<FindVolumeMountPointCloseGuard>,
+	no_more: bool,
+}
+
+impl<'a> Iterator for HfindvolumemountpointIter<'a> {
+	type Item = SysResult<String>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.no_more {
+			return None;
+		}
+
+		let found = match &self.hfind {
+			None => { // first pass
+				match HFINDVOLUMEMOUNTPOINT::FindFirstVolumeMountPoint(self.root_path_name) {
+					Err(e) => Err(e),
+					Ok((hfind, mount_point)) => {
+						self.hfind = Some(hfind);
+						Ok(mount_point)
+					},
+				}
+			},
+			Some(hfind) => hfind.FindNextVolumeMountPoint(), // subsequent passes
+		};
+
+		match found {
+			Err(e) => {
+				self.no_more = true; // prevent further iterations
+				Some(Err(e))
+			},
+			Ok(None) => {
+				self.no_more = true; // no further mount points
+				None
+			},
+			Ok(Some(mount_point)) => Some(Ok(mount_point)),
+		}
+	}
+}
+
+impl<'a> HfindvolumemountpointIter<'a> {
+	pub(in crate::kernel) const fn new(root_path_name: &'a str) -> Self {
+		Self {
+			root_path_name,
+			hfind: None,
+			no_more: false,
+		}
+	}
+}
+
+//------------------------------------------------------------------------------
+
 pub(in crate::kernel) struct HheapHeapwalkIter<'a, H>
 	where H: kernel_Hheap,
 {
@@ -107,3 +107,114 @@ pub(crate) fn parse_multi_z_str(src: *const u16) -> Vec<String> {
 	}
 	strings
 }
+
+/// Bounds-checked little-endian reader over a byte slice, used to decode binary
+/// structures. Reading past the end yields `Err(co::ERROR::INVALID_DATA)`.
+pub(crate) struct ByteReader<'a> {
+	data: &'a [u8],
+	pos: usize,
+}
+
+impl<'a> ByteReader<'a> {
+	pub(crate) const fn new(data: &'a [u8]) -> Self {
+		Self { data, pos: 0 }
+	}
+
+	/// Current offset from the beginning of the slice.
+	pub(crate) const fn pos(&self) -> usize {
+		self.pos
+	}
+
+	/// Number of bytes not read yet.
+	pub(crate) const fn remaining(&self) -> usize {
+		self.data.len() - self.pos
+	}
+
+	/// Moves to the given offset from the beginning of the slice.
+	pub(crate) fn seek(&mut self, pos: usize) -> SysResult<()> {
+		if pos > self.data.len() {
+			return Err(co::ERROR::INVALID_DATA);
+		}
+		self.pos = pos;
+		Ok(())
+	}
+
+	/// Advances the offset to the next multiple of `boundary`.
+	pub(crate) fn align(&mut self, boundary: usize) -> SysResult<()> {
+		let rem = self.pos % boundary;
+		if rem != 0 {
+			self.skip(boundary - rem)?;
+		}
+		Ok(())
+	}
+
+	pub(crate) fn skip(&mut self, num_bytes: usize) -> SysResult<()> {
+		self.bytes(num_bytes).map(|_| ())
+	}
+
+	pub(crate) fn bytes(&mut self, num_bytes: usize) -> SysResult<&'a [u8]> {
+		let end = self.pos.checked_add(num_bytes)
+			.filter(|end| *end <= self.data.len())
+			.ok_or(co::ERROR::INVALID_DATA)?;
+		let chunk = &self.data[self.pos..end];
+		self.pos = end;
+		Ok(chunk)
+	}
+
+	pub(crate) fn u8(&mut self) -> SysResult<u8> {
+		self.bytes(1).map(|b| b[0])
+	}
+
+	pub(crate) fn u16(&mut self) -> SysResult<u16> {
+		self.bytes(2).map(|b| u16::from_le_bytes([b[0], b[1]]))
+	}
+
+	pub(crate) fn i16(&mut self) -> SysResult<i16> {
+		self.u16().map(|v| v as _)
+	}
+
+	pub(crate) fn u32(&mut self) -> SysResult<u32> {
+		self.bytes(4).map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+	}
+
+	pub(crate) fn i32(&mut self) -> SysResult<i32> {
+		self.u32().map(|v| v as _)
+	}
+
+	/// Reads the given number of UTF-16 chars.
+	pub(crate) fn utf16(&mut self, num_chars: usize) -> SysResult<String> {
+		let raw = self.bytes(num_chars.checked_mul(2).ok_or(co::ERROR::INVALID_DATA)?)?;
+		let wchars = raw.chunks_exact(2)
+			.map(|b| u16::from_le_bytes([b[0], b[1]]))
+			.collect::<Vec<_>>();
+		Ok(String::from_utf16_lossy(&wchars))
+	}
+
+	/// Reads a null-terminated UTF-16 string, consuming the terminating null.
+	pub(crate) fn utf16_nullt(&mut self) -> SysResult<String> {
+		let mut wchars = Vec::<u16>::default();
+		loop {
+			match self.u16()? {
+				0 => break,
+				ch => wchars.push(ch),
+			}
+		}
+		Ok(String::from_utf16_lossy(&wchars))
+	}
+
+	/// Reads a
+	/// [`sz_Or_Ord`](https://learn.microsoft.com/en-us/windows/win32/menurc/dlgtemplateex)
+	/// field: `0x0000` means no value, `0xffff` is followed by an ordinal, and
+	/// anything else is a null-terminated UTF-16 string.
+	pub(crate) fn sz_or_ord(&mut self) -> SysResult<Option<IdStr>> {
+		let first = self.u16()?;
+		Ok(match first {
+			0x0000 => None,
+			0xffff => Some(IdStr::Id(self.u16()?)),
+			_ => {
+				self.pos -= 2; // the first char belongs to the string
+				Some(IdStr::Str(WString::from_str(self.utf16_nullt()?)))
+			},
+		})
+	}
+}
@@ -48,6 +48,65 @@ pub struct CONSOLE_READCONSOLE_CONTROL {
 	pub dwControlKeyState: u32,
 }
 
+/// [`CREDENTIAL`](https://learn.microsoft.com/en-us/windows/win32/api/wincred/ns-wincred-credentialw)
+/// struct.
+///
+/// For generic credentials, prefer the higher-level
+/// [`GenericCredential`](crate::GenericCredential).
+#[repr(C)]
+pub struct CREDENTIAL<'a, 'b, 'c, 'd, 'e, 'f> {
+	pub Flags: co::CRED_FLAGS,
+	pub Type: co::CRED_TYPE,
+	TargetName: *mut u16,
+	Comment: *mut u16,
+	pub LastWritten: FILETIME,
+	CredentialBlobSize: u32,
+	CredentialBlob: *mut u8,
+	pub Persist: co::CRED_PERSIST,
+	AttributeCount: u32,
+	Attributes: *mut CREDENTIAL_ATTRIBUTE<'d, 'd>,
+	TargetAlias: *mut u16,
+	UserName: *mut u16,
+
+	_TargetName: PhantomData<&'a mut u16>,
+	_Comment: PhantomData<&'b mut u16>,
+	_CredentialBlob: PhantomData<&'c mut u8>,
+	_Attributes: PhantomData<&'d mut CREDENTIAL_ATTRIBUTE<'d, 'd>>,
+	_TargetAlias: PhantomData<&'e mut u16>,
+	_UserName: PhantomData<&'f mut u16>,
+}
+
+impl_default!(CREDENTIAL, 'a, 'b, 'c, 'd, 'e, 'f);
+
+impl<'a, 'b, 'c, 'd, 'e, 'f> CREDENTIAL<'a, 'b, 'c, 'd, 'e, 'f> {
+	pub_fn_string_ptr_get_set!('a, TargetName, set_TargetName);
+	pub_fn_string_ptr_get_set!('b, Comment, set_Comment);
+	pub_fn_array_buf_get_set!('c, CredentialBlob, set_CredentialBlob, CredentialBlobSize, u8);
+	pub_fn_array_buf_get_set!('d, Attributes, set_Attributes, AttributeCount, CREDENTIAL_ATTRIBUTE<'d, 'd>);
+	pub_fn_string_ptr_get_set!('e, TargetAlias, set_TargetAlias);
+	pub_fn_string_ptr_get_set!('f, UserName, set_UserName);
+}
+
+/// [`CREDENTIAL_ATTRIBUTE`](https://learn.microsoft.com/en-us/windows/win32/api/wincred/ns-wincred-credential_attributew)
+/// struct.
+#[repr(C)]
+pub struct CREDENTIAL_ATTRIBUTE<'a, 'b> {
+	Keyword: *mut u16,
+	Flags: u32,
+	ValueSize: u32,
+	Value: *mut u8,
+
+	_Keyword: PhantomData<&'a mut u16>,
+	_Value: PhantomData<&'b mut u8>,
+}
+
+impl_default!(CREDENTIAL_ATTRIBUTE, 'a, 'b);
+
+impl<'a, 'b> CREDENTIAL_ATTRIBUTE<'a, 'b> {
+	pub_fn_string_ptr_get_set!('a, Keyword, set_Keyword);
+	pub_fn_array_buf_get_set!('b, Value, set_Value, ValueSize, u8);
+}
+
 /// [`DISK_SPACE_INFORMATION`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/ns-fileapi-disk_space_information)
 /// struct.
 #[repr(C)]
@@ -74,7 +133,7 @@ pub struct DISK_SPACE_INFORMATION {
 /// Can be converted to [`SYSTEMTIME`](crate::SYSTEMTIME) with
 /// [`FileTimeToSystemTime`](crate::FileTimeToSystemTime) function.
 #[repr(C)]
-#[derive(Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub struct FILETIME {
 	pub dwLowDateTime: u32,
 	pub dwHighDateTime: u32,
@@ -0,0 +1,389 @@
+use std::collections::VecDeque;
+
+use crate::co;
+use crate::decl::*;
+use crate::guard::*;
+use crate::prelude::*;
+
+/// A change reported by [`DirWatcher`](crate::DirWatcher).
+///
+/// All paths are absolute, built upon the path of the watched directory.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DirChange {
+	/// A file or folder was added.
+	Added(String),
+	/// A file or folder was removed.
+	Removed(String),
+	/// A file or folder was modified, according to the notify filter.
+	Modified(String),
+	/// A file or folder was renamed.
+	Renamed {
+		/// Path before the rename.
+		old: String,
+		/// Path after the rename.
+		new: String,
+	},
+	/// Changes happened but they could not be detailed, so the directory must
+	/// be rescanned. This happens when the internal buffer overflows, or when
+	/// the watcher is running on the
+	/// [`FindFirstChangeNotification`](crate::prelude::kernel_Hfindchange::FindFirstChangeNotification)
+	/// fallback.
+	Overflow,
+}
+
+//------------------------------------------------------------------------------
+
+enum WatchSource {
+	ReadDirChanges {
+		hdir: CloseHandleGuard<HFILE>,
+		hevent: CloseHandleGuard<HEVENT>,
+		overlapped: Box<OVERLAPPED>, // must have a stable address while pending
+		buffer: HeapBlock,
+		pending: bool,
+	},
+	ChangeNotif {
+		hchange: FindCloseChangeNotificationGuard,
+	},
+}
+
+/// Watches a directory for changes, through
+/// [`HFILE::ReadDirectoryChanges`](crate::prelude::kernel_Hfile::ReadDirectoryChanges).
+/// If the file system doesn't support it, falls back to
+/// [`HFINDCHANGE::FindFirstChangeNotification`](crate::prelude::kernel_Hfindchange::FindFirstChangeNotification),
+/// which reports only [`DirChange::Overflow`](crate::DirChange::Overflow).
+///
+/// The watching starts as soon as the object is created, so no changes are
+/// lost between the creation and the first wait. Pending I/O is cancelled when
+/// the object goes out of scope.
+///
+/// # Examples
+///
+/// Blocking iteration:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let mut watcher = w::DirWatcher::open(
+///     "C:\\Temp",
+///     true,
+///     co::FILE_NOTIFY_CHANGE::FILE_NAME
+///         | co::FILE_NOTIFY_CHANGE::DIR_NAME
+///         | co::FILE_NOTIFY_CHANGE::LAST_WRITE,
+/// )?;
+///
+/// for change in watcher.iter() {
+///     match change? {
+///         w::DirChange::Added(path) => println!("+ {}", path),
+///         w::DirChange::Removed(path) => println!("- {}", path),
+///         w::DirChange::Modified(path) => println!("* {}", path),
+///         w::DirChange::Renamed { old, new } => println!("{} -> {}", old, new),
+///         w::DirChange::Overflow => println!("Rescan needed."),
+///     }
+/// }
+/// # Ok::<_, co::ERROR>(())
+/// ```
+///
+/// Polling within a loop which does other work:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let mut watcher = w::DirWatcher::open(
+///     "C:\\Temp", false, co::FILE_NOTIFY_CHANGE::FILE_NAME)?;
+///
+/// loop {
+///     for change in watcher.wait(Some(500))? { // returns empty on timeout
+///         println!("{:?}", change);
+///     }
+///     // other work...
+/// }
+/// # Ok::<_, co::ERROR>(())
+/// ```
+pub struct DirWatcher {
+	dir_path: String,
+	recursive: bool,
+	filter: co::FILE_NOTIFY_CHANGE,
+	source: WatchSource,
+}
+
+impl Drop for DirWatcher {
+	fn drop(&mut self) {
+		if let WatchSource::ReadDirChanges {
+			hdir, overlapped, pending, ..
+		} = &mut self.source {
+			if *pending { // the buffer must outlive the I/O operation
+				let _ = hdir.CancelIoEx(Some(overlapped));
+				let _ = hdir.GetOverlappedResult(overlapped, true);
+			}
+		}
+	}
+}
+
+impl DirWatcher {
+	/// Size of the buffer which receives the change records. Greater sizes
+	/// would fail on network drives.
+	const BUFFER_SIZE: usize = 64 * 1024;
+
+	/// Opens the directory and immediately starts watching it.
+	#[must_use]
+	pub fn open(
+		dir_path: &str,
+		recursive: bool,
+		filter: co::FILE_NOTIFY_CHANGE,
+	) -> SysResult<Self>
+	{
+		let dir_path = path::rtrim_backslash(dir_path).to_owned();
+
+		let (hdir, _) = HFILE::CreateFile(
+			&dir_path,
+			co::GENERIC::READ,
+			Some(co::FILE_SHARE::READ | co::FILE_SHARE::WRITE | co::FILE_SHARE::DELETE),
+			None,
+			co::DISPOSITION::OPEN_EXISTING,
+			co::FILE_ATTRIBUTE::NORMAL,
+			Some(co::FILE_FLAG::BACKUP_SEMANTICS | co::FILE_FLAG::OVERLAPPED),
+			None,
+			None,
+		)?;
+		let hevent = HEVENT::CreateEvent(None, true, false, None)?;
+		let mut overlapped = Box::new(OVERLAPPED::default());
+		overlapped.hEvent = unsafe { hevent.raw_copy() };
+
+		let mut new_self = Self {
+			dir_path,
+			recursive,
+			filter,
+			source: WatchSource::ReadDirChanges {
+				hdir,
+				hevent,
+				overlapped,
+				buffer: HeapBlock::alloc(Self::BUFFER_SIZE)?,
+				pending: false,
+			},
+		};
+
+		match new_self.arm() {
+			Ok(_) => Ok(new_self),
+			Err(co::ERROR::INVALID_FUNCTION) // file system doesn't support it
+				| Err(co::ERROR::NOT_SUPPORTED) => {
+				let hchange = HFINDCHANGE::FindFirstChangeNotification(
+					&new_self.dir_path, recursive, filter)?;
+				new_self.source = WatchSource::ChangeNotif { hchange };
+				Ok(new_self)
+			},
+			Err(e) => Err(e),
+		}
+	}
+
+	/// Returns the path of the watched directory, without a trailing
+	/// backslash.
+	#[must_use]
+	pub fn dir_path(&self) -> &str {
+		&self.dir_path
+	}
+
+	/// Returns the event which is signaled when changes are available, to be
+	/// used with the overlapped I/O model – for example, to wait on several
+	/// objects at once. After the event is signaled, call
+	/// [`poll`](crate::DirWatcher::poll) to retrieve the changes and restart
+	/// the watching.
+	///
+	/// Returns `None` if the watcher is running on the
+	/// [`FindFirstChangeNotification`](crate::prelude::kernel_Hfindchange::FindFirstChangeNotification)
+	/// fallback, whose handle is returned by
+	/// [`hfindchange`](crate::DirWatcher::hfindchange).
+	#[must_use]
+	pub fn hevent(&self) -> Option<&HEVENT> {
+		match &self.source {
+			WatchSource::ReadDirChanges { hevent, .. } => Some(hevent),
+			WatchSource::ChangeNotif { .. } => None,
+		}
+	}
+
+	/// Returns the change notification handle, which is signaled when changes
+	/// happen, if the watcher is running on the
+	/// [`FindFirstChangeNotification`](crate::prelude::kernel_Hfindchange::FindFirstChangeNotification)
+	/// fallback. Otherwise returns `None`.
+	#[must_use]
+	pub fn hfindchange(&self) -> Option<&HFINDCHANGE> {
+		match &self.source {
+			WatchSource::ReadDirChanges { .. } => None,
+			WatchSource::ChangeNotif { hchange } => Some(hchange),
+		}
+	}
+
+	/// Returns true if the watcher is running on the
+	/// [`FindFirstChangeNotification`](crate::prelude::kernel_Hfindchange::FindFirstChangeNotification)
+	/// fallback, which reports only
+	/// [`DirChange::Overflow`](crate::DirChange::Overflow).
+	#[must_use]
+	pub const fn is_fallback(&self) -> bool {
+		matches!(self.source, WatchSource::ChangeNotif { .. })
+	}
+
+	/// Returns a blocking iterator over the changes, which waits indefinitely
+	/// for new changes.
+	///
+	/// If an error occurs, it will be yielded and the iteration will stop.
+	#[must_use]
+	pub fn iter(&mut self) -> impl Iterator<Item = SysResult<DirChange>> + '_ {
+		DirWatcherIter::new(self)
+	}
+
+	/// Retrieves the changes already available, without blocking. Returns an
+	/// empty `Vec` if there are no changes.
+	#[must_use]
+	pub fn poll(&mut self) -> SysResult<Vec<DirChange>> {
+		self.wait(Some(0))
+	}
+
+	/// Waits for changes up to the given timeout in milliseconds, or
+	/// indefinitely if `None`. Returns an empty `Vec` if the timeout expires.
+	#[must_use]
+	pub fn wait(&mut self, milliseconds: Option<u32>) -> SysResult<Vec<DirChange>> {
+		let changes = match &mut self.source {
+			WatchSource::ReadDirChanges {
+				hdir, hevent, overlapped, buffer, pending,
+			} => {
+				if !*pending {
+					Vec::default() // a previous error interrupted the watching
+				} else if hevent.WaitForSingleObject(milliseconds)? == co::WAIT::TIMEOUT {
+					return Ok(Vec::default());
+				} else {
+					*pending = false;
+					match hdir.GetOverlappedResult(overlapped, false) {
+						Ok(0) | Err(co::ERROR::NOTIFY_ENUM_DIR) => vec![DirChange::Overflow],
+						Ok(num_bytes) => parse_notify_buffer(
+							&self.dir_path,
+							&buffer.as_slice()[..num_bytes as usize],
+						),
+						Err(e) => return Err(e),
+					}
+				}
+			},
+			WatchSource::ChangeNotif { hchange } => {
+				if hchange.WaitForSingleObject(milliseconds)? == co::WAIT::TIMEOUT {
+					return Ok(Vec::default());
+				}
+				vec![DirChange::Overflow]
+			},
+		};
+
+		self.arm()?; // restart the watching
+		Ok(changes)
+	}
+
+	fn arm(&mut self) -> SysResult<()> {
+		match &mut self.source {
+			WatchSource::ReadDirChanges {
+				hdir, overlapped, buffer, pending, ..
+			} => {
+				hdir.ReadDirectoryChanges(
+					buffer.as_mut_slice(),
+					self.recursive,
+					self.filter,
+					Some(overlapped),
+				)?; // an overlapped call returns right away
+				*pending = true;
+				Ok(())
+			},
+			WatchSource::ChangeNotif { hchange } => hchange.FindNextChangeNotification(),
+		}
+	}
+}
+
+/// Parses a buffer filled by
+/// [`HFILE::ReadDirectoryChanges`](crate::prelude::kernel_Hfile::ReadDirectoryChanges),
+/// which contains a sequence of `FILE_NOTIFY_INFORMATION` records. Rename pairs
+/// are merged into a single change.
+#[must_use]
+fn parse_notify_buffer(dir_path: &str, buf: &[u8]) -> Vec<DirChange> {
+	let read_u32 = |off: usize| -> Option<u32> {
+		buf.get(off..off + 4)
+			.map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+	};
+
+	let mut records = Vec::<(co::FILE_ACTION, String)>::default();
+	let mut off = 0;
+
+	while let (Some(next_off), Some(action), Some(name_len)) = (
+		read_u32(off), read_u32(off + 4), read_u32(off + 8), // stops at truncated record
+	) {
+		let (next_off, name_len) = (next_off as usize, name_len as usize);
+		let name = match buf.get(off + 12..off + 12 + name_len) {
+			Some(raw) => {
+				let wchars = raw.chunks_exact(2)
+					.map(|ch| u16::from_le_bytes([ch[0], ch[1]]))
+					.collect::<Vec<_>>();
+				format!("{}\\{}", dir_path, String::from_utf16_lossy(&wchars))
+			},
+			None => break, // truncated name
+		};
+		records.push((unsafe { co::FILE_ACTION::from_raw(action) }, name));
+
+		if next_off == 0 {
+			break; // last record
+		}
+		off += next_off;
+	}
+
+	let mut changes = Vec::<DirChange>::with_capacity(records.len());
+	let mut records = records.into_iter().peekable();
+
+	while let Some((action, name)) = records.next() {
+		changes.push(match action {
+			co::FILE_ACTION::ADDED => DirChange::Added(name),
+			co::FILE_ACTION::REMOVED => DirChange::Removed(name),
+			co::FILE_ACTION::MODIFIED => DirChange::Modified(name),
+			co::FILE_ACTION::RENAMED_OLD_NAME => {
+				match records.next_if(|(a, _)| *a == co::FILE_ACTION::RENAMED_NEW_NAME) {
+					Some((_, new)) => DirChange::Renamed { old: name, new },
+					None => DirChange::Removed(name), // moved out of the watched tree
+				}
+			},
+			co::FILE_ACTION::RENAMED_NEW_NAME => DirChange::Added(name), // moved into the watched tree
+			_ => continue, // unknown action, ignore
+		});
+	}
+
+	changes
+}
+
+//------------------------------------------------------------------------------
+
+struct DirWatcherIter<'a> {
+	watcher: &'a mut DirWatcher,
+	queue: VecDeque<DirChange>,
+	no_more: bool,
+}
+
+impl<'a> Iterator for DirWatcherIter<'a> {
+	type Item = SysResult<DirChange>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.no_more {
+			return None;
+		}
+
+		while self.queue.is_empty() {
+			match self.watcher.wait(None) {
+				Ok(changes) => self.queue.extend(changes),
+				Err(e) => {
+					self.no_more = true; // prevent further iterations
+					return Some(Err(e));
+				},
+			}
+		}
+		self.queue.pop_front().map(Ok)
+	}
+}
+
+impl<'a> DirWatcherIter<'a> {
+	fn new(watcher: &'a mut DirWatcher) -> Self {
+		Self {
+			watcher,
+			queue: VecDeque::default(),
+			no_more: false,
+		}
+	}
+}
@@ -0,0 +1,328 @@
+use std::cmp::Ordering;
+
+use crate::decl::*;
+use crate::kernel::{ffi, privs::*};
+
+/// An ordered, case-insensitive map of environment variables, which can be
+/// parsed from and serialized to a Unicode
+/// [environment block](https://learn.microsoft.com/en-us/windows/win32/procthread/environment-variables).
+///
+/// The variables are kept sorted by name, case-insensitive, which is the
+/// order required by
+/// [`HPROCESS::CreateProcess`](crate::prelude::kernel_Hprocess::CreateProcess).
+/// The hidden per-drive current directory entries, like `=C:`, are preserved.
+///
+/// # Examples
+///
+/// Launching a process with a modified copy of the current environment:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let mut env = w::Environment::current()?;
+/// env.set("MY_APP_MODE", "debug");
+/// env.remove("TEMP");
+///
+/// let mut si = w::STARTUPINFO::default();
+/// let pi = w::HPROCESS::CreateProcess(
+///     None,
+///     Some("notepad.exe"),
+///     None,
+///     None,
+///     false,
+///     co::CREATE::NoValue,
+///     Some(env.iter().collect()),
+///     None,
+///     &mut si,
+/// )?;
+/// # Ok::<_, co::ERROR>(())
+/// ```
+///
+/// Expanding variables against a custom environment:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let mut env = w::Environment::default();
+/// env.set("ROOT", "C:\\Temp");
+///
+/// let path = env.expand("%root%\\logs\\%UNKNOWN%"); // C:\Temp\logs\%UNKNOWN%
+/// ```
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct Environment {
+	vars: Vec<(String, String)>,
+}
+
+impl std::fmt::Debug for Environment {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.debug_map()
+			.entries(self.vars.iter().map(|(k, v)| (k, v)))
+			.finish()
+	}
+}
+
+impl Environment {
+	/// Returns a copy of the environment of the calling process, retrieved
+	/// with
+	/// [`GetEnvironmentStrings`](https://learn.microsoft.com/en-us/windows/win32/api/processenv/nf-processenv-getenvironmentstringsw),
+	/// including the hidden `=C:` entries.
+	#[must_use]
+	pub fn current() -> SysResult<Self> {
+		let ptr = ptr_to_sysresult(unsafe { ffi::GetEnvironmentStringsW() } as _)?
+			as *const u16;
+
+		let mut len = 0;
+		while unsafe { *ptr.add(len) != 0 || *ptr.add(len + 1) != 0 } { // find double null
+			len += 1;
+		}
+		let block = unsafe { std::slice::from_raw_parts(ptr, len + 2) };
+		let new_self = Self::parse_block(block);
+
+		unsafe { ffi::FreeEnvironmentStringsW(ptr as _); }
+		Ok(new_self)
+	}
+
+	/// Parses a double-null-terminated environment block, where each entry
+	/// has the `name=value` form.
+	///
+	/// Parsing stops at the first empty entry or at the end of the slice,
+	/// whichever comes first. Entries without a `=` are ignored. If a name is
+	/// repeated, the last value prevails.
+	#[must_use]
+	pub fn parse_block(block: &[u16]) -> Self {
+		let mut new_self = Self::default();
+
+		for entry in block.split(|ch| *ch == 0) {
+			if entry.is_empty() {
+				break; // double null, end of block
+			}
+			let entry = String::from_utf16_lossy(entry);
+			if let Some((idx, _)) = entry.char_indices()
+				.skip(1) // names like "=C:" start with a "="
+				.find(|(_, ch)| *ch == '=')
+			{
+				new_self.set(&entry[..idx], &entry[idx + 1..]);
+			}
+		}
+		new_self
+	}
+
+	/// Serializes the variables into a double-null-terminated Unicode
+	/// environment block, sorted by name.
+	#[must_use]
+	pub fn to_block(&self) -> Vec<u16> {
+		let mut block = Vec::<u16>::default();
+		for (name, val) in self.vars.iter() {
+			block.extend(name.encode_utf16());
+			block.push('=' as _);
+			block.extend(val.encode_utf16());
+			block.push(0);
+		}
+		if block.is_empty() {
+			block.push(0); // an empty block still needs two nulls
+		}
+		block.push(0);
+		block
+	}
+
+	/// Expands the `%NAME%` references in the string, in the same way of
+	/// [`ExpandEnvironmentStrings`](crate::ExpandEnvironmentStrings), but
+	/// looking up the variables in this map, case-insensitive.
+	///
+	/// References to variables which don't exist are kept as they are,
+	/// including both `%` delimiters. As in `ExpandEnvironmentStrings`, the
+	/// closing `%` of an unknown reference may open the next one.
+	#[must_use]
+	pub fn expand(&self, src: &str) -> String {
+		let mut expanded = String::with_capacity(src.len());
+		let mut rest = src;
+
+		while let Some(idx_open) = rest.find('%') {
+			expanded.push_str(&rest[..idx_open]);
+			let after_open = &rest[idx_open + 1..];
+
+			match after_open.find('%') {
+				None => { // unpaired, copy the rest verbatim
+					rest = &rest[idx_open..];
+					break;
+				},
+				Some(idx_close) => {
+					let name = &after_open[..idx_close];
+					match self.get(name) {
+						Some(val) if !name.is_empty() => expanded.push_str(val),
+						_ => { // not found, keep the text; closing % may open the next reference
+							expanded.push('%');
+							expanded.push_str(name);
+							rest = &after_open[idx_close..];
+							continue;
+						},
+					}
+					rest = &after_open[idx_close + 1..];
+				},
+			}
+		}
+
+		expanded.push_str(rest);
+		expanded
+	}
+
+	/// Returns the value of the variable, case-insensitive.
+	#[must_use]
+	pub fn get(&self, name: &str) -> Option<&str> {
+		self.find(name).ok()
+			.map(|idx| self.vars[idx].1.as_str())
+	}
+
+	/// Returns true if there are no variables.
+	#[must_use]
+	pub fn is_empty(&self) -> bool {
+		self.vars.is_empty()
+	}
+
+	/// Returns an iterator over the name/value pairs, sorted by name.
+	#[must_use]
+	pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+		self.vars.iter()
+			.map(|(name, val)| (name.as_str(), val.as_str()))
+	}
+
+	/// Returns the number of variables.
+	#[must_use]
+	pub fn len(&self) -> usize {
+		self.vars.len()
+	}
+
+	/// Removes the variable, case-insensitive, returning its value.
+	pub fn remove(&mut self, name: &str) -> Option<String> {
+		self.find(name).ok()
+			.map(|idx| self.vars.remove(idx).1)
+	}
+
+	/// Inserts or replaces a variable. If the variable already exists, the
+	/// original case of its name is kept.
+	pub fn set(&mut self, name: &str, val: &str) {
+		match self.find(name) {
+			Ok(idx) => self.vars[idx].1 = val.to_owned(),
+			Err(idx) => self.vars.insert(idx, (name.to_owned(), val.to_owned())),
+		}
+	}
+
+	fn find(&self, name: &str) -> Result<usize, usize> {
+		self.vars.binary_search_by(|(cur, _)| cmp_names(cur, name))
+	}
+}
+
+/// Compares two variable names case-insensitive, in Unicode order, without
+/// regard to locale.
+fn cmp_names(a: &str, b: &str) -> Ordering {
+	a.chars().flat_map(char::to_uppercase)
+		.cmp(b.chars().flat_map(char::to_uppercase))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn env() -> Environment {
+		let mut env = Environment::default();
+		env.set("root", "C:\\Temp");
+		env.set("A", "1");
+		env.set("B", "2");
+		env
+	}
+
+	#[test]
+	fn expand_known() {
+		assert_eq!(env().expand("%ROOT%\\logs"), "C:\\Temp\\logs");
+		assert_eq!(env().expand("no refs"), "no refs");
+	}
+
+	#[test]
+	fn expand_unknown() {
+		assert_eq!(env().expand("%UNKNOWN%"), "%UNKNOWN%");
+		assert_eq!(env().expand("x%UNKNOWN%y"), "x%UNKNOWN%y");
+		assert_eq!(env().expand("%UNKNOWN%A%"), "%UNKNOWN1");
+	}
+
+	#[test]
+	fn expand_lone_percent() {
+		assert_eq!(env().expand("%"), "%");
+		assert_eq!(env().expand("50%"), "50%");
+		assert_eq!(env().expand("%A"), "%A");
+		assert_eq!(env().expand("%A% is 100%"), "1 is 100%");
+	}
+
+	#[test]
+	fn expand_double_percent() {
+		assert_eq!(env().expand("%%"), "%%");
+		assert_eq!(env().expand("a%%b"), "a%%b");
+		assert_eq!(env().expand("%%A%"), "%1");
+	}
+
+	#[test]
+	fn expand_adjacent() {
+		assert_eq!(env().expand("%A%%B%"), "12");
+		assert_eq!(env().expand("%A%%UNKNOWN%%B%"), "1%UNKNOWN%2");
+	}
+
+	fn block(s: &str) -> Vec<u16> {
+		s.encode_utf16().collect()
+	}
+
+	#[test]
+	fn block_round_trip() {
+		let env = Environment::parse_block(&block("A=1\0Path=C:\\x;D:\\y\0EMPTY=\0\0"));
+		assert_eq!(env.iter().collect::<Vec<_>>(),
+			[("A", "1"), ("EMPTY", ""), ("Path", "C:\\x;D:\\y")]);
+		assert_eq!(env.get("PATH"), Some("C:\\x;D:\\y"));
+
+		let serialized = env.to_block();
+		assert_eq!(serialized, block("A=1\0EMPTY=\0Path=C:\\x;D:\\y\0\0"));
+		assert_eq!(Environment::parse_block(&serialized), env);
+	}
+
+	#[test]
+	fn block_drive_entries() {
+		let env = Environment::parse_block(&block("=C:=C:\\Temp\0=D:=D:\\\0A=1\0\0"));
+		assert_eq!(env.get("=C:"), Some("C:\\Temp"));
+		assert_eq!(env.get("=d:"), Some("D:\\"));
+		assert_eq!(env.len(), 3);
+		assert_eq!(env.to_block(), block("=C:=C:\\Temp\0=D:=D:\\\0A=1\0\0")); // "=" sorts first
+
+		let env = Environment::parse_block(&block("NOEQUALS\0=\0B=2\0\0")); // no name
+		assert_eq!(env.iter().collect::<Vec<_>>(), [("B", "2")]);
+	}
+
+	#[test]
+	fn block_sorted_case_insensitive() {
+		let mut env = Environment::default();
+		env.set("zeta", "1");
+		env.set("Beta", "2");
+		env.set("alpha", "3");
+		env.set("ALPHA", "4"); // replaces, keeping the original case
+		env.set("_x", "5");
+		assert_eq!(env.to_block(), block("alpha=4\0Beta=2\0zeta=1\0_x=5\0\0"));
+
+		let env = Environment::parse_block(&block("b=1\0A=2\0a=3\0\0")); // last one prevails
+		assert_eq!(env.iter().collect::<Vec<_>>(), [("A", "3"), ("b", "1")]);
+	}
+
+	#[test]
+	fn block_empty() {
+		assert!(Environment::parse_block(&[0, 0]).is_empty());
+		assert!(Environment::parse_block(&[0]).is_empty());
+		assert!(Environment::parse_block(&[]).is_empty());
+		assert_eq!(Environment::default().to_block(), [0, 0]);
+	}
+
+	#[test]
+	fn block_unterminated() {
+		let env = Environment::parse_block(&block("A=1\0B=2"));
+		assert_eq!(env.iter().collect::<Vec<_>>(), [("A", "1"), ("B", "2")]);
+		let env = Environment::parse_block(&block("A=1\0"));
+		assert_eq!(env.iter().collect::<Vec<_>>(), [("A", "1")]);
+
+		let env = Environment::parse_block(&block("A=1\0\0B=2\0\0")); // stops at the double null
+		assert_eq!(env.len(), 1);
+	}
+}
@@ -0,0 +1,137 @@
+use crate::co;
+use crate::decl::*;
+
+/// A generic credential of the
+/// [Credential Manager](https://learn.microsoft.com/en-us/windows/win32/secauthn/credentials-management),
+/// which is read and written with [`CredRead`](crate::CredRead) and
+/// [`CredWrite`](crate::CredWrite), using
+/// [`co::CRED_TYPE::GENERIC`](crate::co::CRED_TYPE::GENERIC).
+///
+/// The blob is stored encrypted for the current user, and it's limited to
+/// 2,560 bytes.
+///
+/// # Examples
+///
+/// Storing and retrieving a service token:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// w::GenericCredential::with_password(
+///     "MyApp/api", "john", "secret token", co::CRED_PERSIST::LOCAL_MACHINE,
+/// ).write()?;
+///
+/// if let Some(cred) = w::GenericCredential::read("MyApp/api")? {
+///     println!("{}: {}", cred.user_name, cred.password());
+/// }
+///
+/// w::GenericCredential::delete("MyApp/api")?;
+/// # Ok::<_, co::ERROR>(())
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GenericCredential {
+	/// Name which identifies the credential, like `MyApp/api`.
+	pub target_name: String,
+	/// User name.
+	pub user_name: String,
+	/// Secret data, like a password or a token.
+	pub blob: Vec<u8>,
+	/// Where and for how long the credential is stored.
+	pub persist: co::CRED_PERSIST,
+	/// Optional comment, displayed by the Credential Manager.
+	pub comment: Option<String>,
+}
+
+impl GenericCredential {
+	/// Creates a new credential whose blob is the password encoded as
+	/// UTF-16, which is the format used by Windows itself and by the
+	/// Credential Manager control panel.
+	#[must_use]
+	pub fn with_password(
+		target_name: &str,
+		user_name: &str,
+		password: &str,
+		persist: co::CRED_PERSIST,
+	) -> Self
+	{
+		Self {
+			target_name: target_name.to_owned(),
+			user_name: user_name.to_owned(),
+			blob: password.encode_utf16()
+				.flat_map(|ch| ch.to_le_bytes())
+				.collect(),
+			persist,
+			comment: None,
+		}
+	}
+
+	/// Deletes the generic credential with the given target name, with
+	/// [`CredDelete`](crate::CredDelete).
+	pub fn delete(target_name: &str) -> SysResult<()> {
+		CredDelete(target_name, co::CRED_TYPE::GENERIC)
+	}
+
+	/// Lists the generic credentials, optionally filtered by a target name
+	/// prefix followed by an asterisk, like `MyApp/*`, with
+	/// [`CredEnumerate`](crate::CredEnumerate).
+	#[must_use]
+	pub fn enumerate(filter: Option<&str>) -> SysResult<Vec<Self>> {
+		let creds = CredEnumerate(filter, co::CRED_ENUMERATE::NoValue)?;
+		Ok(
+			creds.credentials().iter()
+				.filter(|cred| cred.Type == co::CRED_TYPE::GENERIC)
+				.map(|cred| Self::from_credential(cred))
+				.collect()
+		)
+	}
+
+	/// Reads the generic credential with the given target name, with
+	/// [`CredRead`](crate::CredRead). Returns `None` if it doesn't exist.
+	#[must_use]
+	pub fn read(target_name: &str) -> SysResult<Option<Self>> {
+		match CredRead(target_name, co::CRED_TYPE::GENERIC) {
+			Ok(cred) => Ok(Some(Self::from_credential(cred.credential()))),
+			Err(co::ERROR::NOT_FOUND) => Ok(None),
+			Err(e) => Err(e),
+		}
+	}
+
+	/// Returns the blob decoded as a UTF-16 password, as stored by
+	/// [`GenericCredential::with_password`](crate::GenericCredential::with_password).
+	#[must_use]
+	pub fn password(&self) -> String {
+		let wchars = self.blob.chunks_exact(2)
+			.map(|b| u16::from_le_bytes([b[0], b[1]]))
+			.collect::<Vec<_>>();
+		String::from_utf16_lossy(&wchars)
+	}
+
+	/// Writes the credential with [`CredWrite`](crate::CredWrite), creating
+	/// it or replacing an existing one with the same target name.
+	pub fn write(&self) -> SysResult<()> {
+		let mut target_name = WString::from_str(&self.target_name);
+		let mut user_name = WString::from_str(&self.user_name);
+		let mut comment = self.comment.as_deref().map(WString::from_str);
+		let mut blob = self.blob.clone();
+
+		let mut cred = CREDENTIAL::default();
+		cred.Type = co::CRED_TYPE::GENERIC;
+		cred.Persist = self.persist;
+		cred.set_TargetName(Some(&mut target_name));
+		cred.set_UserName(Some(&mut user_name));
+		cred.set_Comment(comment.as_mut());
+		cred.set_CredentialBlob(if blob.is_empty() { None } else { Some(&mut blob) });
+
+		CredWrite(&cred, co::CRED_WRITE::NoValue)
+	}
+
+	fn from_credential(cred: &CREDENTIAL) -> Self {
+		Self {
+			target_name: cred.TargetName().unwrap_or_default(),
+			user_name: cred.UserName().unwrap_or_default(),
+			blob: cred.CredentialBlob().map_or(Vec::default(), |b| b.to_vec()),
+			persist: cred.Persist,
+			comment: cred.Comment(),
+		}
+	}
+}
@@ -0,0 +1,238 @@
+use std::alloc::{GlobalAlloc, Layout};
+use std::sync::atomic::{AtomicPtr, Ordering};
+
+use crate::co;
+use crate::decl::*;
+use crate::kernel::ffi;
+use crate::prelude::*;
+
+/// Alignment guaranteed by
+/// [`HeapAlloc`](https://learn.microsoft.com/en-us/windows/win32/api/heapapi/nf-heapapi-heapalloc),
+/// `MEMORY_ALLOCATION_ALIGNMENT`.
+#[cfg(target_pointer_width = "64")]
+const MIN_ALIGN: usize = 16;
+#[cfg(target_pointer_width = "32")]
+const MIN_ALIGN: usize = 8;
+
+/// An implementation of
+/// [`GlobalAlloc`](std::alloc::GlobalAlloc) backed by a private
+/// [`HHEAP`](crate::HHEAP), which is created with
+/// [`HHEAP::HeapCreate`](crate::prelude::kernel_Hheap::HeapCreate) and
+/// destroyed – along with all its memory – when the object goes out of scope.
+///
+/// The heap is created on the first allocation, so the allocator can be
+/// declared as a `static`. To catch creation errors early, call
+/// [`HeapAllocator::hheap`](crate::HeapAllocator::hheap) beforehand.
+///
+/// # Examples
+///
+/// Using the private heap as the global allocator:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// #[global_allocator]
+/// static ALLOC: w::HeapAllocator = w::HeapAllocator::new(true, true);
+///
+/// let v = vec![1, 2, 3]; // allocated in the private heap
+///
+/// let stats = ALLOC.stats()?;
+/// println!("{} bytes in {} blocks", stats.busy_bytes, stats.busy_blocks);
+/// # Ok::<_, winsafe::co::ERROR>(())
+/// ```
+///
+/// Keeping the memory of a plugin in an isolated heap, which is released at
+/// once:
+///
+/// ```no_run
+/// use std::alloc::{GlobalAlloc, Layout};
+/// use winsafe::{self as w, prelude::*};
+///
+/// let plugin_heap = w::HeapAllocator::new(true, true);
+///
+/// let layout = Layout::array::<u32>(1024).unwrap();
+/// let buf = unsafe { plugin_heap.alloc_zeroed(layout) } as *mut u32;
+/// // ... hand the buffer to the plugin ...
+///
+/// drop(plugin_heap); // HeapDestroy() releases all the blocks at once
+/// ```
+pub struct HeapAllocator {
+	hheap: AtomicPtr<std::ffi::c_void>,
+	serialize: bool,
+	low_fragmentation: bool,
+}
+
+unsafe impl Send for HeapAllocator {}
+unsafe impl Sync for HeapAllocator {}
+
+impl Drop for HeapAllocator {
+	fn drop(&mut self) {
+		let hheap = *self.hheap.get_mut();
+		if !hheap.is_null() {
+			unsafe { ffi::HeapDestroy(hheap); } // ignore errors
+		}
+	}
+}
+
+unsafe impl GlobalAlloc for HeapAllocator {
+	unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+		self.alloc_flags(layout, co::HEAP_ALLOC::default())
+	}
+
+	unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+		self.alloc_flags(layout, co::HEAP_ALLOC::ZERO_MEMORY)
+	}
+
+	unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+		let hheap = self.hheap.load(Ordering::Acquire);
+		let pmem = if Self::is_overaligned(layout) {
+			*(ptr as *mut *mut u8).sub(1) // original pointer, stored before the block
+		} else {
+			ptr
+		};
+		ffi::HeapFree(hheap, 0, pmem as _);
+	}
+
+	unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+		if Self::is_overaligned(layout) {
+			let new_layout = Layout::from_size_align_unchecked(new_size, layout.align());
+			let new_ptr = self.alloc(new_layout);
+			if !new_ptr.is_null() {
+				std::ptr::copy_nonoverlapping(ptr, new_ptr, layout.size().min(new_size));
+				self.dealloc(ptr, layout);
+			}
+			new_ptr
+		} else {
+			ffi::HeapReAlloc(self.hheap.load(Ordering::Acquire), 0, ptr as _, new_size) as _
+		}
+	}
+}
+
+impl HeapAllocator {
+	/// Creates a new allocator. The heap itself is created on the first
+	/// allocation.
+	///
+	/// If `serialize` is false, the heap is created with
+	/// [`co::HEAP_CREATE::NO_SERIALIZE`](crate::co::HEAP_CREATE::NO_SERIALIZE),
+	/// which is faster, but the allocator must be used by a single thread. If
+	/// `low_fragmentation` is true, the
+	/// [low-fragmentation heap](https://learn.microsoft.com/en-us/windows/win32/memory/low-fragmentation-heap)
+	/// is enabled; this is ignored if the heap is not serialized.
+	#[must_use]
+	pub const fn new(serialize: bool, low_fragmentation: bool) -> Self {
+		Self {
+			hheap: AtomicPtr::new(std::ptr::null_mut()),
+			serialize,
+			low_fragmentation,
+		}
+	}
+
+	/// Returns the underlying heap, creating it if it doesn't exist yet.
+	#[must_use]
+	pub fn hheap(&self) -> SysResult<HHEAP> {
+		let cur = self.hheap.load(Ordering::Acquire);
+		if !cur.is_null() {
+			return Ok(unsafe { HHEAP::from_ptr(cur) });
+		}
+
+		let mut hheap_guard = HHEAP::HeapCreate(
+			if self.serialize { None } else { Some(co::HEAP_CREATE::NO_SERIALIZE) },
+			0,
+			0,
+		)?;
+		if self.low_fragmentation && self.serialize {
+			hheap_guard.HeapSetInformation(HeapInformation::Compatibility(2))?;
+		}
+
+		match self.hheap.compare_exchange(
+			std::ptr::null_mut(),
+			hheap_guard.ptr(),
+			Ordering::AcqRel,
+			Ordering::Acquire,
+		) {
+			Ok(_) => Ok(hheap_guard.leak()),
+			Err(other) => Ok(unsafe { HHEAP::from_ptr(other) }), // another thread won; ours is destroyed
+		}
+	}
+
+	/// Walks the heap with
+	/// [`HHEAP::HeapWalk`](crate::prelude::kernel_Hheap::HeapWalk) and
+	/// returns a summary of its blocks and regions.
+	///
+	/// If the heap is serialized, it's locked during the walk.
+	#[must_use]
+	pub fn stats(&self) -> SysResult<HeapStats> {
+		let hheap = self.hheap()?;
+		let _lock = if self.serialize { Some(hheap.HeapLock()?) } else { None };
+
+		let mut stats = HeapStats::default();
+		for entry in hheap.HeapWalk() {
+			let entry = entry?;
+			if let Some(region) = entry.Region() {
+				stats.regions += 1;
+				stats.committed_bytes += region.dwCommittedSize as usize;
+				stats.uncommitted_bytes += region.dwUnCommittedSize as usize;
+			} else if entry.wFlags.has(co::PROCESS_HEAP::ENTRY_BUSY) {
+				stats.busy_blocks += 1;
+				stats.busy_bytes += entry.cbData as usize;
+				stats.overhead_bytes += entry.cbOverhead as usize;
+			} else if !entry.wFlags.has(co::PROCESS_HEAP::UNCOMMITTED_RANGE) {
+				stats.free_blocks += 1;
+				stats.free_bytes += entry.cbData as usize;
+			}
+		}
+		Ok(stats)
+	}
+
+	const fn is_overaligned(layout: Layout) -> bool {
+		layout.align() > MIN_ALIGN
+	}
+
+	unsafe fn alloc_flags(&self, layout: Layout, flags: co::HEAP_ALLOC) -> *mut u8 {
+		let hheap = match self.hheap() {
+			Ok(hheap) => hheap,
+			Err(_) => return std::ptr::null_mut(),
+		};
+
+		if !Self::is_overaligned(layout) {
+			return ffi::HeapAlloc(hheap.ptr(), flags.raw(), layout.size()) as _;
+		}
+
+		// Allocate extra room to align the block and to store the original
+		// pointer right before it.
+		let sz = match layout.size().checked_add(layout.align()) {
+			Some(sz) => sz,
+			None => return std::ptr::null_mut(),
+		};
+		let pmem = ffi::HeapAlloc(hheap.ptr(), flags.raw(), sz) as *mut u8;
+		if pmem.is_null() {
+			return pmem;
+		}
+		let offset = layout.align() - (pmem as usize & (layout.align() - 1));
+		let aligned = pmem.add(offset);
+		*(aligned as *mut *mut u8).sub(1) = pmem;
+		aligned
+	}
+}
+
+/// Heap statistics returned by
+/// [`HeapAllocator::stats`](crate::HeapAllocator::stats).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct HeapStats {
+	/// Number of regions of contiguous virtual memory.
+	pub regions: usize,
+	/// Committed bytes of all regions.
+	pub committed_bytes: usize,
+	/// Reserved but uncommitted bytes of all regions.
+	pub uncommitted_bytes: usize,
+	/// Number of allocated blocks.
+	pub busy_blocks: usize,
+	/// Bytes of the allocated blocks.
+	pub busy_bytes: usize,
+	/// Bookkeeping bytes of the allocated blocks.
+	pub overhead_bytes: usize,
+	/// Number of free blocks.
+	pub free_blocks: usize,
+	/// Bytes of the free blocks.
+	pub free_bytes: usize,
+}
@@ -1,15 +1,31 @@
+mod dir_watcher;
 mod encoding;
+mod environment;
 mod file_mapped;
 mod file;
+mod generic_credential;
+mod heap_allocator;
 mod heap_block;
 mod ini;
+mod process_snapshot;
+mod resource_data;
+mod token_group;
+mod volume;
 mod w_string;
 
 pub mod path;
 
+pub use dir_watcher::{DirChange, DirWatcher};
 pub use encoding::Encoding;
+pub use environment::Environment;
 pub use file_mapped::FileMapped;
 pub use file::{File, FileAccess};
+pub use generic_credential::GenericCredential;
+pub use heap_allocator::{HeapAllocator, HeapStats};
 pub use heap_block::HeapBlock;
 pub use ini::{Ini, IniEntry, IniSection};
+pub use process_snapshot::{ProcessDiff, ProcessInfo, ProcessModuleInfo, ProcessSnapshot, ProcessThreadInfo};
+pub use resource_data::{ResIconGroup, ResIconGroupEntry, ResMessageTable, ResStringBlock};
+pub use token_group::TokenGroup;
+pub use volume::Volume;
 pub use w_string::WString;
@@ -0,0 +1,427 @@
+use crate::co;
+use crate::decl::*;
+use crate::prelude::*;
+
+/// A snapshot of the running processes, taken with
+/// [`HPROCESSLIST::CreateToolhelp32Snapshot`](crate::prelude::kernel_Hprocesslist::CreateToolhelp32Snapshot),
+/// where each process is joined with its threads and, optionally, its
+/// modules.
+///
+/// The snapshot can be navigated as a parent/child tree, and two snapshots can
+/// be compared to find out which processes started and exited in between.
+///
+/// # Examples
+///
+/// Printing the process tree:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// fn print_tree(snap: &w::ProcessSnapshot, proc: &w::ProcessInfo, level: usize) {
+///     println!("{}{} [{}]", "  ".repeat(level), proc.exe_file, proc.pid);
+///     for child in snap.children(proc) {
+///         print_tree(snap, child, level + 1);
+///     }
+/// }
+///
+/// let snap = w::ProcessSnapshot::take(false)?;
+/// for root in snap.roots() {
+///     print_tree(&snap, root, 0);
+/// }
+/// # Ok::<_, winsafe::co::ERROR>(())
+/// ```
+///
+/// Monitoring started and exited processes:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let mut prev = w::ProcessSnapshot::take(false)?;
+/// loop {
+///     w::Sleep(1000);
+///     let cur = w::ProcessSnapshot::take(false)?;
+///     let diff = prev.diff(&cur);
+///
+///     for proc in diff.started.iter() {
+///         println!("+ {} {}", proc.pid, proc.image_path.as_deref().unwrap_or(&proc.exe_file));
+///     }
+///     for proc in diff.exited.iter() {
+///         println!("- {} {}", proc.pid, proc.exe_file);
+///     }
+///     prev = cur;
+/// }
+/// # Ok::<_, winsafe::co::ERROR>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct ProcessSnapshot {
+	processes: Vec<ProcessInfo>,
+}
+
+/// A process of a [`ProcessSnapshot`](crate::ProcessSnapshot).
+#[derive(Clone, Debug)]
+pub struct ProcessInfo {
+	/// Process ID.
+	pub pid: u32,
+	/// ID of the process which created this one. Note that the parent may have
+	/// already exited, and its ID may have been reused by another process.
+	pub parent_pid: u32,
+	/// Name of the executable file, without the path.
+	pub exe_file: String,
+	/// Full path of the executable, retrieved with
+	/// [`HPROCESS::QueryFullProcessImageName`](crate::prelude::kernel_Hprocess::QueryFullProcessImageName).
+	/// `None` if the process could not be opened, like system processes.
+	pub image_path: Option<String>,
+	/// Creation time, retrieved with
+	/// [`HPROCESS::GetProcessTimes`](crate::prelude::kernel_Hprocess::GetProcessTimes).
+	/// `None` if the process could not be opened.
+	pub creation_time: Option<FILETIME>,
+	/// Base priority of the threads.
+	pub base_priority: i32,
+	/// The threads of the process.
+	pub threads: Vec<ProcessThreadInfo>,
+	/// The modules of the process. Empty if the modules were not requested, or
+	/// if the process could not be inspected.
+	pub modules: Vec<ProcessModuleInfo>,
+}
+
+/// A thread of a [`ProcessInfo`](crate::ProcessInfo).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ProcessThreadInfo {
+	/// Thread ID.
+	pub tid: u32,
+	/// Priority level assigned to the thread.
+	pub base_priority: i32,
+}
+
+/// A module of a [`ProcessInfo`](crate::ProcessInfo).
+#[derive(Clone, Debug)]
+pub struct ProcessModuleInfo {
+	/// Name of the module.
+	pub name: String,
+	/// Full path of the module.
+	pub exe_path: String,
+	/// Base address of the module, in the context of its process, which is
+	/// also its `HMODULE`.
+	pub base_addr: usize,
+	/// Size of the module, in bytes.
+	pub base_size: u32,
+}
+
+/// Processes which started and exited between two snapshots, returned by
+/// [`ProcessSnapshot::diff`](crate::ProcessSnapshot::diff).
+#[derive(Debug)]
+pub struct ProcessDiff<'a> {
+	/// Processes present only in the newer snapshot.
+	pub started: Vec<&'a ProcessInfo>,
+	/// Processes present only in the older snapshot.
+	pub exited: Vec<&'a ProcessInfo>,
+}
+
+impl ProcessSnapshot {
+	/// Takes a snapshot of all processes and their threads. If
+	/// `include_modules` is true, a module snapshot is also taken for each
+	/// process, which is considerably slower.
+	///
+	/// Processes which can't be opened – usually due to access rights – are
+	/// still listed, without image path, creation time and modules.
+	#[must_use]
+	pub fn take(include_modules: bool) -> SysResult<Self> {
+		let mut hpl = HPROCESSLIST::CreateToolhelp32Snapshot(
+			co::TH32CS::SNAPPROCESS | co::TH32CS::SNAPTHREAD, None)?;
+
+		let mut processes = hpl.iter_processes()
+			.map(|pe| pe.map(|pe| ProcessInfo {
+				pid: pe.th32ProcessID,
+				parent_pid: pe.th32ParentProcessID,
+				exe_file: pe.szExeFile(),
+				image_path: None,
+				creation_time: None,
+				base_priority: pe.pcPriClassBase,
+				threads: Vec::default(),
+				modules: Vec::default(),
+			}))
+			.collect::<SysResult<Vec<_>>>()?;
+		processes.sort_by_key(|p| p.pid);
+
+		let mut new_self = Self { processes };
+
+		for te in hpl.iter_threads() {
+			let te = te?;
+			if let Some(proc) = new_self.get_mut(te.th32OwnerProcessID) {
+				proc.threads.push(ProcessThreadInfo {
+					tid: te.th32ThreadID,
+					base_priority: te.tpBasePri,
+				});
+			}
+		}
+
+		for proc in new_self.processes.iter_mut() {
+			proc.query_details();
+			if include_modules {
+				proc.modules = Self::modules_of(proc.pid);
+			}
+		}
+		Ok(new_self)
+	}
+
+	/// Returns the direct children of the process.
+	///
+	/// A process is considered a child only if it was created after its
+	/// parent, so children of an exited parent whose ID was reused are not
+	/// returned.
+	#[must_use]
+	pub fn children<'a>(&'a self,
+		proc: &'a ProcessInfo,
+	) -> impl Iterator<Item = &'a ProcessInfo> + 'a
+	{
+		self.processes.iter()
+			.filter(move |child| child.pid != proc.pid
+				&& child.parent_pid == proc.pid
+				&& Self::created_before(proc, child))
+	}
+
+	/// Returns all the descendants of the process – children, grandchildren
+	/// and so on –, in depth-first order.
+	#[must_use]
+	pub fn descendants<'a>(&'a self, proc: &'a ProcessInfo) -> Vec<&'a ProcessInfo> {
+		let mut found = Vec::<&ProcessInfo>::default();
+		let mut pending = self.children(proc).collect::<Vec<_>>();
+		pending.reverse();
+
+		while let Some(cur) = pending.pop() {
+			if cur.pid == proc.pid || found.iter().any(|f| f.pid == cur.pid) {
+				continue; // guard against cycles
+			}
+			found.push(cur);
+			let first_pending = pending.len();
+			pending.extend(self.children(cur));
+			pending[first_pending..].reverse();
+		}
+		found
+	}
+
+	/// Returns the processes which started and exited between this snapshot
+	/// and a newer one.
+	///
+	/// Processes are matched by ID and, when known, by creation time, so a
+	/// reused process ID is reported as an exit plus a start.
+	#[must_use]
+	pub fn diff<'a>(&'a self, newer: &'a Self) -> ProcessDiff<'a> {
+		ProcessDiff {
+			started: newer.processes.iter()
+				.filter(|cur| !self.contains(cur))
+				.collect(),
+			exited: self.processes.iter()
+				.filter(|prev| !newer.contains(prev))
+				.collect(),
+		}
+	}
+
+	/// Returns the process with the given ID.
+	#[must_use]
+	pub fn get(&self, pid: u32) -> Option<&ProcessInfo> {
+		self.processes.binary_search_by_key(&pid, |p| p.pid).ok()
+			.map(|idx| &self.processes[idx])
+	}
+
+	/// Returns the parent of the process, if it's still running.
+	#[must_use]
+	pub fn parent(&self, proc: &ProcessInfo) -> Option<&ProcessInfo> {
+		self.get(proc.parent_pid)
+			.filter(|parent| parent.pid != proc.pid && Self::created_before(parent, proc))
+	}
+
+	/// Returns all the processes, sorted by ID.
+	#[must_use]
+	pub fn processes(&self) -> &[ProcessInfo] {
+		&self.processes
+	}
+
+	/// Returns the processes without a running parent, which are the roots of
+	/// the process tree.
+	#[must_use]
+	pub fn roots(&self) -> impl Iterator<Item = &ProcessInfo> {
+		self.processes.iter()
+			.filter(|proc| self.parent(proc).is_none())
+	}
+
+	fn contains(&self, proc: &ProcessInfo) -> bool {
+		self.get(proc.pid)
+			.is_some_and(|other| match (other.creation_time, proc.creation_time) {
+				(Some(t1), Some(t2)) => t1 == t2,
+				_ => other.exe_file == proc.exe_file,
+			})
+	}
+
+	fn created_before(parent: &ProcessInfo, child: &ProcessInfo) -> bool {
+		match (parent.creation_time, child.creation_time) {
+			(Some(p), Some(c)) => (p.dwHighDateTime, p.dwLowDateTime)
+				<= (c.dwHighDateTime, c.dwLowDateTime),
+			_ => true, // can't tell, assume so
+		}
+	}
+
+	fn get_mut(&mut self, pid: u32) -> Option<&mut ProcessInfo> {
+		self.processes.binary_search_by_key(&pid, |p| p.pid).ok()
+			.map(|idx| &mut self.processes[idx])
+	}
+
+	fn modules_of(pid: u32) -> Vec<ProcessModuleInfo> {
+		let mut hpl = match HPROCESSLIST::CreateToolhelp32Snapshot(
+			co::TH32CS::SNAPMODULE | co::TH32CS::SNAPMODULE32, Some(pid),
+		) {
+			Ok(hpl) => hpl,
+			Err(_) => return Vec::default(), // access denied, process exited, etc.
+		};
+
+		hpl.iter_modules()
+			.map_while(|me| me.ok())
+			.map(|me| ProcessModuleInfo {
+				name: me.szModule(),
+				exe_path: me.szExePath(),
+				base_addr: me.modBaseAddr as _,
+				base_size: me.modBaseSize,
+			})
+			.collect()
+	}
+}
+
+impl ProcessInfo {
+	fn query_details(&mut self) {
+		if self.pid == 0 { // System Idle Process
+			return;
+		}
+		let hproc = match HPROCESS::OpenProcess(
+			co::PROCESS::QUERY_LIMITED_INFORMATION, false, self.pid,
+		) {
+			Ok(hproc) => hproc,
+			Err(_) => return,
+		};
+
+		self.image_path = hproc.QueryFullProcessImageName(co::PROCESS_NAME::WIN32).ok();
+
+		let (mut creation, mut exit, mut kernel, mut user) =
+			(FILETIME::default(), FILETIME::default(), FILETIME::default(), FILETIME::default());
+		if hproc.GetProcessTimes(&mut creation, &mut exit, &mut kernel, &mut user).is_ok() {
+			self.creation_time = Some(creation);
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn proc(pid: u32, parent_pid: u32, exe_file: &str, created: Option<u32>) -> ProcessInfo {
+		ProcessInfo {
+			pid,
+			parent_pid,
+			exe_file: exe_file.to_owned(),
+			image_path: None,
+			creation_time: created.map(|t| FILETIME { dwLowDateTime: t, dwHighDateTime: 0 }),
+			base_priority: 8,
+			threads: Vec::default(),
+			modules: Vec::default(),
+		}
+	}
+
+	fn snap(mut processes: Vec<ProcessInfo>) -> ProcessSnapshot {
+		processes.sort_by_key(|p| p.pid);
+		ProcessSnapshot { processes }
+	}
+
+	fn pids<'a>(procs: impl IntoIterator<Item = &'a ProcessInfo>) -> Vec<u32> {
+		procs.into_iter().map(|p| p.pid).collect()
+	}
+
+	fn tree() -> ProcessSnapshot {
+		snap(vec![
+			proc(300, 200, "c.exe", Some(40)),
+			proc(0, 0, "[System Process]", None),
+			proc(4, 0, "System", Some(1)),
+			proc(100, 4, "a.exe", Some(10)),
+			proc(200, 100, "b.exe", Some(20)),
+			proc(201, 100, "b.exe", Some(30)),
+			proc(500, 999, "orphan.exe", Some(50)), // parent exited
+			proc(600, 100, "old.exe", Some(5)), // parent ID was reused
+		])
+	}
+
+	#[test]
+	fn get_sorted() {
+		let s = tree();
+		assert_eq!(pids(s.processes()), [0, 4, 100, 200, 201, 300, 500, 600]);
+		assert_eq!(s.get(201).map(|p| p.exe_file.as_str()), Some("b.exe"));
+		assert!(s.get(999).is_none());
+	}
+
+	#[test]
+	fn tree_roots_and_parents() {
+		let s = tree();
+		assert_eq!(pids(s.roots()), [0, 500, 600]);
+		assert_eq!(s.parent(s.get(300).unwrap()).map(|p| p.pid), Some(200));
+		assert!(s.parent(s.get(0).unwrap()).is_none()); // its own parent
+		assert!(s.parent(s.get(600).unwrap()).is_none()); // created before pid 100
+	}
+
+	#[test]
+	fn tree_children_and_descendants() {
+		let s = tree();
+		assert_eq!(pids(s.children(s.get(100).unwrap())), [200, 201]);
+		assert_eq!(pids(s.children(s.get(0).unwrap())), [4]);
+		assert_eq!(pids(s.descendants(s.get(4).unwrap())), [100, 200, 300, 201]);
+		assert!(s.descendants(s.get(300).unwrap()).is_empty());
+	}
+
+	#[test]
+	fn tree_cycle() {
+		let s = snap(vec![
+			proc(10, 20, "x.exe", None),
+			proc(20, 10, "y.exe", None),
+		]);
+		assert_eq!(pids(s.descendants(s.get(10).unwrap())), [20]);
+		assert_eq!(pids(s.descendants(s.get(20).unwrap())), [10]);
+	}
+
+	#[test]
+	fn diff_started_exited() {
+		let prev = snap(vec![
+			proc(4, 0, "System", Some(1)),
+			proc(100, 4, "a.exe", Some(10)),
+			proc(200, 4, "b.exe", Some(20)),
+		]);
+		let cur = snap(vec![
+			proc(4, 0, "System", Some(1)),
+			proc(100, 4, "a.exe", Some(10)),
+			proc(300, 4, "c.exe", Some(30)),
+		]);
+		let diff = prev.diff(&cur);
+		assert_eq!(pids(diff.started), [300]);
+		assert_eq!(pids(diff.exited), [200]);
+
+		let diff = prev.diff(&prev);
+		assert!(diff.started.is_empty() && diff.exited.is_empty());
+	}
+
+	#[test]
+	fn diff_reused_pid() {
+		let prev = snap(vec![
+			proc(100, 4, "a.exe", Some(10)),
+			proc(200, 4, "b.exe", None),
+		]);
+		let cur = snap(vec![
+			proc(100, 4, "a.exe", Some(90)), // same name, new creation time
+			proc(200, 4, "c.exe", None), // no creation time, new name
+		]);
+		let diff = prev.diff(&cur);
+		assert_eq!(pids(diff.started), [100, 200]);
+		assert_eq!(pids(diff.exited), [100, 200]);
+
+		let cur = snap(vec![
+			proc(100, 4, "a.exe", Some(10)),
+			proc(200, 4, "b.exe", Some(20)), // creation time now known
+		]);
+		let diff = prev.diff(&cur);
+		assert!(diff.started.is_empty() && diff.exited.is_empty());
+	}
+}
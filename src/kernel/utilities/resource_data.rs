@@ -0,0 +1,483 @@
+use crate::co;
+use crate::decl::*;
+use crate::kernel::privs::*;
+
+/// A decoded block of an
+/// [`RT_STRING`](https://learn.microsoft.com/en-us/windows/win32/menurc/stringtable-resource)
+/// resource.
+///
+/// String tables are stored in blocks of 16 strings each: the strings with IDs
+/// 0 to 15 belong to block 1, 16 to 31 to block 2, and so on. Each string is
+/// prefixed with its length, and missing strings have zero length.
+///
+/// Usually you'll load the blocks with
+/// [`HINSTANCE::load_string_block`](crate::prelude::kernel_Hinstance::load_string_block),
+/// or retrieve the strings directly with
+/// [`HINSTANCE::load_res_string`](crate::prelude::kernel_Hinstance::load_res_string).
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let hexe = w::HINSTANCE::LoadLibrary("hand.exe")?;
+/// let block = hexe.load_string_block(w::ResStringBlock::block_id(101), None)?;
+///
+/// for (id, text) in block.iter() {
+///     println!("{}: {}", id, text);
+/// }
+/// # Ok::<_, winsafe::co::ERROR>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct ResStringBlock {
+	block_id: u16,
+	strings: Vec<String>,
+}
+
+impl ResStringBlock {
+	/// Returns the ID of the block which contains the given string ID.
+	#[must_use]
+	pub const fn block_id(string_id: u16) -> u16 {
+		string_id / 16 + 1
+	}
+
+	/// Decodes the raw data of a block.
+	#[must_use]
+	pub fn parse(block_id: u16, data: &[u8]) -> SysResult<Self> {
+		if block_id == 0 || block_id > Self::block_id(0xffff) {
+			return Err(co::ERROR::INVALID_PARAMETER);
+		}
+
+		let mut rd = ByteReader::new(data);
+		let mut strings = Vec::<String>::with_capacity(16);
+		for _ in 0..16 {
+			let len = rd.u16()?;
+			strings.push(rd.utf16(len as _)?);
+		}
+		Ok(Self { block_id, strings })
+	}
+
+	/// Returns the ID of the first string of this block.
+	#[must_use]
+	pub const fn first_id(&self) -> u16 {
+		(self.block_id - 1) * 16
+	}
+
+	/// Returns the string with the given ID, if it belongs to this block and
+	/// it's not empty.
+	#[must_use]
+	pub fn get(&self, string_id: u16) -> Option<&str> {
+		if Self::block_id(string_id) != self.block_id {
+			return None;
+		}
+		Some(self.strings[(string_id - self.first_id()) as usize].as_str())
+			.filter(|s| !s.is_empty())
+	}
+
+	/// Returns an iterator over the ID/string pairs of this block, skipping
+	/// the empty ones.
+	#[must_use]
+	pub fn iter(&self) -> impl Iterator<Item = (u16, &str)> {
+		let first_id = self.first_id();
+		self.strings.iter()
+			.enumerate()
+			.filter(|(_, s)| !s.is_empty())
+			.map(move |(idx, s)| (first_id + idx as u16, s.as_str()))
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// A decoded
+/// [`RT_GROUP_ICON`](https://learn.microsoft.com/en-us/windows/win32/menurc/newheader)
+/// or `RT_GROUP_CURSOR` resource, which lists the images of an icon or a
+/// cursor. Each image is stored as a separate `RT_ICON` or `RT_CURSOR`
+/// resource, identified by [`ResIconGroupEntry::id`](crate::ResIconGroupEntry::id).
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let hexe = w::HINSTANCE::LoadLibrary("hand.exe")?;
+/// let group = hexe.load_icon_group(w::IdStr::Id(1), false, None)?;
+///
+/// for entry in group.entries.iter() {
+///     println!("{}x{}, {} bpp, RT_ICON {}",
+///         entry.width, entry.height, entry.bit_count, entry.id);
+/// }
+/// # Ok::<_, co::ERROR>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct ResIconGroup {
+	/// Whether this is a cursor group, rather than an icon group.
+	pub is_cursor: bool,
+	/// The images of the group.
+	pub entries: Vec<ResIconGroupEntry>,
+}
+
+/// An image of a [`ResIconGroup`](crate::ResIconGroup), which corresponds to
+/// a `GRPICONDIRENTRY` or a `GRPCURSORDIRENTRY` structure.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ResIconGroupEntry {
+	/// Width in pixels.
+	pub width: u16,
+	/// Height in pixels. For cursors, the doubled height stored in the
+	/// resource – which accounts for the AND mask – is halved.
+	pub height: u16,
+	/// Number of colors, zero if 8 bpp or more. Always zero for cursors.
+	pub color_count: u8,
+	/// Number of color planes.
+	pub planes: u16,
+	/// Bits per pixel.
+	pub bit_count: u16,
+	/// Size of the image data, in bytes.
+	pub bytes_in_res: u32,
+	/// ID of the `RT_ICON` or `RT_CURSOR` resource with the image data.
+	pub id: u16,
+}
+
+impl ResIconGroup {
+	/// Decodes the raw data of the resource.
+	#[must_use]
+	pub fn parse(data: &[u8]) -> SysResult<Self> {
+		let mut rd = ByteReader::new(data);
+		rd.skip(2)?; // idReserved
+		let is_cursor = match rd.u16()? {
+			1 => false,
+			2 => true,
+			_ => return Err(co::ERROR::INVALID_DATA),
+		};
+		let count = rd.u16()?;
+
+		let entries = (0..count)
+			.map(|_| {
+				let (width, height, color_count) = if is_cursor {
+					(rd.u16()?, rd.u16()? / 2, 0)
+				} else {
+					let width = rd.u8()?;
+					let height = rd.u8()?;
+					let color_count = rd.u8()?;
+					rd.skip(1)?; // bReserved
+					(
+						if width == 0 { 256 } else { width as _ },
+						if height == 0 { 256 } else { height as _ },
+						color_count,
+					)
+				};
+				Ok(ResIconGroupEntry {
+					width,
+					height,
+					color_count,
+					planes: rd.u16()?,
+					bit_count: rd.u16()?,
+					bytes_in_res: rd.u32()?,
+					id: rd.u16()?,
+				})
+			})
+			.collect::<SysResult<Vec<_>>>()?;
+
+		Ok(Self { is_cursor, entries })
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// A decoded
+/// [`RT_MESSAGETABLE`](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-message_resource_data)
+/// resource, usually compiled from a `.mc` file and read by
+/// [`FormatMessage`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-formatmessagew).
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let hdll = w::HINSTANCE::LoadLibrary("netmsg.dll")?;
+/// let table = hdll.load_message_table(w::IdStr::Id(1), None)?;
+///
+/// for (id, text) in table.iter() {
+///     println!("{:#010x}: {}", id, text);
+/// }
+/// # Ok::<_, winsafe::co::ERROR>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct ResMessageTable {
+	entries: Vec<(u32, String)>,
+}
+
+impl ResMessageTable {
+	/// Decodes the raw data of the resource.
+	///
+	/// ANSI entries are decoded with the system code page. Trailing nulls are
+	/// removed, but the trailing line breaks are kept, as stored.
+	#[must_use]
+	pub fn parse(data: &[u8]) -> SysResult<Self> {
+		const MESSAGE_RESOURCE_UNICODE: u16 = 0x0001;
+
+		let mut rd = ByteReader::new(data);
+		let num_blocks = rd.u32()?;
+		let mut entries = Vec::<(u32, String)>::default();
+
+		for idx_block in 0..num_blocks {
+			rd.seek(4 + idx_block as usize * 12)?; // MESSAGE_RESOURCE_BLOCK
+			let low_id = rd.u32()?;
+			let high_id = rd.u32()?;
+			let offset = rd.u32()?;
+			if high_id < low_id {
+				return Err(co::ERROR::INVALID_DATA);
+			}
+
+			rd.seek(offset as _)?;
+			for id in low_id..=high_id {
+				let len = rd.u16()? as usize; // MESSAGE_RESOURCE_ENTRY, including header
+				let flags = rd.u16()?;
+				let raw = rd.bytes(len.checked_sub(4).ok_or(co::ERROR::INVALID_DATA)?)?;
+
+				let text = if flags & MESSAGE_RESOURCE_UNICODE != 0 {
+					let wchars = raw.chunks_exact(2)
+						.map(|b| u16::from_le_bytes([b[0], b[1]]))
+						.take_while(|ch| *ch != 0)
+						.collect::<Vec<_>>();
+					String::from_utf16_lossy(&wchars)
+				} else {
+					let raw = &raw[..raw.iter().position(|b| *b == 0).unwrap_or(raw.len())];
+					if raw.is_ascii() { // same in all ANSI code pages
+						String::from_utf8_lossy(raw).into_owned()
+					} else {
+						WString::from_wchars_slice(
+							&MultiByteToWideChar(co::CP::ACP, co::MBC::NoValue, raw)?,
+						).to_string()
+					}
+				};
+				entries.push((id, text));
+			}
+		}
+
+		entries.sort_by_key(|(id, _)| *id);
+		Ok(Self { entries })
+	}
+
+	/// Returns the message with the given ID.
+	#[must_use]
+	pub fn get(&self, id: u32) -> Option<&str> {
+		self.entries.binary_search_by_key(&id, |(cur, _)| *cur).ok()
+			.map(|idx| self.entries[idx].1.as_str())
+	}
+
+	/// Returns an iterator over the ID/message pairs, sorted by ID.
+	#[must_use]
+	pub fn iter(&self) -> impl Iterator<Item = (u32, &str)> {
+		self.entries.iter()
+			.map(|(id, text)| (*id, text.as_str()))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn string_block(strings: &[(usize, &str)]) -> Vec<u8> {
+		let mut data = Vec::<u8>::default();
+		for idx in 0..16 {
+			let s = strings.iter().find(|(i, _)| *i == idx).map_or("", |(_, s)| s);
+			let wchars = s.encode_utf16().collect::<Vec<_>>();
+			data.extend_from_slice(&(wchars.len() as u16).to_le_bytes());
+			wchars.iter().for_each(|ch| data.extend_from_slice(&ch.to_le_bytes()));
+		}
+		data
+	}
+
+	#[test]
+	fn string_block_parse() {
+		let data = string_block(&[(5, "Hello"), (15, "último")]);
+		let block = ResStringBlock::parse(ResStringBlock::block_id(101), &data).unwrap();
+		assert_eq!(block.first_id(), 96);
+		assert_eq!(block.get(101), Some("Hello"));
+		assert_eq!(block.get(111), Some("último"));
+		assert_eq!(block.get(100), None); // empty
+		assert_eq!(block.get(5), None); // other block
+		assert_eq!(block.iter().collect::<Vec<_>>(), [(101, "Hello"), (111, "último")]);
+	}
+
+	#[test]
+	fn string_block_ids() {
+		assert_eq!(ResStringBlock::block_id(0), 1);
+		assert_eq!(ResStringBlock::block_id(15), 1);
+		assert_eq!(ResStringBlock::block_id(16), 2);
+		assert_eq!(ResStringBlock::block_id(0xffff), 4096);
+
+		let data = string_block(&[]);
+		assert!(ResStringBlock::parse(0, &data).is_err());
+		assert!(ResStringBlock::parse(4097, &data).is_err());
+		let last = ResStringBlock::parse(4096, &data).unwrap();
+		assert_eq!(last.first_id(), 0xfff0);
+		assert_eq!(last.iter().count(), 0);
+	}
+
+	#[test]
+	fn string_block_truncated() {
+		let data = string_block(&[(0, "abc"), (15, "xyz")]);
+		for len in [0, 1, 2, 10, data.len() - 1] {
+			assert!(ResStringBlock::parse(1, &data[..len]).is_err(), "len {}", len);
+		}
+	}
+
+	/// Icon entries are `(width, height, colors, bit_count, id)`, cursor
+	/// entries have 16-bit sizes and no colors.
+	fn icon_group(is_cursor: bool, entries: &[(u16, u16, u8, u16, u16)]) -> Vec<u8> {
+		let mut data = Vec::<u8>::default();
+		data.extend_from_slice(&0u16.to_le_bytes());
+		data.extend_from_slice(&(if is_cursor { 2u16 } else { 1u16 }).to_le_bytes());
+		data.extend_from_slice(&(entries.len() as u16).to_le_bytes());
+		for &(width, height, colors, bit_count, id) in entries {
+			if is_cursor {
+				data.extend_from_slice(&width.to_le_bytes());
+				data.extend_from_slice(&height.to_le_bytes());
+			} else {
+				data.extend_from_slice(&[width as u8, height as u8, colors, 0]);
+			}
+			data.extend_from_slice(&1u16.to_le_bytes()); // planes
+			data.extend_from_slice(&bit_count.to_le_bytes());
+			data.extend_from_slice(&(id as u32 * 100).to_le_bytes()); // bytes_in_res
+			data.extend_from_slice(&id.to_le_bytes());
+		}
+		data
+	}
+
+	#[test]
+	fn icon_group_icons() {
+		let data = icon_group(false, &[(16, 16, 16, 4, 1), (0, 0, 0, 32, 2)]);
+		let group = ResIconGroup::parse(&data).unwrap();
+		assert!(!group.is_cursor);
+		assert_eq!(group.entries, [
+			ResIconGroupEntry {
+				width: 16, height: 16, color_count: 16, planes: 1,
+				bit_count: 4, bytes_in_res: 100, id: 1,
+			},
+			ResIconGroupEntry { // zero means 256
+				width: 256, height: 256, color_count: 0, planes: 1,
+				bit_count: 32, bytes_in_res: 200, id: 2,
+			},
+		]);
+	}
+
+	#[test]
+	fn icon_group_cursors() {
+		let data = icon_group(true, &[(32, 64, 0, 1, 7), (256, 512, 0, 32, 8)]);
+		let group = ResIconGroup::parse(&data).unwrap();
+		assert!(group.is_cursor);
+		let sizes = group.entries.iter()
+			.map(|e| (e.width, e.height, e.color_count, e.id))
+			.collect::<Vec<_>>();
+		assert_eq!(sizes, [(32, 32, 0, 7), (256, 256, 0, 8)]); // height halved
+	}
+
+	#[test]
+	fn icon_group_malformed() {
+		let data = icon_group(false, &[(16, 16, 0, 32, 1), (32, 32, 0, 32, 2)]);
+		for len in [0, 5, 6 + 13, data.len() - 1] {
+			assert!(ResIconGroup::parse(&data[..len]).is_err(), "len {}", len);
+		}
+
+		let mut bad_type = data.clone();
+		bad_type[2] = 3;
+		assert_eq!(ResIconGroup::parse(&bad_type).err(), Some(co::ERROR::INVALID_DATA));
+
+		let mut bad_count = data.clone();
+		bad_count[4] = 3; // one entry more than stored
+		assert_eq!(ResIconGroup::parse(&bad_count).err(), Some(co::ERROR::INVALID_DATA));
+
+		let empty = icon_group(true, &[]);
+		assert_eq!(ResIconGroup::parse(&empty).unwrap().entries.len(), 0);
+	}
+
+	/// Builds a message table from `(low_id, entries)` blocks. Entries are
+	/// `(is_unicode, text)`, stored with a trailing null and padded to 4 bytes.
+	fn message_table(blocks: &[(u32, &[(bool, &str)])]) -> Vec<u8> {
+		let mut entries_data = Vec::<u8>::default();
+		let mut data = Vec::<u8>::default();
+		data.extend_from_slice(&(blocks.len() as u32).to_le_bytes());
+
+		let entries_off = 4 + blocks.len() * 12;
+		for &(low_id, entries) in blocks {
+			data.extend_from_slice(&low_id.to_le_bytes());
+			data.extend_from_slice(&(low_id + entries.len() as u32 - 1).to_le_bytes());
+			data.extend_from_slice(&((entries_off + entries_data.len()) as u32).to_le_bytes());
+
+			for &(is_unicode, text) in entries {
+				let mut raw = if is_unicode {
+					text.encode_utf16()
+						.chain([0])
+						.flat_map(|ch| ch.to_le_bytes())
+						.collect::<Vec<_>>()
+				} else {
+					text.bytes().chain([0]).collect::<Vec<_>>()
+				};
+				raw.resize(raw.len().div_ceil(4) * 4, 0);
+				entries_data.extend_from_slice(&(raw.len() as u16 + 4).to_le_bytes());
+				entries_data.extend_from_slice(&(is_unicode as u16).to_le_bytes());
+				entries_data.extend_from_slice(&raw);
+			}
+		}
+
+		data.extend_from_slice(&entries_data);
+		data
+	}
+
+	#[test]
+	fn message_table_parse() {
+		let data = message_table(&[
+			(0x100, &[(true, "Unicode ção\r\n"), (false, "ANSI\r\n")]),
+			(0x10, &[(false, ""), (true, "")]),
+		]);
+		let table = ResMessageTable::parse(&data).unwrap();
+		assert_eq!(table.get(0x100), Some("Unicode ção\r\n"));
+		assert_eq!(table.get(0x101), Some("ANSI\r\n"));
+		assert_eq!(table.get(0x10), Some(""));
+		assert_eq!(table.get(0x11), Some(""));
+		assert_eq!(table.get(0x102), None);
+		assert_eq!(table.iter().map(|(id, _)| id).collect::<Vec<_>>(), [0x10, 0x11, 0x100, 0x101]); // sorted
+	}
+
+	#[test]
+	fn message_table_block_offsets() {
+		let mut data = message_table(&[(1, &[(true, "one")]), (5, &[(true, "five")])]);
+		let len = data.len() as u32;
+		data[4 + 8..][..4].copy_from_slice(&len.to_le_bytes()); // entries at the very end
+		assert_eq!(ResMessageTable::parse(&data).err(), Some(co::ERROR::INVALID_DATA));
+
+		data[4 + 8..][..4].copy_from_slice(&u32::MAX.to_le_bytes());
+		assert_eq!(ResMessageTable::parse(&data).err(), Some(co::ERROR::INVALID_DATA));
+
+		let mut data = message_table(&[(5, &[(true, "five")])]);
+		data[4..8].copy_from_slice(&6u32.to_le_bytes()); // low_id > high_id
+		assert_eq!(ResMessageTable::parse(&data).err(), Some(co::ERROR::INVALID_DATA));
+
+		let mut data = message_table(&[(1, &[(true, "one")])]);
+		data[0..4].copy_from_slice(&2u32.to_le_bytes()); // more blocks than stored
+		assert_eq!(ResMessageTable::parse(&data).err(), Some(co::ERROR::INVALID_DATA));
+	}
+
+	#[test]
+	fn message_table_malformed_entries() {
+		let data = message_table(&[(1, &[(true, "one"), (true, "two")])]);
+		for len in [0, 3, 4 + 11, data.len() - 1] {
+			assert!(ResMessageTable::parse(&data[..len]).is_err(), "len {}", len);
+		}
+
+		let entries_off = 4 + 12;
+		for len in [0u16, 3] { // shorter than the entry header
+			let mut bad = data.clone();
+			bad[entries_off..][..2].copy_from_slice(&len.to_le_bytes());
+			assert_eq!(ResMessageTable::parse(&bad).err(), Some(co::ERROR::INVALID_DATA));
+		}
+
+		let mut header_only = data.clone();
+		header_only[entries_off..][..2].copy_from_slice(&4u16.to_le_bytes()); // no text
+		header_only.drain(entries_off + 4..entries_off + 12);
+		let table = ResMessageTable::parse(&header_only).unwrap();
+		assert_eq!(table.get(1), Some(""));
+		assert_eq!(table.get(2), Some("two"));
+	}
+}
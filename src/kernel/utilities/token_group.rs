@@ -0,0 +1,27 @@
+use crate::co;
+use crate::prelude::*;
+
+/// A group of an access token, returned by
+/// [`HACCESSTOKEN::groups`](crate::prelude::kernel_Haccesstoken::groups).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TokenGroup {
+	/// The SID of the group, in string format, like `S-1-5-32-544`.
+	pub sid: String,
+	/// Account name, domain name and SID type, as returned by
+	/// [`LookupAccountSid`](crate::LookupAccountSid). `None` if the SID
+	/// couldn't be resolved, like logon SIDs.
+	pub account: Option<(String, String, co::SID_NAME_USE)>,
+	/// Attributes of the group within the token.
+	pub attributes: co::SE_GROUP,
+}
+
+impl TokenGroup {
+	/// Returns true if the group is enabled for access checks – that is, it's
+	/// neither disabled nor
+	/// [`co::SE_GROUP::USE_FOR_DENY_ONLY`](crate::co::SE_GROUP::USE_FOR_DENY_ONLY).
+	#[must_use]
+	pub fn is_enabled(&self) -> bool {
+		self.attributes.has(co::SE_GROUP::ENABLED)
+			&& !self.attributes.has(co::SE_GROUP::USE_FOR_DENY_ONLY)
+	}
+}
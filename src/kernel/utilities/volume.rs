@@ -0,0 +1,164 @@
+use crate::co;
+use crate::decl::*;
+use crate::prelude::*;
+
+/// High-level information about a volume, which combines the results of
+/// [`GetVolumePathNamesForVolumeName`](crate::GetVolumePathNamesForVolumeName),
+/// [`GetVolumeInformation`](crate::GetVolumeInformation),
+/// [`GetDiskFreeSpaceEx`](crate::GetDiskFreeSpaceEx) and
+/// [`GetDriveType`](crate::GetDriveType).
+///
+/// Unlike [`GetLogicalDriveStrings`](crate::GetLogicalDriveStrings), volumes
+/// without a drive letter – like VHDs mounted into folders – are also listed.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// for vol in w::Volume::list()? {
+///     println!("{} [{}] {}", vol.guid_path, vol.label, vol.file_system);
+///     for path in vol.path_names.iter() {
+///         println!("  mounted at {}", path);
+///     }
+///     if let Some((total, free)) = vol.capacity {
+///         println!("  {} of {} bytes free", free, total);
+///     }
+/// }
+/// # Ok::<_, winsafe::co::ERROR>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct Volume {
+	/// Volume GUID path, in the form `\\?\Volume{GUID}\`.
+	pub guid_path: String,
+	/// Drive letters and folders where the volume is mounted, with trailing
+	/// backslashes. Empty if the volume is not mounted anywhere.
+	pub path_names: Vec<String>,
+	/// Volume label.
+	pub label: String,
+	/// File system name, like `NTFS` or `FAT32`.
+	pub file_system: String,
+	/// File system flags.
+	pub file_system_flags: co::FILE_VOL,
+	/// Volume serial number.
+	pub serial_number: u32,
+	/// Drive type.
+	pub drive_type: co::DRIVE,
+	/// Total and free bytes. `None` if the volume is not ready, like a card
+	/// reader without a card, or if it cannot be queried.
+	pub capacity: Option<(u64, u64)>,
+}
+
+impl Volume {
+	/// Retrieves the information of the volume with the given GUID path, in
+	/// the form `\\?\Volume{GUID}\`.
+	///
+	/// If the volume is not ready, has a file system which is not recognized
+	/// (a RAW volume) or cannot be accessed by the current user, the label,
+	/// file system and capacity will be empty.
+	#[must_use]
+	pub fn from_guid_path(guid_path: &str) -> SysResult<Self> {
+		let mut new_self = Self {
+			guid_path: guid_path.to_owned(),
+			path_names: GetVolumePathNamesForVolumeName(guid_path)?,
+			label: String::default(),
+			file_system: String::default(),
+			file_system_flags: co::FILE_VOL::default(),
+			serial_number: 0,
+			drive_type: GetDriveType(Some(guid_path)),
+			capacity: None,
+		};
+
+		match GetVolumeInformation(
+			Some(guid_path),
+			Some(&mut new_self.label),
+			Some(&mut new_self.serial_number),
+			None,
+			Some(&mut new_self.file_system_flags),
+			Some(&mut new_self.file_system),
+		) {
+			Ok(_) => {},
+			Err(co::ERROR::NOT_READY) // no media
+				| Err(co::ERROR::UNRECOGNIZED_VOLUME) // RAW
+				| Err(co::ERROR::ACCESS_DENIED) => return Ok(new_self),
+			Err(e) => return Err(e),
+		}
+
+		let (mut total, mut free) = (u64::default(), u64::default());
+		match GetDiskFreeSpaceEx(Some(guid_path), None, Some(&mut total), Some(&mut free)) {
+			Ok(_) => new_self.capacity = Some((total, free)),
+			Err(co::ERROR::NOT_READY)
+				| Err(co::ERROR::UNRECOGNIZED_VOLUME)
+				| Err(co::ERROR::ACCESS_DENIED) => {},
+			Err(e) => return Err(e),
+		}
+		Ok(new_self)
+	}
+
+	/// Retrieves the volume which contains the given path, which can be a
+	/// drive root, a mounted folder or any file path.
+	#[must_use]
+	pub fn from_path(path: &str) -> SysResult<Self> {
+		let mount_point = GetVolumePathName(path)?;
+		Self::from_guid_path(&GetVolumeNameForVolumeMountPoint(&mount_point)?)
+	}
+
+	/// Lists all the volumes in the system, by calling
+	/// [`HFINDVOLUME::iter_volumes`](crate::prelude::kernel_Hfindvolume::iter_volumes).
+	///
+	/// Only an error in the enumeration itself is returned; a volume whose
+	/// information cannot be retrieved by
+	/// [`from_guid_path`](crate::Volume::from_guid_path) is skipped, so it
+	/// won't hide the other volumes.
+	#[must_use]
+	pub fn list() -> SysResult<Vec<Self>> {
+		let mut vols = Vec::<Self>::new();
+		for guid_path in HFINDVOLUME::iter_volumes() {
+			if let Ok(vol) = Self::from_guid_path(&guid_path?) {
+				vols.push(vol);
+			}
+		}
+		Ok(vols)
+	}
+
+	/// Mounts this volume at the given drive root or empty folder, by calling
+	/// [`SetVolumeMountPoint`](crate::SetVolumeMountPoint). The
+	/// [`path_names`](crate::Volume::path_names) field is updated.
+	///
+	/// A trailing backslash is appended to the path, if not present.
+	pub fn mount_at(&mut self, path: &str) -> SysResult<()> {
+		let path = format!("{}\\", path::rtrim_backslash(path));
+		SetVolumeMountPoint(&path, &self.guid_path)?;
+		self.path_names = GetVolumePathNamesForVolumeName(&self.guid_path)?;
+		Ok(())
+	}
+
+	/// Lists the folder mount points hosted on this volume – that is, the
+	/// folders of this volume where other volumes are mounted –, as absolute
+	/// paths, by calling
+	/// [`HFINDVOLUMEMOUNTPOINT::iter_mount_points`](crate::prelude::kernel_Hfindvolumemountpoint::iter_mount_points).
+	///
+	/// If the volume is not mounted anywhere, the paths are built upon its
+	/// GUID path. Enumerating mount points usually requires administrative
+	/// rights.
+	#[must_use]
+	pub fn mount_points(&self) -> SysResult<Vec<String>> {
+		let root = self.path_names.first()
+			.unwrap_or(&self.guid_path);
+		HFINDVOLUMEMOUNTPOINT::iter_mount_points(&self.guid_path)
+			.map(|mount_point| Ok(format!("{}{}", root, mount_point?)))
+			.collect()
+	}
+
+	/// Removes the volume from the given drive root or folder, by calling
+	/// [`DeleteVolumeMountPoint`](crate::DeleteVolumeMountPoint). The
+	/// [`path_names`](crate::Volume::path_names) field is updated.
+	///
+	/// A trailing backslash is appended to the path, if not present.
+	pub fn unmount_from(&mut self, path: &str) -> SysResult<()> {
+		let path = format!("{}\\", path::rtrim_backslash(path));
+		DeleteVolumeMountPoint(&path)?;
+		self.path_names = GetVolumePathNamesForVolumeName(&self.guid_path)?;
+		Ok(())
+	}
+}
@@ -9,6 +9,7 @@
 // Declarations of modules themselves.
 
 #[cfg(feature = "comctl")] mod comctl;
+#[cfg(feature = "crypt")] mod crypt;
 #[cfg(feature = "dshow")] mod dshow;
 #[cfg(feature = "dwm")] mod dwm;
 #[cfg(feature = "dxgi")] mod dxgi;
@@ -34,6 +35,7 @@
 
 mod decl {
 	#[cfg(feature = "comctl")] pub use super::comctl::decl::*;
+	#[cfg(feature = "crypt")] pub use super::crypt::decl::*;
 	#[cfg(feature = "dshow")] pub use super::dshow::decl::*;
 	#[cfg(feature = "dwm")] pub use super::dwm::decl::*;
 	#[cfg(feature = "dxgi")] pub use super::dxgi::decl::*;
@@ -65,6 +67,7 @@ pub mod co {
 	//! [`HRESULT`](crate::co::HRESULT).
 
 	#[cfg(feature = "comctl")] pub use super::comctl::co::*;
+	#[cfg(feature = "crypt")] pub use super::crypt::co::*;
 	#[cfg(feature = "dshow")] pub use super::dshow::co::*;
 	#[cfg(feature = "dwm")] pub use super::dwm::co::*;
 	#[cfg(feature = "dxgi")] pub use super::dxgi::co::*;
@@ -88,6 +91,8 @@ pub mod guard {
 	//! The guards are named after the functions they call.
 
 	#[cfg(feature = "comctl")] pub use super::comctl::guard::*;
+	#[cfg(feature = "crypt")] pub use super::crypt::guard::*;
+	#[cfg(feature = "dwm")] pub use super::dwm::guard::*;
 	#[cfg(feature = "gdi")] pub use super::gdi::guard::*;
 	#[cfg(feature = "kernel")] pub use super::kernel::guard::*;
 	#[cfg(feature = "ole")] pub use super::ole::guard::*;
@@ -314,6 +319,7 @@ pub mod prelude {
 	//! ```
 
 	#[cfg(feature = "comctl")] pub use super::comctl::traits::*;
+	#[cfg(feature = "crypt")] pub use super::crypt::traits::*;
 	#[cfg(feature = "dshow")] pub use super::dshow::traits::*;
 	#[cfg(feature = "dwm")] pub use super::dwm::traits::*;
 	#[cfg(feature = "dxgi")] pub use super::dxgi::traits::*;
@@ -8,5 +8,6 @@
 #[macro_use] mod gui_events;
 #[macro_use] mod handles;
 #[macro_use] mod messages;
+#[macro_use] mod proc_table;
 #[macro_use] mod seq_ids;
 #[macro_use] mod structs;
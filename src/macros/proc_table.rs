@@ -0,0 +1,88 @@
+/// Declares a struct whose fields are typed function pointers, resolved with
+/// [`HINSTANCE::GetProcAddress`](crate::prelude::kernel_Hinstance::GetProcAddress)
+/// from a loaded module. Requires the `kernel` feature.
+///
+/// The struct receives a lifetime parameter, and its `load` static method
+/// borrows the module, so the table can't outlive it. If any of the exports is
+/// missing, `load` fails with
+/// [`co::ERROR::PROC_NOT_FOUND`](crate::co::ERROR::PROC_NOT_FOUND).
+///
+/// The signatures are not checked against the module, so declaring them
+/// correctly is up to you – which is why the functions should be declared as
+/// `unsafe`.
+///
+/// # Examples
+///
+/// Calling `SetThreadDescription`, which doesn't exist before Windows 10
+/// version 1607, without a link-time dependency:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// w::proc_table! {
+///     /// Functions available on Windows 10 version 1607 and later.
+///     pub struct Win10Procs;
+///
+///     SetThreadDescription: unsafe extern "system" fn(*mut std::ffi::c_void, *const u16) -> i32;
+/// }
+///
+/// let hkernel = w::HINSTANCE::LoadLibrary("kernel32.dll")?;
+///
+/// match Win10Procs::load(&hkernel) {
+///     Ok(procs) => {
+///         let name = w::WString::from_str("worker");
+///         unsafe {
+///             (procs.SetThreadDescription)(
+///                 w::HTHREAD::GetCurrentThread().ptr(),
+///                 name.as_ptr(),
+///             );
+///         }
+///     },
+///     Err(co::ERROR::PROC_NOT_FOUND) => println!("Not available."),
+///     Err(e) => return Err(e),
+/// }
+///
+/// // FreeLibrary() called automatically
+/// # Ok::<_, co::ERROR>(())
+/// ```
+#[macro_export]
+macro_rules! proc_table {
+	(
+		$( #[$comment:meta] )*
+		$vis:vis struct $name:ident;
+
+		$(
+			$( #[$fcomment:meta] )*
+			$func:ident : $fty:ty;
+		)*
+	) => {
+		$( #[$comment] )*
+		#[allow(non_snake_case)]
+		$vis struct $name<'a> {
+			_module: std::marker::PhantomData<&'a $crate::HINSTANCE>,
+			$(
+				$( #[$fcomment] )*
+				pub $func: $fty,
+			)*
+		}
+
+		impl<'a> $name<'a> {
+			/// Resolves all the functions from the given module.
+			$vis fn load(hinstance: &'a $crate::HINSTANCE) -> $crate::SysResult<Self> {
+				Ok(Self {
+					_module: std::marker::PhantomData,
+					$(
+						$func: unsafe {
+							std::mem::transmute::<*const std::ffi::c_void, $fty>(
+								$crate::prelude::kernel_Hinstance::GetProcAddress(
+									hinstance,
+									stringify!($func),
+								)?,
+							)
+						},
+					)*
+				})
+			}
+		}
+	};
+}
@@ -1,4 +1,4 @@
-#![allow(non_camel_case_types)]
+#![allow(non_camel_case_types, non_upper_case_globals)]
 
 const_ordinary! { CLSCTX: u32;
 	/// [`CLSCTX`](https://learn.microsoft.com/en-us/windows/win32/api/wtypesbase/ne-wtypesbase-clsctx)
@@ -83,6 +83,24 @@ const_bitflag! { COINIT: u32;
 	SPEED_OVER_MEMORY 0x8
 }
 
+const_bitflag! { CREDUIWIN: u32;
+	/// [`CredUIPromptForWindowsCredentials`](crate::CredUIPromptForWindowsCredentials)
+	/// `flags` (`u32`).
+	=>
+	=>
+	/// None of the actual values (zero).
+	NoValue 0
+	GENERIC 0x0000_0001
+	CHECKBOX 0x0000_0002
+	AUTHPACKAGE_ONLY 0x0000_0010
+	IN_CRED_ONLY 0x0000_0020
+	ENUMERATE_ADMINS 0x0000_0100
+	ENUMERATE_CURRENT_USER 0x0000_0200
+	SECURE_PROMPT 0x0000_1000
+	PREPROMPTING 0x0000_2000
+	PACK_32_WOW 0x1000_0000
+}
+
 const_ordinary! { DROPEFFECT: u32;
 	/// [`DROPEFFECT`](https://learn.microsoft.com/en-us/windows/win32/com/dropeffect-constants)
 	/// constants (`u32`).
@@ -22,3 +22,9 @@ extern_sys! { "ole32";
 	RevokeDragDrop(HANDLE) -> HRES
 	StringFromCLSID(PCVOID, *mut PSTR) -> HRES
 }
+
+extern_sys! { "credui";
+	CredPackAuthenticationBufferW(u32, PCSTR, PCSTR, *mut u8, *mut u32) -> BOOL
+	CredUIPromptForWindowsCredentialsW(PCVOID, u32, *mut u32, PCVOID, u32, *mut PVOID, *mut u32, *mut BOOL, u32) -> u32
+	CredUnPackAuthenticationBufferW(u32, PCVOID, u32, PSTR, *mut u32, PSTR, *mut u32, PSTR, *mut u32) -> BOOL
+}
@@ -3,6 +3,7 @@
 use crate::co;
 use crate::decl::*;
 use crate::guard::*;
+use crate::kernel::{ffi_types::*, privs::*};
 use crate::ole::ffi;
 use crate::ole::privs::*;
 use crate::prelude::*;
@@ -328,6 +329,178 @@ pub fn CreatePointerMoniker(unk: &impl ole_IUnknown) -> HrResult<IMoniker> {
 	).map(|_| queried)
 }
 
+/// [`CredUIPromptForWindowsCredentials`](https://learn.microsoft.com/en-us/windows/win32/api/wincred/nf-wincred-creduipromptforwindowscredentialsw)
+/// function.
+///
+/// Displays the Windows sign-in dialog, optionally pre-filled with the user
+/// name. If `auth_error` is not
+/// [`co::ERROR::SUCCESS`](crate::co::ERROR::SUCCESS), its message is shown,
+/// which is useful when prompting again after a failed attempt. If `save` is
+/// passed along with
+/// [`co::CREDUIWIN::CHECKBOX`](crate::co::CREDUIWIN::CHECKBOX), it sets and
+/// receives the state of the "Remember me" checkbox.
+///
+/// Returns the user name, the domain name and the password, as unpacked by
+/// [`CredUnPackAuthenticationBuffer`](https://learn.microsoft.com/en-us/windows/win32/api/wincred/nf-wincred-credunpackauthenticationbufferw),
+/// or `None` if the user cancelled the dialog.
+///
+/// Unlike the other credential functions, which belong to the `kernel`
+/// feature, this one requires `ole`, because the returned buffer is allocated
+/// with [`CoTaskMemAlloc`](crate::CoTaskMemAlloc).
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let hwnd: w::HWND; // initialized somewhere
+/// # let hwnd = w::HWND::NULL;
+///
+/// let mut remember = true;
+/// if let Some((user, _, password)) = w::CredUIPromptForWindowsCredentials(
+///     Some(&hwnd),
+///     Some("My App"),
+///     Some("Sign in to the service"),
+///     co::ERROR::SUCCESS,
+///     None,
+///     Some(&mut remember),
+///     co::CREDUIWIN::GENERIC | co::CREDUIWIN::CHECKBOX,
+/// )? {
+///     println!("Signing in as {}", user);
+/// }
+/// # Ok::<_, co::ERROR>(())
+/// ```
+#[must_use]
+pub fn CredUIPromptForWindowsCredentials(
+	hwnd_parent: Option<&HWND>,
+	caption: Option<&str>,
+	message: Option<&str>,
+	auth_error: co::ERROR,
+	user_name: Option<&str>,
+	save: Option<&mut bool>,
+	flags: co::CREDUIWIN,
+) -> SysResult<Option<(String, String, String)>>
+{
+	let mut in_buf = Vec::<u8>::default();
+	if let Some(user_name) = user_name {
+		let wuser = WString::from_str(user_name);
+		let wpass = WString::from_str("");
+		let mut in_sz = u32::default();
+
+		unsafe {
+			ffi::CredPackAuthenticationBufferW( // retrieve needed buffer size
+				0,
+				wuser.as_ptr(),
+				wpass.as_ptr(),
+				std::ptr::null_mut(),
+				&mut in_sz,
+			);
+		}
+		match GetLastError() {
+			co::ERROR::INSUFFICIENT_BUFFER => {},
+			err => return Err(err),
+		}
+
+		in_buf.resize(in_sz as _, 0);
+		bool_to_sysresult(
+			unsafe {
+				ffi::CredPackAuthenticationBufferW(
+					0,
+					wuser.as_ptr(),
+					wpass.as_ptr(),
+					in_buf.as_mut_ptr(),
+					&mut in_sz,
+				)
+			},
+		)?;
+	}
+
+	let wcaption = WString::from_opt_str(caption);
+	let wmessage = WString::from_opt_str(message);
+	let info = CREDUI_INFO {
+		cbSize: std::mem::size_of::<CREDUI_INFO>() as _,
+		hwndParent: hwnd_parent.map_or(std::ptr::null_mut(), |h| h.ptr()),
+		pszMessageText: wmessage.as_ptr(),
+		pszCaptionText: wcaption.as_ptr(),
+		hbmBanner: std::ptr::null_mut(),
+	};
+
+	let mut auth_package = u32::default();
+	let mut pout = std::ptr::null_mut();
+	let mut out_sz = u32::default();
+	let mut fsave: BOOL = save.as_ref().map_or(0, |s| **s as _);
+
+	match unsafe {
+		co::ERROR::from_raw(
+			ffi::CredUIPromptForWindowsCredentialsW(
+				&info as *const _ as _,
+				auth_error.raw(),
+				&mut auth_package,
+				if in_buf.is_empty() { std::ptr::null() } else { in_buf.as_ptr() as _ },
+				in_buf.len() as _,
+				&mut pout,
+				&mut out_sz,
+				if save.is_some() { &mut fsave } else { std::ptr::null_mut() },
+				flags.raw(),
+			),
+		)
+	} {
+		co::ERROR::SUCCESS => {},
+		co::ERROR::CANCELLED => return Ok(None),
+		err => return Err(err),
+	}
+
+	let mut out_guard = unsafe { CoTaskMemFreeGuard::new(pout, out_sz as _) };
+	if let Some(save) = save {
+		*save = fsave != 0;
+	}
+
+	let (mut cch_user, mut cch_domain, mut cch_pass) = (0u32, 0u32, 0u32);
+	unsafe {
+		ffi::CredUnPackAuthenticationBufferW( // retrieve needed buffer sizes
+			0,
+			out_guard.as_ptr(),
+			out_sz,
+			std::ptr::null_mut(),
+			&mut cch_user,
+			std::ptr::null_mut(),
+			&mut cch_domain,
+			std::ptr::null_mut(),
+			&mut cch_pass,
+		);
+	}
+	match GetLastError() {
+		co::ERROR::INSUFFICIENT_BUFFER => {},
+		err => return Err(err),
+	}
+
+	let mut wuser = WString::new_alloc_buf(cch_user as usize + 1);
+	let mut wdomain = WString::new_alloc_buf(cch_domain as usize + 1);
+	let mut wpass = WString::new_alloc_buf(cch_pass as usize + 1);
+
+	let unpacked = bool_to_sysresult(
+		unsafe {
+			ffi::CredUnPackAuthenticationBufferW(
+				0,
+				out_guard.as_ptr(),
+				out_sz,
+				wuser.as_mut_ptr(),
+				&mut cch_user,
+				wdomain.as_mut_ptr(),
+				&mut cch_domain,
+				wpass.as_mut_ptr(),
+				&mut cch_pass,
+			)
+		},
+	);
+	out_guard.as_mut_slice().fill(0); // don't leave the password behind
+	unpacked?;
+
+	let password = wpass.to_string();
+	wpass.fill_with_zero();
+	Ok(Some((wuser.to_string(), wdomain.to_string(), password)))
+}
+
 /// [`StringFromCLSID`](https://learn.microsoft.com/en-us/windows/win32/api/combaseapi/nf-combaseapi-stringfromclsid)
 /// function.
 #[must_use]
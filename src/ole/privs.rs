@@ -26,3 +26,15 @@ pub(crate) const fn okfalse_to_hrresult(hr: HRES) -> HrResult<bool> {
 		hr => Err(hr),
 	}
 }
+
+/// [`CREDUI_INFO`](https://learn.microsoft.com/en-us/windows/win32/api/wincred/ns-wincred-credui_infow)
+/// struct, used only internally.
+#[repr(C)]
+#[allow(non_snake_case)]
+pub(crate) struct CREDUI_INFO {
+	pub(crate) cbSize: u32,
+	pub(crate) hwndParent: HANDLE,
+	pub(crate) pszMessageText: PCSTR,
+	pub(crate) pszCaptionText: PCSTR,
+	pub(crate) hbmBanner: HANDLE,
+}
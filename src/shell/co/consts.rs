@@ -191,6 +191,16 @@ const_bitflag! { KF: u32;
 	ALIAS_ONLY 0x8000_0000
 }
 
+const_ordinary! { MDT: u32;
+	/// [`MONITOR_DPI_TYPE`](https://learn.microsoft.com/en-us/windows/win32/api/shellscalingapi/ne-shellscalingapi-monitor_dpi_type)
+	/// enumeration (`u32`).
+	=>
+	=>
+	EFFECTIVE_DPI 0
+	ANGULAR_DPI 1
+	RAW_DPI 2
+}
+
 const_bitflag! { NIF: u32;
 	/// [`NOTIFYICONDATA`](crate::NOTIFYICONDATA) `uFlags` (`u32`).
 	=>
@@ -17,6 +17,10 @@ extern_sys! { "shell32";
 	SHGetStockIconInfo(u32, u32, PVOID) -> HRES
 }
 
+extern_sys! { "shcore";
+	GetDpiForMonitor(HANDLE, u32, *mut u32, *mut u32) -> HRES
+}
+
 extern_sys! { "shlwapi";
 	PathCombineW(PSTR, PCSTR, PCSTR) -> PSTR
 	PathCommonPrefixW(PCSTR, PCSTR, PSTR) -> i32
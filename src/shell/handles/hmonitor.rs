@@ -0,0 +1,46 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::decl::*;
+use crate::ole::privs::*;
+use crate::prelude::*;
+use crate::shell::ffi;
+
+impl shell_Hmonitor for HMONITOR {}
+
+/// This trait is enabled with the `shell` feature, and provides methods for
+/// [`HMONITOR`](crate::HMONITOR).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait shell_Hmonitor: user_Hmonitor {
+	/// [`GetDpiForMonitor`](https://learn.microsoft.com/en-us/windows/win32/api/shellscalingapi/nf-shellscalingapi-getdpiformonitor)
+	/// function.
+	///
+	/// Returns the horizontal and vertical DPI.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let hmon = w::HMONITOR::MonitorFromPoint(
+	///     w::POINT::new(0, 0), co::MONITOR::DEFAULTTOPRIMARY);
+	///
+	/// let (dpi_x, _) = hmon.GetDpiForMonitor(co::MDT::EFFECTIVE_DPI)?;
+	/// println!("Scale: {}%", dpi_x * 100 / 96);
+	/// # Ok::<_, co::HRESULT>(())
+	/// ```
+	#[must_use]
+	fn GetDpiForMonitor(&self, dpi_type: co::MDT) -> HrResult<(u32, u32)> {
+		let (mut dpi_x, mut dpi_y) = (u32::default(), u32::default());
+		ok_to_hrresult(
+			unsafe {
+				ffi::GetDpiForMonitor(self.ptr(), dpi_type.raw(), &mut dpi_x, &mut dpi_y)
+			},
+		).map(|_| (dpi_x, dpi_y))
+	}
+}
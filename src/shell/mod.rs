@@ -4,6 +4,7 @@ mod com_interfaces;
 mod funcs;
 mod handles;
 mod structs;
+mod utilities;
 
 pub(in crate::shell) mod ffi;
 pub(in crate::shell) mod iterators;
@@ -17,11 +18,13 @@ pub mod decl {
 	pub use super::funcs::*;
 	pub use super::handles::decl::*;
 	pub use super::structs::*;
+	pub use super::utilities::decl::*;
 }
 
 pub mod traits {
 	pub use super::com_interfaces::traits::*;
 	pub use super::handles::traits::*;
+	pub use super::utilities::traits::*;
 }
 
 pub mod vt {
@@ -0,0 +1,127 @@
+#![allow(non_camel_case_types)]
+
+use crate::co;
+use crate::decl::*;
+use crate::prelude::*;
+use crate::shell::ffi;
+
+impl shell_Clipboard for Clipboard<'_> {
+	fn read_files(&self) -> SysResult<Option<Vec<String>>> {
+		if !self.has_format(co::CF::HDROP) {
+			return Ok(None);
+		}
+
+		// The HDROP belongs to the clipboard, so DragFinish must not be called.
+		let hdrop = unsafe { HDROP::from_ptr(GetClipboardData(co::CF::HDROP)? as _) };
+		let count = unsafe {
+			ffi::DragQueryFileW(hdrop.ptr(), 0xffff_ffff, std::ptr::null_mut(), 0)
+		};
+
+		(0..count)
+			.map(|idx| {
+				let len = unsafe {
+					ffi::DragQueryFileW(hdrop.ptr(), idx, std::ptr::null_mut(), 0)
+				};
+				let mut buf = WString::new_alloc_buf(len as usize + 1);
+				match unsafe {
+					ffi::DragQueryFileW(
+						hdrop.ptr(),
+						idx,
+						buf.as_mut_ptr(),
+						buf.buf_len() as _,
+					)
+				} {
+					0 => Err(GetLastError()),
+					_ => Ok(buf.to_string()),
+				}
+			})
+			.collect::<SysResult<Vec<_>>>()
+			.map(Some)
+	}
+
+	fn write_files(&self, paths: &[impl AsRef<str>]) -> SysResult<()> {
+		self.write_bytes(co::CF::HDROP, &hdrop_data(paths))
+	}
+}
+
+/// This trait is enabled with the `shell` feature, and provides methods for
+/// [`Clipboard`](crate::Clipboard) to handle the
+/// [`co::CF::HDROP`](crate::co::CF::HDROP) format, which holds a list of
+/// files copied in Windows Explorer.
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait shell_Clipboard {
+	/// Returns the paths of the [`co::CF::HDROP`](crate::co::CF::HDROP)
+	/// format, or `None` if the format is not available.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let hwnd: w::HWND; // initialized somewhere
+	/// # let hwnd = w::HWND::NULL;
+	///
+	/// let clip = w::Clipboard::open(&hwnd)?;
+	/// for path in clip.read_files()?.unwrap_or_default() {
+	///     println!("{}", path);
+	/// }
+	/// # Ok::<_, winsafe::co::ERROR>(())
+	/// ```
+	#[must_use]
+	fn read_files(&self) -> SysResult<Option<Vec<String>>>;
+
+	/// Writes the [`co::CF::HDROP`](crate::co::CF::HDROP) format, which can
+	/// be pasted in Windows Explorer. The paths should be absolute.
+	fn write_files(&self, paths: &[impl AsRef<str>]) -> SysResult<()>;
+}
+
+/// Serializes a `DROPFILES` struct followed by the double-null-terminated
+/// list of paths.
+fn hdrop_data(paths: &[impl AsRef<str>]) -> Vec<u8> {
+	const DROPFILES_SZ: u32 = 20;
+
+	let mut data = Vec::<u8>::default();
+	data.extend_from_slice(&DROPFILES_SZ.to_le_bytes()); // pFiles
+	data.extend_from_slice(&[0; 8]); // pt
+	data.extend_from_slice(&0u32.to_le_bytes()); // fNC
+	data.extend_from_slice(&1u32.to_le_bytes()); // fWide
+
+	for path in paths.iter() {
+		data.extend(path.as_ref().encode_utf16()
+			.chain(std::iter::once(0))
+			.flat_map(|ch| ch.to_le_bytes()));
+	}
+	if paths.is_empty() {
+		data.extend_from_slice(&[0, 0]); // an empty list still needs two nulls
+	}
+	data.extend_from_slice(&[0, 0]); // list terminator
+	data
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn hdrop_list() {
+		let data = hdrop_data(&["C:\\a.txt", "D:\\ç"]);
+		assert_eq!(&data[..4], &20u32.to_le_bytes());
+		assert_eq!(&data[16..20], &1u32.to_le_bytes()); // fWide
+		let wchars = data[20..].chunks_exact(2)
+			.map(|b| u16::from_le_bytes([b[0], b[1]]))
+			.collect::<Vec<_>>();
+		assert_eq!(String::from_utf16(&wchars).unwrap(), "C:\\a.txt\0D:\\ç\0\0");
+	}
+
+	#[test]
+	fn hdrop_empty_list() {
+		let data = hdrop_data(&[] as &[&str]);
+		assert_eq!(data.len(), 20 + 4);
+		assert_eq!(&data[20..], &[0, 0, 0, 0]); // double null
+	}
+}
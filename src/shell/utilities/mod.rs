@@ -0,0 +1,10 @@
+mod clipboard;
+mod monitor;
+
+pub mod decl {
+	pub use super::monitor::{Monitor, monitors};
+}
+
+pub mod traits {
+	pub use super::clipboard::shell_Clipboard;
+}
@@ -0,0 +1,226 @@
+use std::cell::RefCell;
+
+use crate::co;
+use crate::decl::*;
+use crate::prelude::*;
+
+/// Returns the descriptors of all display monitors, enumerated with
+/// [`HDC::EnumDisplayMonitors`](crate::prelude::user_Hdc::EnumDisplayMonitors).
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// for mon in w::monitors()?.iter() {
+///     println!("{} {}x{} at {}%{}",
+///         mon.device_name,
+///         mon.rc_monitor.right - mon.rc_monitor.left,
+///         mon.rc_monitor.bottom - mon.rc_monitor.top,
+///         mon.dpi * 100 / 96,
+///         if mon.is_primary { ", primary" } else { "" },
+///     );
+/// }
+/// # Ok::<_, winsafe::co::HRESULT>(())
+/// ```
+#[must_use]
+pub fn monitors() -> HrResult<Vec<Monitor>> {
+	let hmons = RefCell::new(Vec::<HMONITOR>::default());
+	HDC::NULL.EnumDisplayMonitors(None, |hmon, _, _| {
+		hmons.borrow_mut().push(hmon);
+		true
+	}).map_err(|err| err.to_hresult())?;
+
+	hmons.into_inner()
+		.iter()
+		.map(Monitor::from_hmonitor)
+		.collect()
+}
+
+/// Descriptor of a display monitor, returned by [`monitors`](crate::monitors).
+///
+/// Coordinates are in physical pixels of the virtual screen, as seen by a
+/// per-monitor DPI aware process.
+pub struct Monitor {
+	/// The monitor handle.
+	pub hmonitor: HMONITOR,
+	/// Device name, like `\\.\DISPLAY1`.
+	pub device_name: String,
+	/// The whole monitor area.
+	pub rc_monitor: RECT,
+	/// The monitor area not covered by the taskbar and docked toolbars.
+	pub rc_work: RECT,
+	/// Whether this is the primary monitor.
+	pub is_primary: bool,
+	/// Effective DPI, which includes the scale factor set by the user.
+	pub dpi: u32,
+	/// Raw horizontal and vertical DPI, as the physical pixel density of the
+	/// screen. May be zero if the screen doesn't report its size.
+	pub raw_dpi: (u32, u32),
+	/// Display orientation.
+	pub orientation: co::DMDO,
+}
+
+impl Monitor {
+	/// Retrieves the descriptor of the given monitor.
+	#[must_use]
+	pub fn from_hmonitor(hmon: &HMONITOR) -> HrResult<Self> {
+		let mut mi = MONITORINFOEX::default();
+		hmon.GetMonitorInfo(&mut mi).map_err(|err| err.to_hresult())?;
+		let device_name = mi.szDevice();
+
+		let (dpi, _) = hmon.GetDpiForMonitor(co::MDT::EFFECTIVE_DPI)?;
+		let raw_dpi = hmon.GetDpiForMonitor(co::MDT::RAW_DPI)
+			.unwrap_or((0, 0)); // fails if the screen doesn't report its size
+
+		let mut dm = DEVMODE::default();
+		let orientation = match EnumDisplaySettings(
+			Some(&device_name),
+			GmidxEnum::Enum(co::ENUM_SETTINGS::CURRENT),
+			&mut dm,
+		) {
+			Ok(true) => dm.dmDisplayOrientation(),
+			_ => co::DMDO::DEFAULT,
+		};
+
+		Ok(Self {
+			hmonitor: unsafe { hmon.raw_copy() },
+			device_name,
+			rc_monitor: mi.rcMonitor,
+			rc_work: mi.rcWork,
+			is_primary: mi.dwFlags == co::MONITORINFOF::PRIMARY,
+			dpi,
+			raw_dpi,
+			orientation,
+		})
+	}
+
+	/// Retrieves the descriptor of the monitor which has the largest area of
+	/// intersection with the given rectangle, or the nearest one if there's
+	/// no intersection.
+	#[must_use]
+	pub fn nearest(rc: RECT) -> HrResult<Self> {
+		Self::from_hmonitor(
+			&HMONITOR::MonitorFromRect(rc, co::MONITOR::DEFAULTTONEAREST),
+		)
+	}
+
+	/// Retrieves the descriptor of the monitor where most of the window is,
+	/// or the nearest one.
+	#[must_use]
+	pub fn of_window(hwnd: &HWND) -> HrResult<Self> {
+		Self::from_hmonitor(&hwnd.MonitorFromWindow(co::MONITOR::DEFAULTTONEAREST))
+	}
+
+	/// Centers the rectangle on the work area, then
+	/// [clamps](crate::Monitor::clamp) it.
+	///
+	/// # Examples
+	///
+	/// Centering a window on the monitor where it currently is:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let hwnd: w::HWND; // initialized somewhere
+	/// # let hwnd = w::HWND::NULL;
+	///
+	/// let rc = hwnd.GetWindowRect()?;
+	/// let rc = w::Monitor::nearest(rc)?.center(rc);
+	///
+	/// hwnd.SetWindowPos(
+	///     w::HwndPlace::None,
+	///     w::POINT::new(rc.left, rc.top),
+	///     w::SIZE::default(),
+	///     co::SWP::NOSIZE | co::SWP::NOZORDER,
+	/// )?;
+	/// # Ok::<_, Box<dyn std::error::Error>>(())
+	/// ```
+	#[must_use]
+	pub const fn center(&self, rc: RECT) -> RECT {
+		let (cx, cy) = (rc.right - rc.left, rc.bottom - rc.top);
+		let left = self.rc_work.left + (self.rc_work.right - self.rc_work.left - cx) / 2;
+		let top = self.rc_work.top + (self.rc_work.bottom - self.rc_work.top - cy) / 2;
+		self.clamp(RECT { left, top, right: left + cx, bottom: top + cy })
+	}
+
+	/// Moves the rectangle the least possible to fit it into the work area. If
+	/// the rectangle is larger than the work area, it's shrunk.
+	#[must_use]
+	pub const fn clamp(&self, rc: RECT) -> RECT {
+		const fn clamp_axis(lo: i32, hi: i32, area_lo: i32, area_hi: i32) -> (i32, i32) {
+			let len = hi - lo;
+			if len >= area_hi - area_lo {
+				(area_lo, area_hi) // too large, shrink
+			} else if lo < area_lo {
+				(area_lo, area_lo + len)
+			} else if hi > area_hi {
+				(area_hi - len, area_hi)
+			} else {
+				(lo, hi)
+			}
+		}
+
+		let (left, right) = clamp_axis(rc.left, rc.right, self.rc_work.left, self.rc_work.right);
+		let (top, bottom) = clamp_axis(rc.top, rc.bottom, self.rc_work.top, self.rc_work.bottom);
+		RECT { left, top, right, bottom }
+	}
+
+	/// Returns the scale factor set by the user, like `1.5` for 150%.
+	#[must_use]
+	pub fn scale(&self) -> f32 {
+		self.dpi as f32 / 96.0
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn rect(left: i32, top: i32, right: i32, bottom: i32) -> RECT {
+		RECT { left, top, right, bottom }
+	}
+
+	fn monitor(rc_work: RECT) -> Monitor {
+		Monitor {
+			hmonitor: HMONITOR::NULL,
+			device_name: String::default(),
+			rc_monitor: rc_work,
+			rc_work,
+			is_primary: false,
+			dpi: 96,
+			raw_dpi: (0, 0),
+			orientation: co::DMDO::DEFAULT,
+		}
+	}
+
+	#[test]
+	fn already_inside() {
+		let mon = monitor(rect(0, 0, 1920, 1040));
+		let rc = rect(100, 200, 900, 800);
+		assert_eq!(mon.clamp(rc), rc);
+		assert_eq!(mon.center(rc), rect(560, 220, 1360, 820));
+	}
+
+	#[test]
+	fn partially_outside() {
+		let mon = monitor(rect(0, 0, 1920, 1040));
+		assert_eq!(mon.clamp(rect(-50, 900, 750, 1200)), rect(0, 740, 800, 1040));
+		assert_eq!(mon.clamp(rect(1500, -30, 2100, 270)), rect(1320, 0, 1920, 300));
+	}
+
+	#[test]
+	fn larger_than_work_area() {
+		let mon = monitor(rect(0, 0, 1920, 1040));
+		assert_eq!(mon.clamp(rect(-100, 50, 2500, 600)), rect(0, 50, 1920, 600));
+		assert_eq!(mon.center(rect(0, 0, 3000, 2000)), rect(0, 0, 1920, 1040));
+	}
+
+	#[test]
+	fn negative_origin_secondary() {
+		let mon = monitor(rect(-1280, -200, 0, 824)); // left of the primary
+		assert_eq!(mon.center(rect(0, 0, 640, 480)), rect(-960, 72, -320, 552));
+		assert_eq!(mon.clamp(rect(-100, 700, 300, 900)), rect(-400, 624, 0, 824));
+		assert_eq!(mon.clamp(rect(-2000, -500, 0, 1000)), rect(-1280, -200, 0, 824));
+	}
+}
@@ -79,6 +79,49 @@ const_ordinary! { APPCOMMAND: u16;
 	DWM_FLIP3D 54
 }
 
+const_bitflag! { BDR: u32;
+	/// [`HDC::DrawEdge`](crate::prelude::user_Hdc::DrawEdge) `edge` (`u32`).
+	=>
+	=>
+	RAISEDOUTER 0x0001
+	SUNKENOUTER 0x0002
+	RAISEDINNER 0x0004
+	SUNKENINNER 0x0008
+	OUTER Self::RAISEDOUTER.0 | Self::SUNKENOUTER.0
+	INNER Self::RAISEDINNER.0 | Self::SUNKENINNER.0
+	RAISED Self::RAISEDOUTER.0 | Self::RAISEDINNER.0
+	SUNKEN Self::SUNKENOUTER.0 | Self::SUNKENINNER.0
+	EDGE_RAISED Self::RAISEDOUTER.0 | Self::RAISEDINNER.0
+	EDGE_SUNKEN Self::SUNKENOUTER.0 | Self::SUNKENINNER.0
+	EDGE_ETCHED Self::SUNKENOUTER.0 | Self::RAISEDINNER.0
+	EDGE_BUMP Self::RAISEDOUTER.0 | Self::SUNKENINNER.0
+}
+
+const_bitflag! { BF: u32;
+	/// [`HDC::DrawEdge`](crate::prelude::user_Hdc::DrawEdge) `flags` (`u32`).
+	=>
+	=>
+	LEFT 0x0001
+	TOP 0x0002
+	RIGHT 0x0004
+	BOTTOM 0x0008
+	TOPLEFT Self::TOP.0 | Self::LEFT.0
+	TOPRIGHT Self::TOP.0 | Self::RIGHT.0
+	BOTTOMLEFT Self::BOTTOM.0 | Self::LEFT.0
+	BOTTOMRIGHT Self::BOTTOM.0 | Self::RIGHT.0
+	RECT Self::LEFT.0 | Self::TOP.0 | Self::RIGHT.0 | Self::BOTTOM.0
+	DIAGONAL 0x0010
+	DIAGONAL_ENDTOPRIGHT Self::DIAGONAL.0 | Self::TOP.0 | Self::RIGHT.0
+	DIAGONAL_ENDTOPLEFT Self::DIAGONAL.0 | Self::TOP.0 | Self::LEFT.0
+	DIAGONAL_ENDBOTTOMLEFT Self::DIAGONAL.0 | Self::BOTTOM.0 | Self::LEFT.0
+	DIAGONAL_ENDBOTTOMRIGHT Self::DIAGONAL.0 | Self::BOTTOM.0 | Self::RIGHT.0
+	MIDDLE 0x0800
+	SOFT 0x1000
+	ADJUST 0x2000
+	FLAT 0x4000
+	MONO 0x8000
+}
+
 const_wm! { BM;
 	/// Button control
 	/// [messages](https://learn.microsoft.com/en-us/windows/win32/controls/bumper-button-control-reference-messages)
@@ -514,6 +557,18 @@ const_bitflag! { DESKTOP_RIGHTS: u32;
 	GENERIC_ALL Self::CREATEMENU.0 | Self::CREATEWINDOW.0 | Self::ENUMERATE.0 | Self::HOOKCONTROL.0 | Self::JOURNALPLAYBACK.0 | Self::JOURNALRECORD.0 | Self::READOBJECTS.0 | Self::SWITCHDESKTOP.0 | Self::WRITEOBJECTS.0 | STANDARD_RIGHTS::REQUIRED.raw()
 }
 
+const_ordinary! { DPI_AWARENESS_CONTEXT: isize;
+	/// [`DPI_AWARENESS_CONTEXT`](https://learn.microsoft.com/en-us/windows/win32/hidpi/dpi-awareness-context)
+	/// pseudo handles (`isize`).
+	=>
+	=>
+	UNAWARE -1
+	SYSTEM_AWARE -2
+	PER_MONITOR_AWARE -3
+	PER_MONITOR_AWARE_V2 -4
+	UNAWARE_GDISCALED -5
+}
+
 const_ordinary! { DF: u32;
 	/// [`HDESK::OpenDesktop`](crate::prelude::user_Hdesk::OpenDesktop) `flags`
 	/// (`u32`).
@@ -522,6 +577,55 @@ const_ordinary! { DF: u32;
 	ALLOWOTHERACCOUNTHOOK 0x0001
 }
 
+const_ordinary! { DFC: u32;
+	/// [`HDC::DrawFrameControl`](crate::prelude::user_Hdc::DrawFrameControl)
+	/// `kind` (`u32`).
+	=>
+	=>
+	CAPTION 1
+	MENU 2
+	SCROLL 3
+	BUTTON 4
+	POPUPMENU 5
+}
+
+const_bitflag! { DFCS: u32;
+	/// [`HDC::DrawFrameControl`](crate::prelude::user_Hdc::DrawFrameControl)
+	/// `state` (`u32`).
+	=>
+	=>
+	CAPTIONCLOSE 0x0000
+	CAPTIONMIN 0x0001
+	CAPTIONMAX 0x0002
+	CAPTIONRESTORE 0x0003
+	CAPTIONHELP 0x0004
+	MENUARROW 0x0000
+	MENUCHECK 0x0001
+	MENUBULLET 0x0002
+	MENUARROWRIGHT 0x0004
+	SCROLLUP 0x0000
+	SCROLLDOWN 0x0001
+	SCROLLLEFT 0x0002
+	SCROLLRIGHT 0x0003
+	SCROLLCOMBOBOX 0x0005
+	SCROLLSIZEGRIP 0x0008
+	SCROLLSIZEGRIPRIGHT 0x0010
+	BUTTONCHECK 0x0000
+	BUTTONRADIOIMAGE 0x0001
+	BUTTONRADIOMASK 0x0002
+	BUTTONRADIO 0x0004
+	BUTTON3STATE 0x0008
+	BUTTONPUSH 0x0010
+	INACTIVE 0x0100
+	PUSHED 0x0200
+	CHECKED 0x0400
+	TRANSPARENT 0x0800
+	HOT 0x1000
+	ADJUSTRECT 0x2000
+	FLAT 0x4000
+	MONO 0x8000
+}
+
 const_ordinary! { DISP_CHANGE: i32;
 	/// [`ChangeDisplaySettings`](crate::ChangeDisplaySettings) return value
 	/// (`u32`).
@@ -1439,6 +1543,23 @@ const_ordinary! { GWLP: i32;
 	DWLP_USER Self::DWLP_DLGPROC.0 + std::mem::size_of::<isize>() as i32
 }
 
+const_ordinary! { HCBT: i32;
+	/// [`WindowsHook::cbt`](crate::WindowsHook::cbt) hook `code`
+	/// (`i32`).
+	=>
+	=>
+	MOVESIZE 0
+	MINMAX 1
+	QS 2
+	CREATEWND 3
+	DESTROYWND 4
+	ACTIVATE 5
+	CLICKSKIPPED 6
+	KEYSKIPPED 7
+	SYSCOMMAND 8
+	SETFOCUS 9
+}
+
 const_ordinary! { HELPINFO: i32;
 	/// [`HELPINFO`](crate::HELPINFO) `iContextType` (`i32`).
 	=>
@@ -1710,6 +1831,27 @@ const_ws! { LBS: u32;
 	STANDARD Self::NOTIFY.0 | Self::SORT.0 | WS::VSCROLL.0 | WS::BORDER.0
 }
 
+const_bitflag! { LLKHF: u32;
+	/// [`KBDLLHOOKSTRUCT`](crate::KBDLLHOOKSTRUCT) `flags` (`u32`).
+	=>
+	=>
+	NoValue 0
+	EXTENDED 0x0001
+	LOWER_IL_INJECTED 0x0002
+	INJECTED 0x0010
+	ALTDOWN 0x0020
+	UP 0x0080
+}
+
+const_bitflag! { LLMHF: u32;
+	/// [`MSLLHOOKSTRUCT`](crate::MSLLHOOKSTRUCT) `flags` (`u32`).
+	=>
+	=>
+	NoValue 0
+	INJECTED 0x0001
+	LOWER_IL_INJECTED 0x0002
+}
+
 const_ordinary! { LSFW: u32;
 	/// [`LockSetForegroundWindow`](crate::LockSetForegroundWindow) `lock_code`
 	/// (`u32`).
@@ -1997,6 +2139,20 @@ const_bitflag! { MNS: u32;
 	CHECKORBMP 0x0400_0000
 }
 
+const_bitflag! { MOD: u16;
+	/// [`HWND::RegisterHotKey`](crate::prelude::user_Hwnd::RegisterHotKey)
+	/// `modifiers` and [`wm::Hotkey`](crate::msg::wm::Hotkey) `modifiers`
+	/// (`u16`).
+	=>
+	=>
+	ALT 0x0001
+	CONTROL 0x0002
+	SHIFT 0x0004
+	WIN 0x0008
+	/// Not reported in [`wm::Hotkey`](crate::msg::wm::Hotkey).
+	NOREPEAT 0x4000
+}
+
 const_ordinary! { MONITOR: u32;
 	/// [`HMONITOR::MonitorFromPoint`](crate::prelude::user_Hmonitor::MonitorFromPoint),
 	/// [`HMONITOR::MonitorFromRect`](crate::prelude::user_Hmonitor::MonitorFromRect),
@@ -2018,6 +2174,17 @@ const_ordinary! { MONITORINFOF: u32;
 	PRIMARY 0x0000_0001
 }
 
+const_bitflag! { MOUSE: u16;
+	/// [`RAWMOUSE`](crate::RAWMOUSE) `usFlags` (`u16`).
+	=>
+	=>
+	MOVE_RELATIVE 0x00
+	MOVE_ABSOLUTE 0x01
+	VIRTUAL_DESKTOP 0x02
+	ATTRIBUTES_CHANGED 0x04
+	MOVE_NOCOALESCE 0x08
+}
+
 const_bitflag! { MOUSEEVENTF: u32;
 	/// [`MOUSEINPUT`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-mouseinput)
 	/// `dwFlags` (`u32`).
@@ -2256,6 +2423,70 @@ const_ordinary! { REGION: i32;
 	COMPLEX 3
 }
 
+const_bitflag! { RIDEV: u32;
+	/// [`RAWINPUTDEVICE`](crate::RAWINPUTDEVICE) `dwFlags` (`u32`).
+	=>
+	=>
+	NoValue 0
+	REMOVE 0x0000_0001
+	EXCLUDE 0x0000_0010
+	PAGEONLY 0x0000_0020
+	NOLEGACY 0x0000_0030
+	INPUTSINK 0x0000_0100
+	CAPTUREMOUSE 0x0000_0200
+	NOHOTKEYS 0x0000_0200
+	APPKEYS 0x0000_0400
+	EXINPUTSINK 0x0000_1000
+	DEVNOTIFY 0x0000_2000
+}
+
+const_ordinary! { RIM: u8;
+	/// [`wm::Input`](crate::msg::wm::Input) `code` (`u8`).
+	=>
+	=>
+	INPUT 0
+	INPUTSINK 1
+}
+
+const_ordinary! { RIM_TYPE: u32;
+	/// [`RAWINPUTHEADER`](crate::RAWINPUTHEADER) and
+	/// [`RAWINPUTDEVICELIST`](crate::RAWINPUTDEVICELIST) `dwType` (`u32`).
+	=>
+	=>
+	MOUSE 0
+	KEYBOARD 1
+	HID 2
+}
+
+const_bitflag! { RI_KEY: u16;
+	/// [`RAWKEYBOARD`](crate::RAWKEYBOARD) `Flags` (`u16`).
+	=>
+	=>
+	MAKE 0
+	BREAK 1
+	E0 2
+	E1 4
+}
+
+const_bitflag! { RI_MOUSE: u16;
+	/// [`RAWMOUSE`](crate::RAWMOUSE) `usButtonFlags` (`u16`).
+	=>
+	=>
+	NoValue 0
+	LEFT_BUTTON_DOWN 0x0001
+	LEFT_BUTTON_UP 0x0002
+	RIGHT_BUTTON_DOWN 0x0004
+	RIGHT_BUTTON_UP 0x0008
+	MIDDLE_BUTTON_DOWN 0x0010
+	MIDDLE_BUTTON_UP 0x0020
+	BUTTON_4_DOWN 0x0040
+	BUTTON_4_UP 0x0080
+	BUTTON_5_DOWN 0x0100
+	BUTTON_5_UP 0x0200
+	WHEEL 0x0400
+	HWHEEL 0x0800
+}
+
 const_ordinary! { SB_EM: u16;
 	/// [`em::Scroll`](crate::msg::em::Scroll) action.
 	///
@@ -3243,6 +3474,7 @@ const_ordinary! { WM: u32;
 	SYSCOLORCHANGE 0x0015
 	SHOWWINDOW 0x0018
 	WININICHANGE 0x001a
+	SETTINGCHANGE Self::WININICHANGE.0
 	DEVMODECHANGE 0x001b
 	ACTIVATEAPP 0x001c
 	FONTCHANGE 0x001d
@@ -19,6 +19,7 @@ extern_sys! { "user32";
 }
 
 extern_sys! { "user32";
+	AddClipboardFormatListener(HANDLE) -> BOOL
 	AdjustWindowRectEx(PVOID, u32, BOOL, u32) -> BOOL
 	AdjustWindowRectExForDpi(PVOID, u32, BOOL, u32, u32) -> BOOL
 	AllowSetForegroundWindow(u32) -> BOOL
@@ -43,6 +44,7 @@ extern_sys! { "user32";
 	CloseDesktop(HANDLE) -> BOOL
 	CloseWindow(HANDLE) -> BOOL
 	CopyIcon(HANDLE) -> HANDLE
+	CountClipboardFormats() -> i32
 	CreateAcceleratorTableW(PVOID, i32) -> HANDLE
 	CreateDesktopExW(PCSTR, PCSTR, PCVOID, u32, u32, PVOID, u32, PVOID) -> HANDLE
 	CreateDesktopW(PCSTR, PCSTR, PCVOID, u32, u32, PVOID) -> HANDLE
@@ -63,7 +65,9 @@ extern_sys! { "user32";
 	DispatchMessageW(PCVOID) -> isize
 	DragDetect(HANDLE, i32, i32) -> BOOL
 	DrawCaption(HANDLE, HANDLE, PCVOID, u32) -> BOOL
+	DrawEdge(HANDLE, PVOID, u32, u32) -> BOOL
 	DrawFocusRect(HANDLE, PCVOID) -> BOOL
+	DrawFrameControl(HANDLE, PVOID, u32, u32) -> BOOL
 	DrawMenuBar(HANDLE) -> BOOL
 	DrawTextExW(HANDLE, PCSTR, i32, PVOID, u32, PCVOID) -> i32
 	DrawTextW(HANDLE, PCSTR, i32, PVOID, u32) -> i32
@@ -76,6 +80,7 @@ extern_sys! { "user32";
 	EndMenu() -> BOOL
 	EndPaint(HANDLE, PCVOID) -> BOOL
 	EnumChildWindows(HANDLE, PFUNC, isize) -> BOOL
+	EnumClipboardFormats(u32) -> u32
 	EnumDisplayDevicesW(PCSTR, u32, PVOID, u32) -> BOOL
 	EnumDisplayMonitors(HANDLE, PCVOID, PFUNC, isize) -> BOOL
 	EnumDisplaySettingsExW(PCSTR, u32, PVOID, u32) -> BOOL
@@ -95,6 +100,7 @@ extern_sys! { "user32";
 	GetClassNameW(HANDLE, PSTR, i32) -> i32
 	GetClientRect(HANDLE, PVOID) -> BOOL
 	GetClipboardData(u32) -> HANDLE
+	GetClipboardFormatNameW(u32, PSTR, i32) -> i32
 	GetClipboardSequenceNumber() -> u32
 	GetClipCursor(PVOID) -> BOOL
 	GetCursorPos(PVOID) -> BOOL
@@ -104,6 +110,7 @@ extern_sys! { "user32";
 	GetDlgCtrlID(HANDLE) -> i32
 	GetDlgItem(HANDLE, i32) -> HANDLE
 	GetDoubleClickTime() -> u32
+	GetDpiForSystem() -> u32
 	GetDpiForWindow(HANDLE) -> u32
 	GetFocus() -> HANDLE
 	GetForegroundWindow() -> HANDLE
@@ -127,6 +134,9 @@ extern_sys! { "user32";
 	GetNextDlgTabItem(HANDLE, HANDLE, BOOL) -> HANDLE
 	GetParent(HANDLE) -> HANDLE
 	GetQueueStatus(u32) -> u32
+	GetRawInputData(HANDLE, u32, PVOID, *mut u32, u32) -> u32
+	GetRawInputDeviceInfoW(HANDLE, u32, PVOID, *mut u32) -> u32
+	GetRawInputDeviceList(PVOID, *mut u32, u32) -> u32
 	GetScrollInfo(HANDLE, i32, PVOID) -> BOOL
 	GetScrollPos(HANDLE, i32) -> i32
 	GetShellWindow() -> HANDLE
@@ -160,6 +170,7 @@ extern_sys! { "user32";
 	InvalidateRgn(HANDLE, HANDLE, BOOL) -> BOOL
 	InvertRect(HANDLE, PCVOID) -> BOOL
 	IsChild(HANDLE, HANDLE) -> BOOL
+	IsClipboardFormatAvailable(u32) -> BOOL
 	IsDialogMessageW(HANDLE, PVOID) -> BOOL
 	IsGUIThread(BOOL) -> BOOL
 	IsIconic(HANDLE) -> BOOL
@@ -201,9 +212,13 @@ extern_sys! { "user32";
 	RealGetWindowClassW(HANDLE, PSTR, i32) -> u32
 	RedrawWindow(HANDLE, PCVOID, HANDLE, u32) -> BOOL
 	RegisterClassExW(PCVOID) -> u16
+	RegisterClipboardFormatW(PCSTR) -> u32
+	RegisterHotKey(HANDLE, i32, u32, u32) -> BOOL
+	RegisterRawInputDevices(PCVOID, u32, u32) -> BOOL
 	RegisterWindowMessageW(PCSTR) -> u32
 	ReleaseCapture() -> BOOL
 	ReleaseDC(HANDLE, HANDLE) -> i32
+	RemoveClipboardFormatListener(HANDLE) -> BOOL
 	RemoveMenu(HANDLE, u32, u32) -> BOOL
 	ScreenToClient(HANDLE, PVOID) -> BOOL
 	ScrollWindowEx(HANDLE, i32, i32, PCVOID, PCVOID, HANDLE, PVOID, u32) -> i32
@@ -227,6 +242,7 @@ extern_sys! { "user32";
 	SetMenuItemInfoW(HANDLE, u32, BOOL, PCVOID) -> BOOL
 	SetParent(HANDLE, HANDLE) -> HANDLE
 	SetProcessDPIAware() -> BOOL
+	SetProcessDpiAwarenessContext(isize) -> BOOL
 	SetScrollInfo(HANDLE, i32, PCVOID, BOOL) -> i32
 	SetScrollPos(HANDLE, i32, i32, BOOL) -> i32
 	SetScrollRange(HANDLE, i32, i32, i32, BOOL) -> BOOL
@@ -249,6 +265,7 @@ extern_sys! { "user32";
 	SubtractRect(PVOID, PCVOID, PCVOID) -> BOOL
 	SwapMouseButton(BOOL) -> BOOL
 	SwitchDesktop(HANDLE) -> BOOL
+	SystemParametersInfoForDpi(u32, u32, PVOID, u32, u32) -> BOOL
 	SystemParametersInfoW(u32, u32, PVOID, u32) -> BOOL
 	TileWindows(HANDLE, u32, PCVOID, u32, PCVOID) -> u16
 	TrackMouseEvent(PVOID) -> BOOL
@@ -258,6 +275,7 @@ extern_sys! { "user32";
 	UnhookWindowsHookEx(HANDLE) -> BOOL
 	UnionRect(PVOID, PCVOID, PCVOID) -> BOOL
 	UnregisterClassW(PCSTR, HANDLE) -> BOOL
+	UnregisterHotKey(HANDLE, i32) -> BOOL
 	UpdateWindow(HANDLE) -> BOOL
 	ValidateRect(HANDLE, PCVOID) -> BOOL
 	ValidateRgn(HANDLE, HANDLE) -> BOOL
@@ -219,6 +219,20 @@ pub fn CommDlgExtendedError() -> co::CDERR {
 	unsafe { co::CDERR::from_raw(ffi::CommDlgExtendedError()) }
 }
 
+/// [`CountClipboardFormats`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-countclipboardformats)
+/// function.
+#[must_use]
+pub fn CountClipboardFormats() -> SysResult<u32> {
+	SetLastError(co::ERROR::SUCCESS);
+	match unsafe { ffi::CountClipboardFormats() } {
+		0 => match GetLastError() {
+			co::ERROR::SUCCESS => Ok(0), // clipboard is actually empty
+			err => Err(err),
+		},
+		count => Ok(count as _),
+	}
+}
+
 /// [`DispatchMessage`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-dispatchmessagew)
 /// function.
 ///
@@ -242,6 +256,42 @@ pub fn EndMenu() -> SysResult<()> {
 	bool_to_sysresult(unsafe { ffi::EndMenu() })
 }
 
+/// [`EnumClipboardFormats`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-enumclipboardformats)
+/// function.
+///
+/// Returns all the formats currently available in the clipboard, in the order
+/// they were placed. The clipboard must be opened with
+/// [`HWND::OpenClipboard`](crate::prelude::user_Hwnd::OpenClipboard).
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let _hclip = w::HWND::NULL.OpenClipboard()?;
+///
+/// for cf in w::EnumClipboardFormats()? {
+///     println!("{} {}", cf, w::GetClipboardFormatName(cf).unwrap_or_default());
+/// }
+/// # Ok::<_, winsafe::co::ERROR>(())
+/// ```
+#[must_use]
+pub fn EnumClipboardFormats() -> SysResult<Vec<co::CF>> {
+	let mut formats = Vec::<co::CF>::default();
+	let mut cur = 0;
+	loop {
+		SetLastError(co::ERROR::SUCCESS);
+		cur = unsafe { ffi::EnumClipboardFormats(cur) };
+		if cur == 0 {
+			return match GetLastError() {
+				co::ERROR::SUCCESS => Ok(formats), // no more formats
+				err => Err(err),
+			};
+		}
+		formats.push(unsafe { co::CF::from_raw(cur) });
+	}
+}
+
 /// [`EnumDisplayDevices`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-enumdisplaydevicesw)
 /// function.
 ///
@@ -493,6 +543,25 @@ pub unsafe fn GetClipboardData(format: co::CF) -> SysResult<*mut u8> {
 		.map(|hmem| hmem as *mut _ as _)
 }
 
+/// [`GetClipboardFormatName`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getclipboardformatnamew)
+/// function.
+///
+/// Fails for the predefined formats, which have no name.
+#[must_use]
+pub fn GetClipboardFormatName(format: co::CF) -> SysResult<String> {
+	let mut buf = WString::new_alloc_buf(256 + 1); // names are atoms, limited to 255 chars
+	match unsafe {
+		ffi::GetClipboardFormatNameW(
+			format.raw(),
+			buf.as_mut_ptr(),
+			buf.buf_len() as _,
+		)
+	} {
+		0 => Err(GetLastError()),
+		_ => Ok(buf.to_string()),
+	}
+}
+
 /// [`GetClipboardSequenceNumber`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getclipboardsequencenumber)
 /// function.
 #[must_use]
@@ -525,6 +594,13 @@ pub fn GetDialogBaseUnits() -> i32 {
 	unsafe { ffi::GetDialogBaseUnits() }
 }
 
+/// [`GetDpiForSystem`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getdpiforsystem)
+/// function.
+#[must_use]
+pub fn GetDpiForSystem() -> u32 {
+	unsafe { ffi::GetDpiForSystem() }
+}
+
 /// [`GetDoubleClickTime`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getdoubleclicktime)
 /// function.
 #[must_use]
@@ -604,6 +680,48 @@ pub fn GetQueueStatus(flags: co::QS) -> u32 {
 	unsafe { ffi::GetQueueStatus(flags.raw()) }
 }
 
+/// [`GetRawInputDeviceList`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getrawinputdevicelist)
+/// function.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// for dev in w::GetRawInputDeviceList()? {
+///     println!("{} {}", dev.dwType, dev.hDevice.device_name()?);
+/// }
+/// # Ok::<_, winsafe::co::ERROR>(())
+/// ```
+#[must_use]
+pub fn GetRawInputDeviceList() -> SysResult<Vec<RAWINPUTDEVICELIST>> {
+	let cb_item = std::mem::size_of::<RAWINPUTDEVICELIST>() as u32;
+	loop {
+		let mut num_devices = u32::default();
+		if unsafe {
+			ffi::GetRawInputDeviceList(std::ptr::null_mut(), &mut num_devices, cb_item)
+		} == u32::MAX {
+			return Err(GetLastError());
+		}
+
+		let mut devices = (0..num_devices)
+			.map(|_| RAWINPUTDEVICELIST::default())
+			.collect::<Vec<_>>();
+		match unsafe {
+			ffi::GetRawInputDeviceList(devices.as_mut_ptr() as _, &mut num_devices, cb_item)
+		} {
+			u32::MAX => match GetLastError() {
+				co::ERROR::INSUFFICIENT_BUFFER => continue, // a device was attached in between
+				err => return Err(err),
+			},
+			copied => {
+				devices.truncate(copied as _);
+				return Ok(devices);
+			},
+		}
+	}
+}
+
 /// [`GetSysColor`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getsyscolor)
 /// function.
 #[must_use]
@@ -673,6 +791,13 @@ pub fn IntersectRect(
 	)
 }
 
+/// [`IsClipboardFormatAvailable`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-isclipboardformatavailable)
+/// function.
+#[must_use]
+pub fn IsClipboardFormatAvailable(format: co::CF) -> bool {
+	unsafe { ffi::IsClipboardFormatAvailable(format.raw()) != 0 }
+}
+
 /// [`IsGUIThread`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-isguithread)
 /// function.
 pub fn IsGUIThread(convert_to_gui_thread: bool) -> SysResult<bool> {
@@ -776,6 +901,63 @@ pub unsafe fn RegisterClassEx(wcx: &WNDCLASSEX) -> SysResult<ATOM> {
 	}
 }
 
+/// [`RegisterClipboardFormat`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-registerclipboardformatw)
+/// function.
+///
+/// If a format with the given name is already registered, its value is
+/// returned, so the same name yields the same format across processes.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let cf_html = w::RegisterClipboardFormat("HTML Format")?;
+/// # Ok::<_, winsafe::co::ERROR>(())
+/// ```
+#[must_use]
+pub fn RegisterClipboardFormat(name: &str) -> SysResult<co::CF> {
+	match unsafe { ffi::RegisterClipboardFormatW(WString::from_str(name).as_ptr()) } {
+		0 => Err(GetLastError()),
+		cf => Ok(unsafe { co::CF::from_raw(cf) }),
+	}
+}
+
+/// [`RegisterRawInputDevices`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-registerrawinputdevices)
+/// function.
+///
+/// # Examples
+///
+/// Receiving raw mouse input, even when the window is in background:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let hwnd: w::HWND; // initialized somewhere
+/// # let hwnd = w::HWND::NULL;
+///
+/// w::RegisterRawInputDevices(&[
+///     w::RAWINPUTDEVICE {
+///         usUsagePage: 0x01, // generic desktop
+///         usUsage: 0x02, // mouse
+///         dwFlags: co::RIDEV::INPUTSINK,
+///         hwndTarget: hwnd,
+///     },
+/// ])?;
+/// # Ok::<_, co::ERROR>(())
+/// ```
+pub fn RegisterRawInputDevices(devices: &[RAWINPUTDEVICE]) -> SysResult<()> {
+	bool_to_sysresult(
+		unsafe {
+			ffi::RegisterRawInputDevices(
+				devices.as_ptr() as _,
+				devices.len() as _,
+				std::mem::size_of::<RAWINPUTDEVICE>() as _,
+			)
+		},
+	)
+}
+
 /// [`RegisterWindowMessage`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-registerwindowmessagew)
 /// function.
 #[must_use]
@@ -887,6 +1069,29 @@ pub fn SetProcessDPIAware() -> SysResult<()> {
 	bool_to_sysresult(unsafe { ffi::SetProcessDPIAware() })
 }
 
+/// [`SetProcessDpiAwarenessContext`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-setprocessdpiawarenesscontext)
+/// function.
+///
+/// Fails with [`co::ERROR::ACCESS_DENIED`](crate::co::ERROR::ACCESS_DENIED)
+/// if the awareness was already set, either by a previous call or by the
+/// application manifest.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// w::SetProcessDpiAwarenessContext(
+///     co::DPI_AWARENESS_CONTEXT::PER_MONITOR_AWARE_V2)?;
+/// # Ok::<_, co::ERROR>(())
+/// ```
+pub fn SetProcessDpiAwarenessContext(
+	value: co::DPI_AWARENESS_CONTEXT,
+) -> SysResult<()>
+{
+	bool_to_sysresult(unsafe { ffi::SetProcessDpiAwarenessContext(value.raw()) })
+}
+
 /// [`ShowCursor`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-showcursor)
 /// function.
 pub fn ShowCursor(show: bool) -> i32 {
@@ -924,6 +1129,32 @@ pub fn SwapMouseButton(swap: bool) -> bool {
 	unsafe { ffi::SwapMouseButton(swap as _) != 0 }
 }
 
+/// [`SystemParametersInfoForDpi`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-systemparametersinfofordpi)
+/// function.
+///
+/// # Safety
+///
+/// The `pv_param` type varies according to `action`. If you set it wrong,
+/// you're likely to cause a buffer overrun.
+pub unsafe fn SystemParametersInfoForDpi<T>(
+	action: co::SPI,
+	ui_param: u32,
+	pv_param: &mut T,
+	win_ini: co::SPIF,
+	dpi: u32,
+) -> SysResult<()>
+{
+	bool_to_sysresult(
+		ffi::SystemParametersInfoForDpi(
+			action.raw(),
+			ui_param,
+			pv_param as *mut _ as _,
+			win_ini.raw(),
+			dpi,
+		),
+	)
+}
+
 /// [`SystemParametersInfo`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-systemparametersinfow)
 /// function.
 ///
@@ -262,3 +262,46 @@ impl<'a, H> ReleaseDCGuard<'a, H>
 		std::mem::replace(&mut self.hdc, HDC::INVALID)
 	}
 }
+
+//------------------------------------------------------------------------------
+
+/// RAII implementation for a hotkey which automatically calls
+/// [`UnregisterHotKey`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-unregisterhotkey)
+/// when the object goes out of scope.
+///
+/// Unlike other window guards, it keeps its own copy of the
+/// [`HWND`](crate::HWND), so it can be stored along with the window state.
+pub struct UnregisterHotKeyGuard {
+	hwnd: HWND,
+	id: i32,
+}
+
+impl Drop for UnregisterHotKeyGuard {
+	fn drop(&mut self) {
+		unsafe { ffi::UnregisterHotKey(self.hwnd.ptr(), self.id); } // ignore errors
+	}
+}
+
+impl UnregisterHotKeyGuard {
+	/// Constructs the guard by taking ownership of the hotkey.
+	///
+	/// # Safety
+	///
+	/// Be sure the hotkey must be freed with
+	/// [`UnregisterHotKey`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-unregisterhotkey)
+	/// at the end of scope.
+	///
+	/// This method is used internally by the library, and not intended to be
+	/// used externally.
+	#[must_use]
+	pub const unsafe fn new(hwnd: HWND, id: i32) -> Self {
+		Self { hwnd, id }
+	}
+
+	/// Returns the ID of the hotkey, which is reported in
+	/// [`wm::Hotkey`](crate::msg::wm::Hotkey).
+	#[must_use]
+	pub const fn id(&self) -> i32 {
+		self.id
+	}
+}
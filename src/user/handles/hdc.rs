@@ -22,6 +22,24 @@ impl user_Hdc for HDC {}
 /// use winsafe::prelude::*;
 /// ```
 pub trait user_Hdc: Handle {
+	/// [`DrawEdge`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-drawedge)
+	/// function.
+	///
+	/// If `flags` has [`co::BF::ADJUST`](crate::co::BF::ADJUST), the rectangle
+	/// is shrunk to exclude the edges.
+	fn DrawEdge(&self,
+		rc: &mut RECT,
+		edge: co::BDR,
+		flags: co::BF,
+	) -> SysResult<()>
+	{
+		bool_to_sysresult(
+			unsafe {
+				ffi::DrawEdge(self.ptr(), rc as *mut _ as _, edge.raw(), flags.raw())
+			},
+		)
+	}
+
 	/// [`DrawFocusRect`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-drawfocusrect)
 	/// function.
 	fn DrawFocusRect(&self, rect: &RECT) -> SysResult<()> {
@@ -30,6 +48,24 @@ pub trait user_Hdc: Handle {
 		)
 	}
 
+	/// [`DrawFrameControl`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-drawframecontrol)
+	/// function.
+	///
+	/// If `state` has [`co::DFCS::ADJUSTRECT`](crate::co::DFCS::ADJUSTRECT),
+	/// the rectangle is shrunk to exclude the edges.
+	fn DrawFrameControl(&self,
+		rc: &mut RECT,
+		kind: co::DFC,
+		state: co::DFCS,
+	) -> SysResult<()>
+	{
+		bool_to_sysresult(
+			unsafe {
+				ffi::DrawFrameControl(self.ptr(), rc as *mut _ as _, kind.raw(), state.raw())
+			},
+		)
+	}
+
 	/// [`DrawText`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-drawtextw)
 	/// function.
 	fn DrawText(&self,
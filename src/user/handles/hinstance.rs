@@ -1,5 +1,6 @@
 #![allow(non_camel_case_types, non_snake_case)]
 
+use crate::co;
 use crate::decl::*;
 use crate::guard::*;
 use crate::kernel::privs::*;
@@ -17,6 +18,38 @@ impl user_Hinstance for HINSTANCE {}
 /// use winsafe::prelude::*;
 /// ```
 pub trait user_Hinstance: kernel_Hinstance {
+	/// Loads and decodes an
+	/// [`RT_DIALOG`](https://learn.microsoft.com/en-us/windows/win32/dlgbox/dialog-box-templates)
+	/// resource.
+	///
+	/// For an example, see [`ResDialog`](crate::ResDialog).
+	#[must_use]
+	fn load_dialog_template(&self,
+		resource_id: IdStr,
+		language: Option<LANGID>,
+	) -> SysResult<ResDialog>
+	{
+		ResDialog::parse(
+			self.load_resource_data(resource_id, RtStr::Rt(co::RT::DIALOG), language)?,
+		)
+	}
+
+	/// Loads and decodes an
+	/// [`RT_MENU`](https://learn.microsoft.com/en-us/windows/win32/menurc/menu-template-resources)
+	/// resource.
+	///
+	/// For an example, see [`ResMenu`](crate::ResMenu).
+	#[must_use]
+	fn load_menu_template(&self,
+		resource_id: IdStr,
+		language: Option<LANGID>,
+	) -> SysResult<ResMenu>
+	{
+		ResMenu::parse(
+			self.load_resource_data(resource_id, RtStr::Rt(co::RT::MENU), language)?,
+		)
+	}
+
 	/// [`CreateDialogParam`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createdialogparamw)
 	/// function.
 	///
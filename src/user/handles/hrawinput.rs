@@ -0,0 +1,62 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::decl::*;
+use crate::prelude::*;
+use crate::user::{ffi, privs::*};
+
+impl_handle! { HRAWINPUT;
+	/// Handle to a
+	/// [raw input](https://learn.microsoft.com/en-us/windows/win32/inputdev/raw-input)
+	/// record, received in [`wm::Input`](crate::msg::wm::Input).
+}
+
+impl user_Hrawinput for HRAWINPUT {}
+
+/// This trait is enabled with the `user` feature, and provides methods for
+/// [`HRAWINPUT`](crate::HRAWINPUT).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait user_Hrawinput: Handle {
+	/// [`GetRawInputData`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getrawinputdata)
+	/// function.
+	///
+	/// Retrieves the whole `RAWINPUT` record and decodes it.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let hrawinput: w::HRAWINPUT; // initialized somewhere
+	/// # let hrawinput = w::HRAWINPUT::NULL;
+	///
+	/// match hrawinput.GetRawInputData()?.data {
+	///     w::RawInputData::Mouse(m) => println!("Mouse: {}, {}", m.lLastX, m.lLastY),
+	///     w::RawInputData::Keyboard(k) => println!("Key: {}", k.VKey),
+	///     w::RawInputData::Hid(h) => println!("HID: {} reports", h.count),
+	/// }
+	/// # Ok::<_, winsafe::co::ERROR>(())
+	/// ```
+	#[must_use]
+	fn GetRawInputData(&self) -> SysResult<RawInput> {
+		let hdr_sz = std::mem::size_of::<RAWINPUTHEADER>() as u32;
+		let mut sz = u32::default();
+		if unsafe {
+			ffi::GetRawInputData(self.ptr(), RID_INPUT, std::ptr::null_mut(), &mut sz, hdr_sz)
+		} == u32::MAX {
+			return Err(GetLastError());
+		}
+
+		let mut buf = vec![0u8; sz as _];
+		match unsafe {
+			ffi::GetRawInputData(self.ptr(), RID_INPUT, buf.as_mut_ptr() as _, &mut sz, hdr_sz)
+		} {
+			u32::MAX => Err(GetLastError()),
+			copied => RawInput::parse(&buf[..copied as _]),
+		}
+	}
+}
@@ -0,0 +1,112 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::decl::*;
+use crate::prelude::*;
+use crate::user::{ffi, privs::*};
+
+impl_handle! { HRAWINPUTDEVICE;
+	/// Handle to a
+	/// [raw input](https://learn.microsoft.com/en-us/windows/win32/inputdev/raw-input)
+	/// device, as returned by
+	/// [`GetRawInputDeviceList`](crate::GetRawInputDeviceList).
+	///
+	/// Originally just a `HANDLE`.
+}
+
+impl user_Hrawinputdevice for HRAWINPUTDEVICE {}
+
+/// This trait is enabled with the `user` feature, and provides methods for
+/// [`HRAWINPUTDEVICE`](crate::HRAWINPUTDEVICE).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait user_Hrawinputdevice: Handle {
+	/// [`GetRawInputDeviceInfo`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getrawinputdeviceinfow)
+	/// function with `RIDI_DEVICENAME`.
+	///
+	/// Returns the device interface path, which can be opened with
+	/// [`HFILE::CreateFile`](crate::prelude::kernel_Hfile::CreateFile).
+	#[must_use]
+	fn device_name(&self) -> SysResult<String> {
+		let mut num_chars = u32::default();
+		if unsafe {
+			ffi::GetRawInputDeviceInfoW(self.ptr(), RIDI_DEVICENAME,
+				std::ptr::null_mut(), &mut num_chars)
+		} == u32::MAX {
+			return Err(GetLastError());
+		}
+
+		let mut buf = WString::new_alloc_buf(num_chars as usize + 1);
+		num_chars = buf.buf_len() as _;
+		match unsafe {
+			ffi::GetRawInputDeviceInfoW(self.ptr(), RIDI_DEVICENAME,
+				buf.as_mut_ptr() as _, &mut num_chars)
+		} {
+			u32::MAX => Err(GetLastError()),
+			_ => Ok(buf.to_string()),
+		}
+	}
+
+	/// [`GetRawInputDeviceInfo`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getrawinputdeviceinfow)
+	/// function with `RIDI_PREPARSEDDATA`.
+	///
+	/// Returns the HID preparsed data, which is opaque and meant to be passed
+	/// to the `HidP_*` functions. Fails for keyboards and mice.
+	#[must_use]
+	fn preparsed_data(&self) -> SysResult<Vec<u8>> {
+		let mut sz = u32::default();
+		if unsafe {
+			ffi::GetRawInputDeviceInfoW(self.ptr(), RIDI_PREPARSEDDATA,
+				std::ptr::null_mut(), &mut sz)
+		} == u32::MAX {
+			return Err(GetLastError());
+		}
+
+		let mut buf = vec![0u8; sz as _];
+		match unsafe {
+			ffi::GetRawInputDeviceInfoW(self.ptr(), RIDI_PREPARSEDDATA,
+				buf.as_mut_ptr() as _, &mut sz)
+		} {
+			u32::MAX => Err(GetLastError()),
+			copied => {
+				buf.truncate(copied as _);
+				Ok(buf)
+			},
+		}
+	}
+
+	/// [`GetRawInputDeviceInfo`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getrawinputdeviceinfow)
+	/// function with `RIDI_DEVICEINFO`.
+	///
+	/// # Examples
+	///
+	/// Listing the keyboards:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// for dev in w::GetRawInputDeviceList()? {
+	///     if dev.dwType == co::RIM_TYPE::KEYBOARD {
+	///         let info = dev.hDevice.GetRawInputDeviceInfo()?;
+	///         println!("{} - {} keys", dev.hDevice.device_name()?,
+	///             info.keyboard().unwrap().dwNumberOfKeysTotal);
+	///     }
+	/// }
+	/// # Ok::<_, co::ERROR>(())
+	/// ```
+	#[must_use]
+	fn GetRawInputDeviceInfo(&self) -> SysResult<RID_DEVICE_INFO> {
+		let mut info = RID_DEVICE_INFO::default();
+		let mut sz = std::mem::size_of::<RID_DEVICE_INFO>() as u32;
+		match unsafe {
+			ffi::GetRawInputDeviceInfoW(self.ptr(), RIDI_DEVICEINFO,
+				&mut info as *mut _ as _, &mut sz)
+		} {
+			u32::MAX => Err(GetLastError()),
+			_ => Ok(info),
+		}
+	}
+}
@@ -43,6 +43,18 @@ pub trait user_Hwnd: Handle {
 		}
 	}
 
+	/// [`AddClipboardFormatListener`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-addclipboardformatlistener)
+	/// function.
+	///
+	/// The window will receive
+	/// [`wm::ClipboardUpdate`](crate::msg::wm::ClipboardUpdate) messages
+	/// whenever the contents of the clipboard change, until
+	/// [`HWND::RemoveClipboardFormatListener`](crate::prelude::user_Hwnd::RemoveClipboardFormatListener)
+	/// is called.
+	fn AddClipboardFormatListener(&self) -> SysResult<()> {
+		bool_to_sysresult(unsafe { ffi::AddClipboardFormatListener(self.ptr()) })
+	}
+
 	/// [`ArrangeIconicWindows`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-arrangeiconicwindows)
 	/// function.
 	fn ArrangeIconicWindows(&self) -> SysResult<u32> {
@@ -1292,6 +1304,71 @@ pub trait user_Hwnd: Handle {
 		)
 	}
 
+	/// [`RegisterHotKey`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-registerhotkey)
+	/// function.
+	///
+	/// In the original C implementation, you must call
+	/// [`UnregisterHotKey`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-unregisterhotkey)
+	/// as a cleanup operation.
+	///
+	/// Here, the cleanup is performed automatically, because `RegisterHotKey`
+	/// returns an
+	/// [`UnregisterHotKeyGuard`](crate::guard::UnregisterHotKeyGuard), which
+	/// automatically calls `UnregisterHotKey` when the guard goes out of
+	/// scope. You must, however, keep the guard alive, otherwise the cleanup
+	/// will be performed right away.
+	///
+	/// The window will receive [`wm::Hotkey`](crate::msg::wm::Hotkey)
+	/// messages even when it doesn't have the focus. If the window is
+	/// [`HWND::NULL`](crate::HWND::NULL), the messages are posted to the
+	/// message queue of the calling thread.
+	///
+	/// Fails with
+	/// [`co::ERROR::HOTKEY_ALREADY_REGISTERED`](crate::co::ERROR::HOTKEY_ALREADY_REGISTERED)
+	/// if another application already registered the same key combination.
+	///
+	/// # Examples
+	///
+	/// Registering Ctrl+Shift+S:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let hwnd: w::HWND; // initialized somewhere
+	/// # let hwnd = w::HWND::NULL;
+	///
+	/// let _hotkey = hwnd.RegisterHotKey( // keep guard alive
+	///     1,
+	///     co::MOD::CONTROL | co::MOD::SHIFT | co::MOD::NOREPEAT,
+	///     co::VK::CHAR_S,
+	/// )?;
+	/// # Ok::<_, co::ERROR>(())
+	/// ```
+	#[must_use]
+	fn RegisterHotKey(&self,
+		id: i32,
+		modifiers: co::MOD,
+		vkey_code: co::VK,
+	) -> SysResult<UnregisterHotKeyGuard>
+	{
+		unsafe {
+			bool_to_sysresult(
+				ffi::RegisterHotKey(
+					self.ptr(),
+					id,
+					modifiers.raw() as _,
+					vkey_code.raw() as _,
+				),
+			).map(|_| UnregisterHotKeyGuard::new(HWND::from_ptr(self.ptr()), id))
+		}
+	}
+
+	/// [`RemoveClipboardFormatListener`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-removeclipboardformatlistener)
+	/// function.
+	fn RemoveClipboardFormatListener(&self) -> SysResult<()> {
+		bool_to_sysresult(unsafe { ffi::RemoveClipboardFormatListener(self.ptr()) })
+	}
+
 	/// [`ScreenToClient`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-screentoclient)
 	/// function.
 	///
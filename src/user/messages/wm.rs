@@ -158,6 +158,10 @@ pub_struct_msg_empty_handleable! { ChildActivate: co::WM::CHILDACTIVATE;
 	/// [`WM_CHILDACTIVATE`](https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-childactivate)
 }
 
+pub_struct_msg_empty_handleable! { ClipboardUpdate: co::WM::CLIPBOARDUPDATE;
+	/// [`WM_CLIPBOARDUPDATE`](https://learn.microsoft.com/en-us/windows/win32/dataxchg/wm-clipboardupdate)
+}
+
 pub_struct_msg_empty_handleable! { Close: co::WM::CLOSE;
 	/// [`WM_CLOSE`](https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-close)
 }
@@ -316,6 +320,44 @@ pub_struct_msg_empty_handleable! { Destroy: co::WM::DESTROY;
 	/// [`WM_DESTROY`](https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-destroy)
 }
 
+/// [`WM_DPICHANGED`](https://learn.microsoft.com/en-us/windows/win32/hidpi/wm-dpichanged)
+/// message parameters.
+///
+/// Return type: `()`.
+pub struct DpiChanged<'a> {
+	/// The new DPI of the window; horizontal and vertical values are always
+	/// the same.
+	pub new_dpi: u32,
+	/// Suggested size and position of the window, in screen coordinates,
+	/// scaled for the new DPI.
+	pub suggested_rect: &'a RECT,
+}
+
+unsafe impl<'a> MsgSend for DpiChanged<'a> {
+	type RetType = ();
+
+	fn convert_ret(&self, _: isize) -> Self::RetType {
+		()
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::WM::DPICHANGED,
+			wparam: MAKEDWORD(self.new_dpi as _, self.new_dpi as _) as _,
+			lparam: self.suggested_rect as *const _ as _,
+		}
+	}
+}
+
+unsafe impl<'a> MsgSendRecv for DpiChanged<'a> {
+	fn from_generic_wm(p: WndMsg) -> Self {
+		Self {
+			new_dpi: LOWORD(p.wparam as _) as _,
+			suggested_rect: unsafe { &*(p.lparam as *const _) },
+		}
+	}
+}
+
 /// [`WM_ENABLE`](https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-enable)
 /// message parameters.
 ///
@@ -767,6 +809,46 @@ unsafe impl<'a> MsgSendRecv for Help<'a> {
 	}
 }
 
+/// [`WM_HOTKEY`](https://learn.microsoft.com/en-us/windows/win32/inputdev/wm-hotkey)
+/// message parameters.
+///
+/// Return type: `()`.
+pub struct Hotkey {
+	/// ID given to
+	/// [`HWND::RegisterHotKey`](crate::prelude::user_Hwnd::RegisterHotKey),
+	/// or `-1` (`IDHOT_SNAPWINDOW`) and `-2` (`IDHOT_SNAPDESKTOP`) for the
+	/// system hotkeys.
+	pub id: i32,
+	pub modifiers: co::MOD,
+	pub vkey_code: co::VK,
+}
+
+unsafe impl MsgSend for Hotkey {
+	type RetType = ();
+
+	fn convert_ret(&self, _: isize) -> Self::RetType {
+		()
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::WM::HOTKEY,
+			wparam: self.id as _,
+			lparam: MAKEDWORD(self.modifiers.raw(), self.vkey_code.raw()) as _,
+		}
+	}
+}
+
+unsafe impl MsgSendRecv for Hotkey {
+	fn from_generic_wm(p: WndMsg) -> Self {
+		Self {
+			id: p.wparam as _,
+			modifiers: unsafe { co::MOD::from_raw(LOWORD(p.lparam as _)) },
+			vkey_code: unsafe { co::VK::from_raw(HIWORD(p.lparam as _)) },
+		}
+	}
+}
+
 /// [`WM_HSCROLL`](https://learn.microsoft.com/en-us/windows/win32/controls/wm-hscroll)
 /// message parameters.
 ///
@@ -876,6 +958,40 @@ unsafe impl MsgSendRecv for InitMenuPopup {
 	}
 }
 
+/// [`WM_INPUT`](https://learn.microsoft.com/en-us/windows/win32/inputdev/wm-input)
+/// message parameters.
+///
+/// Return type: `()`.
+pub struct Input {
+	pub code: co::RIM,
+	pub hrawinput: HRAWINPUT,
+}
+
+unsafe impl MsgSend for Input {
+	type RetType = ();
+
+	fn convert_ret(&self, _: isize) -> Self::RetType {
+		()
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::WM::INPUT,
+			wparam: self.code.raw() as _,
+			lparam: self.hrawinput.ptr() as _,
+		}
+	}
+}
+
+unsafe impl MsgSendRecv for Input {
+	fn from_generic_wm(p: WndMsg) -> Self {
+		Self {
+			code: unsafe { co::RIM::from_raw((p.wparam & 0xff) as _) },
+			hrawinput: unsafe { HRAWINPUT::from_ptr(p.lparam as _) },
+		}
+	}
+}
+
 pub_struct_msg_char_key! { KeyDown: co::WM::KEYDOWN;
 	/// [`WM_KEYDOWN`](https://learn.microsoft.com/en-us/windows/win32/inputdev/wm-keydown)
 }
@@ -1513,6 +1629,62 @@ unsafe impl MsgSendRecv for SetText {
 	}
 }
 
+/// [`WM_SETTINGCHANGE`](https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-settingchange)
+/// message parameters.
+///
+/// Return type: `()`.
+pub struct SettingChange {
+	/// The [`co::SPI`](crate::co::SPI) flag, if the change was made with
+	/// [`SystemParametersInfo`](crate::SystemParametersInfo); otherwise zero.
+	pub action: co::SPI,
+	/// Null-terminated name of the changed section, like `ImmersiveColorSet`
+	/// or `Environment`; may be null.
+	pub section: *const u16, // can't be WString because this message can be received
+}
+
+unsafe impl MsgSend for SettingChange {
+	type RetType = ();
+
+	fn convert_ret(&self, _: isize) -> Self::RetType {
+		()
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::WM::SETTINGCHANGE,
+			wparam: self.action.raw() as _,
+			lparam: self.section as _,
+		}
+	}
+}
+
+unsafe impl MsgSendRecv for SettingChange {
+	fn from_generic_wm(p: WndMsg) -> Self {
+		Self {
+			action: unsafe { co::SPI::from_raw(p.wparam as _) },
+			section: p.lparam as _,
+		}
+	}
+}
+
+impl SettingChange {
+	/// Returns the name of the changed section, if any.
+	///
+	/// # Safety
+	///
+	/// The `section` pointer must be null or point to a valid null-terminated
+	/// string, which is the case when the message was received by a window
+	/// procedure.
+	#[must_use]
+	pub unsafe fn section_name(&self) -> Option<String> {
+		if self.section.is_null() {
+			None
+		} else {
+			Some(WString::from_wchars_nullt(self.section).to_string())
+		}
+	}
+}
+
 /// [`WM_SHOWWINDOW`](https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-showwindow)
 /// message parameters.
 ///
@@ -620,6 +620,18 @@ impl INPUT {
 	}
 }
 
+/// [`KBDLLHOOKSTRUCT`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-kbdllhookstruct)
+/// struct.
+#[repr(C)]
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+pub struct KBDLLHOOKSTRUCT {
+	pub vkCode: co::VK,
+	pub scanCode: u32,
+	pub flags: co::LLKHF,
+	pub time: u32,
+	pub dwExtraInfo: usize,
+}
+
 /// [`KEYBDINPUT`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-keybdinput)
 /// struct.
 #[repr(C)]
@@ -745,6 +757,35 @@ pub struct MOUSEINPUT {
 	pub dwExtraInfo: usize,
 }
 
+/// [`MSLLHOOKSTRUCT`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-msllhookstruct)
+/// struct.
+#[repr(C)]
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+pub struct MSLLHOOKSTRUCT {
+	pub pt: POINT,
+	pub mouseData: u32,
+	pub flags: co::LLMHF,
+	pub time: u32,
+	pub dwExtraInfo: usize,
+}
+
+impl MSLLHOOKSTRUCT {
+	/// Returns the wheel delta, stored in the high-order word of `mouseData`
+	/// for [`co::WM::MOUSEWHEEL`](crate::co::WM::MOUSEWHEEL) and
+	/// [`co::WM::MOUSEHWHEEL`](crate::co::WM::MOUSEHWHEEL).
+	#[must_use]
+	pub const fn wheel_delta(&self) -> i16 {
+		HIWORD(self.mouseData) as _
+	}
+
+	/// Returns the X button – `1` or `2` –, stored in the high-order word of
+	/// `mouseData` for the `WM_XBUTTON*` messages.
+	#[must_use]
+	pub const fn xbutton(&self) -> u16 {
+		HIWORD(self.mouseData)
+	}
+}
+
 /// [`NCCALCSIZE_PARAMS`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-nccalcsize_params)
 /// struct.
 #[repr(C)]
@@ -782,7 +823,7 @@ impl PAINTSTRUCT {
 /// [`POINT`](https://learn.microsoft.com/en-us/windows/win32/api/windef/ns-windef-point)
 /// struct.
 #[repr(C)]
-#[derive(Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub struct POINT {
 	pub x: i32,
 	pub y: i32,
@@ -814,10 +855,85 @@ impl POINT {
 	}
 }
 
+/// [`RAWINPUTDEVICE`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-rawinputdevice)
+/// struct.
+///
+/// Common HID usages, for page `0x01` (generic desktop), are `0x02` for mice
+/// and `0x06` for keyboards.
+#[repr(C)]
+pub struct RAWINPUTDEVICE {
+	pub usUsagePage: u16,
+	pub usUsage: u16,
+	pub dwFlags: co::RIDEV,
+	pub hwndTarget: HWND,
+}
+
+impl_default!(RAWINPUTDEVICE);
+
+/// [`RAWINPUTDEVICELIST`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-rawinputdevicelist)
+/// struct.
+#[repr(C)]
+pub struct RAWINPUTDEVICELIST {
+	pub hDevice: HRAWINPUTDEVICE,
+	pub dwType: co::RIM_TYPE,
+}
+
+impl_default!(RAWINPUTDEVICELIST);
+
+/// [`RAWINPUTHEADER`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-rawinputheader)
+/// struct.
+#[repr(C)]
+pub struct RAWINPUTHEADER {
+	pub dwType: co::RIM_TYPE,
+	pub dwSize: u32,
+	pub hDevice: HRAWINPUTDEVICE,
+	pub wParam: usize,
+}
+
+impl_default!(RAWINPUTHEADER);
+
+/// [`RAWKEYBOARD`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-rawkeyboard)
+/// struct.
+#[repr(C)]
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+pub struct RAWKEYBOARD {
+	pub MakeCode: u16,
+	pub Flags: co::RI_KEY,
+	Reserved: u16,
+	pub VKey: co::VK,
+	pub Message: co::WM,
+	pub ExtraInformation: u32,
+}
+
+/// [`RAWMOUSE`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-rawmouse)
+/// struct.
+#[repr(C)]
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+pub struct RAWMOUSE {
+	pub usFlags: co::MOUSE,
+	padding1: u16,
+	pub usButtonFlags: co::RI_MOUSE,
+	pub usButtonData: u16,
+	pub ulRawButtons: u32,
+	pub lLastX: i32,
+	pub lLastY: i32,
+	pub ulExtraInformation: u32,
+}
+
+impl RAWMOUSE {
+	/// Returns the wheel delta, which is stored in `usButtonData` when
+	/// `usButtonFlags` has [`co::RI_MOUSE::WHEEL`](crate::co::RI_MOUSE::WHEEL)
+	/// or [`co::RI_MOUSE::HWHEEL`](crate::co::RI_MOUSE::HWHEEL).
+	#[must_use]
+	pub const fn wheel_delta(&self) -> i16 {
+		self.usButtonData as _
+	}
+}
+
 /// [`RECT`](https://learn.microsoft.com/en-us/windows/win32/api/windef/ns-windef-rect)
 /// struct.
 #[repr(C)]
-#[derive(Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub struct RECT {
 	pub left: i32,
 	pub top: i32,
@@ -832,6 +948,96 @@ impl std::fmt::Display for RECT {
 	}
 }
 
+/// [`RID_DEVICE_INFO`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-rid_device_info)
+/// struct.
+#[repr(C)]
+pub struct RID_DEVICE_INFO {
+	cbSize: u32,
+	pub dwType: co::RIM_TYPE,
+	union0: RID_DEVICE_INFO_union0,
+}
+
+#[repr(C)]
+union RID_DEVICE_INFO_union0 {
+	mouse: RID_DEVICE_INFO_MOUSE,
+	keyboard: RID_DEVICE_INFO_KEYBOARD,
+	hid: RID_DEVICE_INFO_HID,
+}
+
+impl_default_with_size!(RID_DEVICE_INFO, cbSize);
+
+impl RID_DEVICE_INFO {
+	/// Returns the `hid` union field, if `dwType` is
+	/// [`co::RIM_TYPE::HID`](crate::co::RIM_TYPE::HID).
+	#[must_use]
+	pub const fn hid(&self) -> Option<&RID_DEVICE_INFO_HID> {
+		match self.dwType {
+			co::RIM_TYPE::HID => Some(unsafe { &self.union0.hid }),
+			_ => None,
+		}
+	}
+
+	/// Returns the `keyboard` union field, if `dwType` is
+	/// [`co::RIM_TYPE::KEYBOARD`](crate::co::RIM_TYPE::KEYBOARD).
+	#[must_use]
+	pub const fn keyboard(&self) -> Option<&RID_DEVICE_INFO_KEYBOARD> {
+		match self.dwType {
+			co::RIM_TYPE::KEYBOARD => Some(unsafe { &self.union0.keyboard }),
+			_ => None,
+		}
+	}
+
+	/// Returns the `mouse` union field, if `dwType` is
+	/// [`co::RIM_TYPE::MOUSE`](crate::co::RIM_TYPE::MOUSE).
+	#[must_use]
+	pub const fn mouse(&self) -> Option<&RID_DEVICE_INFO_MOUSE> {
+		match self.dwType {
+			co::RIM_TYPE::MOUSE => Some(unsafe { &self.union0.mouse }),
+			_ => None,
+		}
+	}
+}
+
+/// [`RID_DEVICE_INFO_HID`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-rid_device_info_hid)
+/// struct.
+#[repr(C)]
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+pub struct RID_DEVICE_INFO_HID {
+	pub dwVendorId: u32,
+	pub dwProductId: u32,
+	pub dwVersionNumber: u32,
+	pub usUsagePage: u16,
+	pub usUsage: u16,
+}
+
+/// [`RID_DEVICE_INFO_KEYBOARD`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-rid_device_info_keyboard)
+/// struct.
+#[repr(C)]
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+pub struct RID_DEVICE_INFO_KEYBOARD {
+	pub dwType: u32,
+	pub dwSubType: u32,
+	pub dwKeyboardMode: u32,
+	pub dwNumberOfFunctionKeys: u32,
+	pub dwNumberOfIndicators: u32,
+	pub dwNumberOfKeysTotal: u32,
+}
+
+/// [`RID_DEVICE_INFO_MOUSE`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-rid_device_info_mouse)
+/// struct.
+#[repr(C)]
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+pub struct RID_DEVICE_INFO_MOUSE {
+	pub dwId: u32,
+	pub dwNumberOfButtons: u32,
+	pub dwSampleRate: u32,
+	fHasHorizontalWheel: BOOL,
+}
+
+impl RID_DEVICE_INFO_MOUSE {
+	pub_fn_bool_get_set!(fHasHorizontalWheel, set_fHasHorizontalWheel);
+}
+
 /// [`SCROLLINFO`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-scrollinfo)
 /// struct.
 #[repr(C)]
@@ -851,7 +1057,7 @@ impl_default_with_size!(SCROLLINFO, cbSize);
 /// [`SIZE`](https://learn.microsoft.com/en-us/windows/win32/api/windef/ns-windef-size)
 /// struct.
 #[repr(C)]
-#[derive(Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub struct SIZE {
 	pub cx: i32,
 	pub cy: i32,
@@ -0,0 +1,456 @@
+use crate::co;
+use crate::decl::*;
+use crate::guard::*;
+use crate::prelude::*;
+
+/// An opened clipboard, with typed methods to read and write its formats.
+///
+/// The clipboard is opened with
+/// [`HWND::OpenClipboard`](crate::prelude::user_Hwnd::OpenClipboard) and
+/// automatically closed when the object goes out of scope. Memory formats are
+/// transferred through [`HGLOBAL`](crate::HGLOBAL) blocks, which are owned by
+/// the system once written.
+///
+/// To write, first call [`Clipboard::clear`](crate::Clipboard::clear), which
+/// also makes the window the clipboard owner, then write one or more formats.
+/// Note that writing fails if the clipboard was opened with
+/// [`HWND::NULL`](crate::HWND::NULL).
+///
+/// File lists – the `CF_HDROP` format – are handled by the
+/// [`shell_Clipboard`](crate::prelude::shell_Clipboard) trait, enabled with the
+/// `shell` feature.
+///
+/// # Examples
+///
+/// Reading text:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let hwnd: w::HWND; // initialized somewhere
+/// # let hwnd = w::HWND::NULL;
+///
+/// let clip = w::Clipboard::open(&hwnd)?;
+/// if let Some(text) = clip.read_text()? {
+///     println!("{}", text);
+/// }
+/// # Ok::<_, winsafe::co::ERROR>(())
+/// ```
+///
+/// Writing text and HTML at once:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let hwnd: w::HWND; // initialized somewhere
+/// # let hwnd = w::HWND::NULL;
+///
+/// let clip = w::Clipboard::open(&hwnd)?;
+/// clip.clear()?;
+/// clip.write_text("Hello")?;
+/// clip.write_html(&w::ClipboardHtml::new("<b>Hello</b>", None))?;
+/// # Ok::<_, winsafe::co::ERROR>(())
+/// ```
+pub struct Clipboard<'a> {
+	_guard: CloseClipboardGuard<'a>,
+}
+
+impl<'a> Clipboard<'a> {
+	/// Opens the clipboard with
+	/// [`HWND::OpenClipboard`](crate::prelude::user_Hwnd::OpenClipboard).
+	///
+	/// Fails if another window has the clipboard open.
+	#[must_use]
+	pub fn open(hwnd: &'a HWND) -> SysResult<Self> {
+		Ok(Self { _guard: hwnd.OpenClipboard()? })
+	}
+
+	/// Returns the `HTML Format` clipboard format, registered with
+	/// [`RegisterClipboardFormat`](crate::RegisterClipboardFormat).
+	#[must_use]
+	pub fn html_format() -> SysResult<co::CF> {
+		RegisterClipboardFormat("HTML Format")
+	}
+
+	/// Empties the clipboard with
+	/// [`EmptyClipboard`](crate::EmptyClipboard), making the window the
+	/// clipboard owner. Must be called before writing.
+	pub fn clear(&self) -> SysResult<()> {
+		EmptyClipboard()
+	}
+
+	/// Returns the formats currently available, with
+	/// [`EnumClipboardFormats`](crate::EnumClipboardFormats).
+	#[must_use]
+	pub fn formats(&self) -> SysResult<Vec<co::CF>> {
+		EnumClipboardFormats()
+	}
+
+	/// Tells whether the format is available, with
+	/// [`IsClipboardFormatAvailable`](crate::IsClipboardFormatAvailable).
+	#[must_use]
+	pub fn has_format(&self, format: co::CF) -> bool {
+		IsClipboardFormatAvailable(format)
+	}
+
+	/// Returns a copy of the raw memory block of the given format, or `None`
+	/// if the format is not available.
+	///
+	/// Only formats stored in [`HGLOBAL`](crate::HGLOBAL) blocks can be read;
+	/// GDI formats like [`co::CF::BITMAP`](crate::co::CF::BITMAP) will fail.
+	/// Note that the block may be larger than the data written.
+	#[must_use]
+	pub fn read_bytes(&self, format: co::CF) -> SysResult<Option<Vec<u8>>> {
+		if !self.has_format(format) {
+			return Ok(None);
+		}
+		let hglobal = unsafe { HGLOBAL::from_ptr(GetClipboardData(format)? as _) };
+		let block = hglobal.GlobalLock()?;
+		Ok(Some(block.as_slice().to_vec()))
+	}
+
+	/// Returns the [`co::CF::DIB`](crate::co::CF::DIB) format, which is a
+	/// packed DIB: a `BITMAPINFOHEADER`, followed by the color table and the
+	/// pixels. It's the contents of a `.bmp` file without the
+	/// `BITMAPFILEHEADER`.
+	///
+	/// Returns `None` if the format is not available.
+	#[must_use]
+	pub fn read_dib(&self) -> SysResult<Option<Vec<u8>>> {
+		self.read_bytes(co::CF::DIB)
+	}
+
+	/// Returns the `HTML Format` format, or `None` if not available.
+	#[must_use]
+	pub fn read_html(&self) -> SysResult<Option<ClipboardHtml>> {
+		self.read_bytes(Self::html_format()?)?
+			.map(|data| ClipboardHtml::parse(&data))
+			.transpose()
+	}
+
+	/// Returns the [`co::CF::UNICODETEXT`](crate::co::CF::UNICODETEXT)
+	/// format, or `None` if not available.
+	#[must_use]
+	pub fn read_text(&self) -> SysResult<Option<String>> {
+		Ok(self.read_bytes(co::CF::UNICODETEXT)?
+			.map(|data| {
+				let wchars = data.chunks_exact(2)
+					.map(|b| u16::from_le_bytes([b[0], b[1]]))
+					.take_while(|ch| *ch != 0)
+					.collect::<Vec<_>>();
+				String::from_utf16_lossy(&wchars)
+			}))
+	}
+
+	/// Copies the data into a new [`HGLOBAL`](crate::HGLOBAL) block and
+	/// places it in the clipboard with
+	/// [`SetClipboardData`](crate::SetClipboardData).
+	pub fn write_bytes(&self, format: co::CF, data: &[u8]) -> SysResult<()> {
+		let mut hglobal = HGLOBAL::GlobalAlloc(
+			Some(co::GMEM::MOVEABLE), data.len().max(1))?;
+		{
+			let mut block = hglobal.GlobalLock()?;
+			block.as_mut_slice()[..data.len()].copy_from_slice(data);
+		}
+		unsafe { SetClipboardData(format, hglobal.ptr() as _)?; }
+		let _ = hglobal.leak(); // now owned by the system
+		Ok(())
+	}
+
+	/// Writes the [`co::CF::DIB`](crate::co::CF::DIB) format, which must be a
+	/// packed DIB, as returned by
+	/// [`Clipboard::read_dib`](crate::Clipboard::read_dib).
+	pub fn write_dib(&self, packed_dib: &[u8]) -> SysResult<()> {
+		let hdr_sz = packed_dib.get(..4)
+			.map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]) as usize)
+			.ok_or(co::ERROR::INVALID_DATA)?;
+		if hdr_sz < 40 || hdr_sz > packed_dib.len() { // at least a BITMAPINFOHEADER
+			return Err(co::ERROR::INVALID_DATA);
+		}
+		self.write_bytes(co::CF::DIB, packed_dib)
+	}
+
+	/// Writes the `HTML Format` format.
+	pub fn write_html(&self, html: &ClipboardHtml) -> SysResult<()> {
+		let mut data = html.as_bytes().to_vec();
+		data.push(0); // terminating null
+		self.write_bytes(Self::html_format()?, &data)
+	}
+
+	/// Writes the [`co::CF::UNICODETEXT`](crate::co::CF::UNICODETEXT)
+	/// format.
+	pub fn write_text(&self, text: &str) -> SysResult<()> {
+		let data = text.encode_utf16()
+			.chain(std::iter::once(0)) // terminating null
+			.flat_map(|ch| ch.to_le_bytes())
+			.collect::<Vec<_>>();
+		self.write_bytes(co::CF::UNICODETEXT, &data)
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// Contents of the
+/// [`HTML Format`](https://learn.microsoft.com/en-us/windows/win32/dataxchg/html-clipboard-format)
+/// clipboard format, which is UTF-8 text with a header of byte offsets to the
+/// HTML and to the selected fragment.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let html = w::ClipboardHtml::new("<i>hi</i>", Some("https://example.com"));
+/// println!("{}", html.html());
+/// assert_eq!(html.fragment(), "<i>hi</i>");
+/// ```
+#[derive(Clone, Debug)]
+pub struct ClipboardHtml {
+	data: String,
+	start_html: Option<usize>,
+	end_html: Option<usize>,
+	start_fragment: usize,
+	end_fragment: usize,
+	source_url: Option<String>,
+}
+
+impl ClipboardHtml {
+	const PREFIX: &'static str = "<html>\r\n<body>\r\n<!--StartFragment-->";
+	const SUFFIX: &'static str = "<!--EndFragment-->\r\n</body>\r\n</html>";
+
+	/// Builds the contents from an HTML fragment, which is wrapped in a
+	/// minimal document. The offsets are computed automatically.
+	#[must_use]
+	pub fn new(fragment: &str, source_url: Option<&str>) -> Self {
+		let header = |start_html, end_html, start_frag, end_frag| {
+			let mut h = format!(
+				"Version:0.9\r\nStartHTML:{:010}\r\nEndHTML:{:010}\r\nStartFragment:{:010}\r\nEndFragment:{:010}\r\n",
+				start_html, end_html, start_frag, end_frag,
+			);
+			if let Some(url) = source_url {
+				h.push_str(&format!("SourceURL:{}\r\n", url));
+			}
+			h
+		};
+
+		let start_html = header(0, 0, 0, 0).len(); // fixed-width offsets
+		let start_fragment = start_html + Self::PREFIX.len();
+		let end_fragment = start_fragment + fragment.len();
+		let end_html = end_fragment + Self::SUFFIX.len();
+
+		Self {
+			data: format!("{}{}{}{}",
+				header(start_html, end_html, start_fragment, end_fragment),
+				Self::PREFIX, fragment, Self::SUFFIX),
+			start_html: Some(start_html),
+			end_html: Some(end_html),
+			start_fragment,
+			end_fragment,
+			source_url: source_url.map(|s| s.to_owned()),
+		}
+	}
+
+	/// Decodes the raw clipboard data, validating the header offsets.
+	/// Trailing nulls are ignored.
+	#[must_use]
+	pub fn parse(data: &[u8]) -> SysResult<Self> {
+		let data = &data[..data.iter().rposition(|b| *b != 0).map_or(0, |p| p + 1)];
+		let data = std::str::from_utf8(data)
+			.map_err(|_| co::ERROR::INVALID_DATA)?;
+
+		let (mut start_html, mut end_html) = (None, None);
+		let (mut start_fragment, mut end_fragment) = (None, None);
+		let mut source_url = None;
+
+		for line in data.lines() {
+			let (key, val) = match line.split_once(':') {
+				Some((key, val)) if !key.starts_with('<')
+					&& key.chars().all(|ch| ch.is_ascii_alphanumeric()) => (key, val),
+				_ => break, // end of header
+			};
+			let offset = || -> SysResult<Option<usize>> {
+				match val.trim().parse::<i64>() {
+					Ok(-1) => Ok(None), // absent
+					Ok(off) => usize::try_from(off).map(Some)
+						.map_err(|_| co::ERROR::INVALID_DATA),
+					Err(_) => Err(co::ERROR::INVALID_DATA),
+				}
+			};
+			match key {
+				"StartHTML" => start_html = offset()?,
+				"EndHTML" => end_html = offset()?,
+				"StartFragment" => start_fragment = offset()?,
+				"EndFragment" => end_fragment = offset()?,
+				"SourceURL" => source_url = Some(val.to_owned()),
+				_ => {}, // Version, StartSelection, etc.
+			}
+		}
+
+		let start_fragment = start_fragment.ok_or(co::ERROR::INVALID_DATA)?;
+		let end_fragment = end_fragment.ok_or(co::ERROR::INVALID_DATA)?;
+		let new_self = Self {
+			data: data.to_owned(),
+			start_html,
+			end_html,
+			start_fragment,
+			end_fragment,
+			source_url,
+		};
+
+		if new_self.data.get(start_fragment..end_fragment).is_none()
+			|| new_self.start_html.zip(new_self.end_html)
+				.is_some_and(|(s, e)| new_self.data.get(s..e).is_none()
+					|| s > start_fragment || e < end_fragment)
+		{
+			return Err(co::ERROR::INVALID_DATA);
+		}
+		Ok(new_self)
+	}
+
+	/// Returns the raw data, including the header, without the terminating
+	/// null.
+	#[must_use]
+	pub fn as_bytes(&self) -> &[u8] {
+		self.data.as_bytes()
+	}
+
+	/// Returns the `EndFragment` byte offset.
+	#[must_use]
+	pub const fn end_fragment(&self) -> usize {
+		self.end_fragment
+	}
+
+	/// Returns the `EndHTML` byte offset, `None` if absent.
+	#[must_use]
+	pub const fn end_html(&self) -> Option<usize> {
+		self.end_html
+	}
+
+	/// Returns the selected HTML fragment.
+	#[must_use]
+	pub fn fragment(&self) -> &str {
+		&self.data[self.start_fragment..self.end_fragment]
+	}
+
+	/// Returns the whole HTML document. If the HTML offsets are absent,
+	/// returns the fragment.
+	#[must_use]
+	pub fn html(&self) -> &str {
+		match self.start_html.zip(self.end_html) {
+			Some((start, end)) => &self.data[start..end],
+			None => self.fragment(),
+		}
+	}
+
+	/// Returns the `SourceURL` header value, if any.
+	#[must_use]
+	pub fn source_url(&self) -> Option<&str> {
+		self.source_url.as_deref()
+	}
+
+	/// Returns the `StartFragment` byte offset.
+	#[must_use]
+	pub const fn start_fragment(&self) -> usize {
+		self.start_fragment
+	}
+
+	/// Returns the `StartHTML` byte offset, `None` if absent.
+	#[must_use]
+	pub const fn start_html(&self) -> Option<usize> {
+		self.start_html
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	/// Length of the header written by `html_data`.
+	const HDR: i64 = 105;
+
+	/// Builds CF_HTML data with the given header offsets, `-1` if absent.
+	fn html_data(start_html: i64, end_html: i64, start_frag: i64, end_frag: i64, body: &str) -> Vec<u8> {
+		format!(
+			"Version:0.9\r\nStartHTML:{:010}\r\nEndHTML:{:010}\r\nStartFragment:{:010}\r\nEndFragment:{:010}\r\n{}",
+			start_html, end_html, start_frag, end_frag, body,
+		).into_bytes()
+	}
+
+	#[test]
+	fn html_round_trip() {
+		for (fragment, url) in [
+			("<i>hi</i>", Some("https://example.com/a:b")),
+			("<b>ação ✓</b>", None),
+			("", None),
+		] {
+			let html = ClipboardHtml::new(fragment, url);
+			assert_eq!(html.fragment(), fragment);
+			assert_eq!(html.source_url(), url);
+			assert!(html.html().starts_with("<html>") && html.html().ends_with("</html>"));
+
+			let bytes = html.as_bytes();
+			let start_html = html.start_html().unwrap();
+			assert_eq!(&bytes[start_html..start_html + 6], b"<html>");
+			assert_eq!(html.end_html(), Some(bytes.len()));
+			assert_eq!(&bytes[html.start_fragment()..html.end_fragment()], fragment.as_bytes());
+
+			let mut with_null = bytes.to_vec();
+			with_null.extend_from_slice(&[0, 0]);
+			let parsed = ClipboardHtml::parse(&with_null).unwrap();
+			assert_eq!(parsed.as_bytes(), bytes);
+			assert_eq!(parsed.fragment(), fragment);
+			assert_eq!(parsed.html(), html.html());
+			assert_eq!(parsed.source_url(), url);
+			assert_eq!((parsed.start_html(), parsed.end_html()), (html.start_html(), html.end_html()));
+		}
+	}
+
+	#[test]
+	fn html_absent_offsets() {
+		let data = html_data(-1, -1, HDR + 3, HDR + 4, "<p>x</p>");
+		let html = ClipboardHtml::parse(&data).unwrap();
+		assert_eq!((html.start_html(), html.end_html()), (None, None));
+		assert_eq!(html.fragment(), "x");
+		assert_eq!(html.html(), "x"); // falls back to the fragment
+	}
+
+	#[test]
+	fn html_bad_offsets() {
+		let body = "<html><!--StartFragment-->é<!--EndFragment--></html>";
+		assert_eq!(html_data(0, 0, 0, 0, "").len() as i64, HDR);
+		let hdr = HDR;
+		let len = hdr + body.len() as i64;
+		let good = html_data(hdr, len, hdr + 26, hdr + 28, body);
+		assert_eq!(ClipboardHtml::parse(&good).unwrap().fragment(), "é");
+
+		for (start_html, end_html, start_frag, end_frag) in [
+			(hdr, len + 1, hdr + 26, hdr + 28), // EndHTML past the end
+			(hdr, len, hdr + 26, len + 100), // EndFragment past the end
+			(hdr, len, hdr + 28, hdr + 26), // fragment end before its start
+			(hdr, len, hdr + 27, hdr + 28), // in the middle of a UTF-8 char
+			(hdr + 27, len, hdr + 26, hdr + 28), // fragment before the HTML
+			(hdr, hdr + 27, hdr + 26, hdr + 28), // fragment after the HTML
+			(hdr, len, -2, hdr + 28), // negative
+		] {
+			let data = html_data(start_html, end_html, start_frag, end_frag, body);
+			assert_eq!(ClipboardHtml::parse(&data).err(), Some(co::ERROR::INVALID_DATA),
+				"{} {} {} {}", start_html, end_html, start_frag, end_frag);
+		}
+	}
+
+	#[test]
+	fn html_malformed_header() {
+		let body = "<p>x</p>";
+		for data in [
+			b"Version:0.9\r\nStartFragment:abc\r\nEndFragment:1\r\n<p>x</p>".to_vec(),
+			b"Version:0.9\r\nEndFragment:1\r\n<p>x</p>".to_vec(), // no StartFragment
+			b"<p>x</p>".to_vec(),
+			b"".to_vec(),
+			b"\0\0".to_vec(),
+			html_data(-1, -1, HDR, 99_999_999_999, body),
+			{ let mut d = html_data(-1, -1, HDR, HDR + 1, body); d.push(0xff); d }, // not UTF-8
+		] {
+			assert_eq!(ClipboardHtml::parse(&data).err(), Some(co::ERROR::INVALID_DATA));
+		}
+	}
+}
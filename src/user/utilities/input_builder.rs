@@ -0,0 +1,495 @@
+use crate::co;
+use crate::decl::*;
+
+#[derive(Clone, Copy)]
+enum Action {
+	Key { vkey_code: co::VK, up: bool },
+	Unicode { ch: u16, up: bool },
+	MoveTo(POINT),
+	Button { button: co::VK, up: bool },
+	Wheel { delta: i32, horizontal: bool },
+}
+
+/// Builds a sequence of keyboard and mouse events to be sent with
+/// [`SendInput`](crate::SendInput).
+///
+/// Mouse coordinates are absolute screen coordinates, which are normalized
+/// over the whole virtual desktop, so all monitors are reachable.
+///
+/// Key sequences can also be written in a small textual syntax, parsed by
+/// [`InputBuilder::keys`](crate::InputBuilder::keys).
+///
+/// # Examples
+///
+/// Opening the Task Manager, then typing in the focused window:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// w::InputBuilder::new()
+///     .keys("Ctrl+Shift+Esc")?
+///     .text("Olá, 世界 🦀")
+///     .key(co::VK::RETURN)
+///     .send()?;
+/// # Ok::<_, co::ERROR>(())
+/// ```
+///
+/// Dragging with the mouse:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// w::InputBuilder::new()
+///     .drag(co::VK::LBUTTON, w::POINT::new(100, 100), w::POINT::new(400, 300))
+///     .send()?;
+/// # Ok::<_, co::ERROR>(())
+/// ```
+#[derive(Clone, Default)]
+pub struct InputBuilder {
+	actions: Vec<Action>,
+}
+
+impl InputBuilder {
+	/// Creates a new, empty sequence.
+	#[must_use]
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Converts the sequence into the events to be sent with
+	/// [`SendInput`](crate::SendInput).
+	///
+	/// `virtual_screen` is the bounding rectangle of the virtual desktop,
+	/// used to normalize the mouse coordinates.
+	#[must_use]
+	pub fn build(&self, virtual_screen: RECT) -> Vec<HwKbMouse> {
+		let normalize = |v: i32, origin: i32, extent: i32| -> i32 {
+			let extent = (extent as i64 - 1).max(1);
+			(((v as i64 - origin as i64) * 65535 + extent / 2) / extent) as _
+		};
+
+		self.actions.iter()
+			.map(|action| match *action {
+				Action::Key { vkey_code, up } => HwKbMouse::Kb(KEYBDINPUT {
+					wVk: vkey_code,
+					dwFlags: if up { co::KEYEVENTF::KEYUP } else { co::KEYEVENTF::NoValue }
+						| if Self::is_extended(vkey_code) { co::KEYEVENTF::EXTENDEDKEY } else { co::KEYEVENTF::NoValue },
+					..Default::default()
+				}),
+				Action::Unicode { ch, up } => HwKbMouse::Kb(KEYBDINPUT {
+					wScan: ch,
+					dwFlags: co::KEYEVENTF::UNICODE
+						| if up { co::KEYEVENTF::KEYUP } else { co::KEYEVENTF::NoValue },
+					..Default::default()
+				}),
+				Action::MoveTo(pt) => HwKbMouse::Mouse(MOUSEINPUT {
+					dx: normalize(pt.x, virtual_screen.left, virtual_screen.right - virtual_screen.left),
+					dy: normalize(pt.y, virtual_screen.top, virtual_screen.bottom - virtual_screen.top),
+					dwFlags: co::MOUSEEVENTF::MOVE | co::MOUSEEVENTF::ABSOLUTE | co::MOUSEEVENTF::VIRTUALDESK,
+					..Default::default()
+				}),
+				Action::Button { button, up } => {
+					let (flags, mouse_data) = match (button, up) {
+						(co::VK::LBUTTON, false) => (co::MOUSEEVENTF::LEFTDOWN, 0),
+						(co::VK::LBUTTON, true) => (co::MOUSEEVENTF::LEFTUP, 0),
+						(co::VK::RBUTTON, false) => (co::MOUSEEVENTF::RIGHTDOWN, 0),
+						(co::VK::RBUTTON, true) => (co::MOUSEEVENTF::RIGHTUP, 0),
+						(co::VK::MBUTTON, false) => (co::MOUSEEVENTF::MIDDLEDOWN, 0),
+						(co::VK::MBUTTON, true) => (co::MOUSEEVENTF::MIDDLEUP, 0),
+						(co::VK::XBUTTON1, false) => (co::MOUSEEVENTF::XDOWN, 1),
+						(co::VK::XBUTTON1, true) => (co::MOUSEEVENTF::XUP, 1),
+						(co::VK::XBUTTON2, false) => (co::MOUSEEVENTF::XDOWN, 2),
+						(_, _) => (co::MOUSEEVENTF::XUP, 2), // validated when added
+					};
+					HwKbMouse::Mouse(MOUSEINPUT {
+						mouseData: mouse_data,
+						dwFlags: flags,
+						..Default::default()
+					})
+				},
+				Action::Wheel { delta, horizontal } => HwKbMouse::Mouse(MOUSEINPUT {
+					mouseData: delta as _,
+					dwFlags: if horizontal { co::MOUSEEVENTF::HWHEEL } else { co::MOUSEEVENTF::WHEEL },
+					..Default::default()
+				}),
+			})
+			.collect()
+	}
+
+	/// Presses and releases all keys at once, like Ctrl+Shift+Esc: they are
+	/// pressed in the given order, and released in the reverse order.
+	pub fn chord(&mut self, vkey_codes: &[co::VK]) -> &mut Self {
+		for vkey_code in vkey_codes.iter() {
+			self.key_down(*vkey_code);
+		}
+		for vkey_code in vkey_codes.iter().rev() {
+			self.key_up(*vkey_code);
+		}
+		self
+	}
+
+	/// Moves the mouse to the given point, then presses and releases the
+	/// button, which must be one of
+	/// [`co::VK::LBUTTON`](crate::co::VK::LBUTTON),
+	/// [`co::VK::RBUTTON`](crate::co::VK::RBUTTON),
+	/// [`co::VK::MBUTTON`](crate::co::VK::MBUTTON),
+	/// [`co::VK::XBUTTON1`](crate::co::VK::XBUTTON1) or
+	/// [`co::VK::XBUTTON2`](crate::co::VK::XBUTTON2).
+	///
+	/// # Panics
+	///
+	/// Panics if `button` is not a mouse button.
+	pub fn click(&mut self, button: co::VK, pt: POINT) -> &mut Self {
+		self.move_to(pt)
+			.button(button, false)
+			.button(button, true)
+	}
+
+	/// Moves the mouse to `from`, presses the button, moves to `to` and
+	/// releases the button.
+	///
+	/// # Panics
+	///
+	/// Panics if `button` is not a mouse button.
+	pub fn drag(&mut self, button: co::VK, from: POINT, to: POINT) -> &mut Self {
+		self.move_to(from)
+			.button(button, false)
+			.move_to(to)
+			.button(button, true)
+	}
+
+	/// Presses and releases a key.
+	pub fn key(&mut self, vkey_code: co::VK) -> &mut Self {
+		self.key_down(vkey_code).key_up(vkey_code)
+	}
+
+	/// Presses a key.
+	pub fn key_down(&mut self, vkey_code: co::VK) -> &mut Self {
+		self.actions.push(Action::Key { vkey_code, up: false });
+		self
+	}
+
+	/// Releases a key.
+	pub fn key_up(&mut self, vkey_code: co::VK) -> &mut Self {
+		self.actions.push(Action::Key { vkey_code, up: true });
+		self
+	}
+
+	/// Parses a sequence of keys and appends it.
+	///
+	/// The items are separated by spaces, and each item can be:
+	///
+	/// * a key name, like `Enter`, `F5`, `A` or `PgDn`, case-insensitive;
+	/// * a chord of key names joined by `+`, like `Ctrl+Shift+Esc`;
+	/// * a text between double quotes, like `"Hello, world"`, typed as
+	///   Unicode characters, where `\"` and `\\` are escapes.
+	///
+	/// Returns
+	/// [`co::ERROR::INVALID_PARAMETER`](crate::co::ERROR::INVALID_PARAMETER)
+	/// if the sequence is malformed or a key name is unknown, in which case
+	/// nothing is appended.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// w::InputBuilder::new()
+	///     .keys(r#"Win+R "notepad" Enter"#)?
+	///     .send()?;
+	/// # Ok::<_, winsafe::co::ERROR>(())
+	/// ```
+	pub fn keys(&mut self, sequence: &str) -> SysResult<&mut Self> {
+		let mut parsed = Self::new();
+		let mut chars = sequence.chars().peekable();
+
+		while let Some(ch) = chars.peek().copied() {
+			if ch.is_whitespace() {
+				chars.next();
+			} else if ch == '"' {
+				chars.next();
+				let mut text = String::default();
+				loop {
+					match chars.next() {
+						Some('"') => break,
+						Some('\\') => match chars.next() {
+							Some(esc @ ('"' | '\\')) => text.push(esc),
+							_ => return Err(co::ERROR::INVALID_PARAMETER),
+						},
+						Some(ch) => text.push(ch),
+						None => return Err(co::ERROR::INVALID_PARAMETER), // unterminated
+					}
+				}
+				parsed.text(&text);
+			} else {
+				let mut item = String::default();
+				while let Some(ch) = chars.peek().copied() {
+					if ch.is_whitespace() || ch == '"' {
+						break;
+					}
+					item.push(ch);
+					chars.next();
+				}
+				let vkey_codes = item.split('+')
+					.map(Self::vk_from_name)
+					.collect::<Option<Vec<_>>>()
+					.ok_or(co::ERROR::INVALID_PARAMETER)?;
+				parsed.chord(&vkey_codes);
+			}
+		}
+
+		self.actions.extend_from_slice(&parsed.actions);
+		Ok(self)
+	}
+
+	/// Moves the mouse to the given point, in screen coordinates.
+	pub fn move_to(&mut self, pt: POINT) -> &mut Self {
+		self.actions.push(Action::MoveTo(pt));
+		self
+	}
+
+	/// Moves the mouse to the given point, then rotates the vertical wheel.
+	/// A positive `delta` scrolls up; one notch is `120`.
+	pub fn scroll(&mut self, pt: POINT, delta: i32) -> &mut Self {
+		self.move_to(pt);
+		self.actions.push(Action::Wheel { delta, horizontal: false });
+		self
+	}
+
+	/// Moves the mouse to the given point, then rotates the horizontal wheel.
+	/// A positive `delta` scrolls right; one notch is `120`.
+	pub fn scroll_horz(&mut self, pt: POINT, delta: i32) -> &mut Self {
+		self.move_to(pt);
+		self.actions.push(Action::Wheel { delta, horizontal: true });
+		self
+	}
+
+	/// Sends the sequence with [`SendInput`](crate::SendInput), normalizing
+	/// the mouse coordinates over the current virtual desktop.
+	///
+	/// Returns the number of events actually inserted, which can be less
+	/// than expected if the input was blocked by another thread or by
+	/// [UIPI](https://learn.microsoft.com/en-us/windows/win32/winauto/uiauto-securityoverview).
+	pub fn send(&self) -> SysResult<u32> {
+		let x = GetSystemMetrics(co::SM::XVIRTUALSCREEN);
+		let y = GetSystemMetrics(co::SM::YVIRTUALSCREEN);
+		let virtual_screen = RECT {
+			left: x,
+			top: y,
+			right: x + GetSystemMetrics(co::SM::CXVIRTUALSCREEN),
+			bottom: y + GetSystemMetrics(co::SM::CYVIRTUALSCREEN),
+		};
+		SendInput(&self.build(virtual_screen))
+	}
+
+	/// Types the text as Unicode characters, so it doesn't depend on the
+	/// keyboard layout. Characters outside the BMP are sent as surrogate
+	/// pairs. Line breaks and tabs are sent as
+	/// [`co::VK::RETURN`](crate::co::VK::RETURN) and
+	/// [`co::VK::TAB`](crate::co::VK::TAB) keys.
+	pub fn text(&mut self, text: &str) -> &mut Self {
+		for ch in text.chars() {
+			match ch {
+				'\r' => {}, // CRLF yields a single RETURN
+				'\n' => { self.key(co::VK::RETURN); },
+				'\t' => { self.key(co::VK::TAB); },
+				ch => {
+					let mut buf = [0u16; 2];
+					let units = ch.encode_utf16(&mut buf);
+					for unit in units.iter() { // all the down events go first
+						self.actions.push(Action::Unicode { ch: *unit, up: false });
+					}
+					for unit in units.iter() {
+						self.actions.push(Action::Unicode { ch: *unit, up: true });
+					}
+				},
+			}
+		}
+		self
+	}
+
+	fn button(&mut self, button: co::VK, up: bool) -> &mut Self {
+		match button {
+			co::VK::LBUTTON | co::VK::RBUTTON | co::VK::MBUTTON
+				| co::VK::XBUTTON1 | co::VK::XBUTTON2 => {},
+			_ => panic!("Not a mouse button: {}.", button),
+		}
+		self.actions.push(Action::Button { button, up });
+		self
+	}
+
+	const fn is_extended(vkey_code: co::VK) -> bool {
+		matches!(vkey_code,
+			co::VK::INSERT | co::VK::DELETE | co::VK::HOME | co::VK::END
+			| co::VK::PRIOR | co::VK::NEXT
+			| co::VK::LEFT | co::VK::UP | co::VK::RIGHT | co::VK::DOWN
+			| co::VK::RCONTROL | co::VK::RMENU | co::VK::LWIN | co::VK::RWIN
+			| co::VK::APPS | co::VK::DIVIDE | co::VK::NUMLOCK | co::VK::SNAPSHOT)
+	}
+
+	fn vk_from_name(name: &str) -> Option<co::VK> {
+		let upper = name.to_ascii_uppercase();
+		let mut chars = upper.chars();
+		if let (Some(ch), None) = (chars.next(), chars.next()) {
+			if ch.is_ascii_uppercase() || ch.is_ascii_digit() {
+				return Some(unsafe { co::VK::from_raw(ch as _) }); // VK_A to VK_Z, VK_0 to VK_9
+			}
+		}
+		if let Some(num) = upper.strip_prefix('F').and_then(|n| n.parse::<u16>().ok()) {
+			return if (1..=24).contains(&num) {
+				Some(unsafe { co::VK::from_raw(co::VK::F1.raw() + num - 1) })
+			} else {
+				None
+			};
+		}
+
+		Some(match upper.as_str() {
+			"CTRL" | "CONTROL" => co::VK::CONTROL,
+			"SHIFT" => co::VK::SHIFT,
+			"ALT" => co::VK::MENU,
+			"WIN" => co::VK::LWIN,
+			"ESC" | "ESCAPE" => co::VK::ESCAPE,
+			"ENTER" | "RETURN" => co::VK::RETURN,
+			"TAB" => co::VK::TAB,
+			"SPACE" => co::VK::SPACE,
+			"BACKSPACE" | "BKSP" => co::VK::BACK,
+			"DEL" | "DELETE" => co::VK::DELETE,
+			"INS" | "INSERT" => co::VK::INSERT,
+			"HOME" => co::VK::HOME,
+			"END" => co::VK::END,
+			"PGUP" | "PAGEUP" => co::VK::PRIOR,
+			"PGDN" | "PAGEDOWN" => co::VK::NEXT,
+			"UP" => co::VK::UP,
+			"DOWN" => co::VK::DOWN,
+			"LEFT" => co::VK::LEFT,
+			"RIGHT" => co::VK::RIGHT,
+			"CAPSLOCK" => co::VK::CAPITAL,
+			"NUMLOCK" => co::VK::NUMLOCK,
+			"PRTSC" | "PRINTSCREEN" => co::VK::SNAPSHOT,
+			"PAUSE" => co::VK::PAUSE,
+			"APPS" => co::VK::APPS,
+			_ => return None,
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const SCREEN: RECT = RECT { left: 0, top: 0, right: 1921, bottom: 1081 };
+
+	/// Keyboard events as `(vk, scan, flags)` tuples.
+	fn kb_events(b: &InputBuilder) -> Vec<(co::VK, u16, co::KEYEVENTF)> {
+		b.build(SCREEN).iter()
+			.map(|ev| match ev {
+				HwKbMouse::Kb(kb) => (kb.wVk, kb.wScan, kb.dwFlags),
+				_ => panic!("Not a keyboard event."),
+			})
+			.collect()
+	}
+
+	fn down(vk: co::VK) -> (co::VK, u16, co::KEYEVENTF) {
+		(vk, 0, co::KEYEVENTF::NoValue)
+	}
+
+	fn up(vk: co::VK) -> (co::VK, u16, co::KEYEVENTF) {
+		(vk, 0, co::KEYEVENTF::KEYUP)
+	}
+
+	fn uni(ch: u16, is_up: bool) -> (co::VK, u16, co::KEYEVENTF) {
+		(co::VK::NoValue, ch, co::KEYEVENTF::UNICODE
+			| if is_up { co::KEYEVENTF::KEYUP } else { co::KEYEVENTF::NoValue })
+	}
+
+	#[test]
+	fn keys_modifiers() {
+		let mut b = InputBuilder::new();
+		b.keys("ctrl+Shift+ESC").unwrap();
+		assert_eq!(kb_events(&b), [
+			down(co::VK::CONTROL), down(co::VK::SHIFT), down(co::VK::ESCAPE),
+			up(co::VK::ESCAPE), up(co::VK::SHIFT), up(co::VK::CONTROL),
+		]);
+	}
+
+	#[test]
+	fn keys_names() {
+		let mut b = InputBuilder::new();
+		b.keys("a 7 F12 Enter").unwrap();
+		assert_eq!(kb_events(&b), [
+			down(co::VK::CHAR_A), up(co::VK::CHAR_A),
+			down(co::VK::CHAR_7), up(co::VK::CHAR_7),
+			down(co::VK::F12), up(co::VK::F12),
+			down(co::VK::RETURN), up(co::VK::RETURN),
+		]);
+	}
+
+	#[test]
+	fn keys_extended() {
+		let mut b = InputBuilder::new();
+		b.keys("Win+Left").unwrap();
+		let ext = co::KEYEVENTF::EXTENDEDKEY;
+		assert_eq!(kb_events(&b), [
+			(co::VK::LWIN, 0, ext), (co::VK::LEFT, 0, ext),
+			(co::VK::LEFT, 0, ext | co::KEYEVENTF::KEYUP),
+			(co::VK::LWIN, 0, ext | co::KEYEVENTF::KEYUP),
+		]);
+	}
+
+	#[test]
+	fn keys_quoted_text() {
+		let mut b = InputBuilder::new();
+		b.keys(r#"Tab "a \"b\" \\" Tab"#).unwrap();
+		let mut expected = vec![down(co::VK::TAB), up(co::VK::TAB)];
+		for ch in "a \"b\" \\".encode_utf16() {
+			expected.push(uni(ch, false));
+			expected.push(uni(ch, true));
+		}
+		expected.extend_from_slice(&[down(co::VK::TAB), up(co::VK::TAB)]);
+		assert_eq!(kb_events(&b), expected);
+	}
+
+	#[test]
+	fn keys_invalid() {
+		for seq in ["Ctrl+Nope", "F25", "F0", "Ctrl+", r#""unterminated"#, r#""bad \n""#] {
+			let mut b = InputBuilder::new();
+			b.key(co::VK::TAB);
+			assert!(b.keys(seq).is_err(), "{}", seq);
+			assert_eq!(kb_events(&b).len(), 2, "{}", seq); // nothing appended
+		}
+	}
+
+	#[test]
+	fn text_surrogates_and_newlines() {
+		let mut b = InputBuilder::new();
+		b.text("🦀\r\n");
+		assert_eq!(kb_events(&b), [
+			uni(0xd83e, false), uni(0xdd80, false), uni(0xd83e, true), uni(0xdd80, true),
+			down(co::VK::RETURN), up(co::VK::RETURN),
+		]);
+	}
+
+	#[test]
+	fn mouse_click() {
+		let evs = InputBuilder::new()
+			.click(co::VK::XBUTTON2, POINT::new(1920, 540))
+			.build(SCREEN);
+		let mouse = evs.iter()
+			.map(|ev| match ev {
+				HwKbMouse::Mouse(m) => (m.dx, m.dy, m.mouseData, m.dwFlags),
+				_ => panic!("Not a mouse event."),
+			})
+			.collect::<Vec<_>>();
+		assert_eq!(mouse, [
+			(65535, 32768, 0, co::MOUSEEVENTF::MOVE | co::MOUSEEVENTF::ABSOLUTE | co::MOUSEEVENTF::VIRTUALDESK),
+			(0, 0, 2, co::MOUSEEVENTF::XDOWN),
+			(0, 0, 2, co::MOUSEEVENTF::XUP),
+		]);
+	}
+
+	#[test]
+	#[should_panic]
+	fn mouse_bad_button() {
+		InputBuilder::new().click(co::VK::RETURN, POINT::default());
+	}
+}
@@ -0,0 +1,11 @@
+mod clipboard;
+mod input_builder;
+mod raw_input;
+mod resource_templates;
+mod windows_hook;
+
+pub use clipboard::{Clipboard, ClipboardHtml};
+pub use input_builder::InputBuilder;
+pub use raw_input::{RawHid, RawInput, RawInputData};
+pub use resource_templates::{ResDialog, ResDialogFont, ResDialogItem, ResMenu, ResMenuItem};
+pub use windows_hook::WindowsHook;
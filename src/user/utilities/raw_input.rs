@@ -0,0 +1,221 @@
+use crate::co;
+use crate::decl::*;
+
+/// A decoded
+/// [`RAWINPUT`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-rawinput)
+/// record, returned by
+/// [`HRAWINPUT::GetRawInputData`](crate::prelude::user_Hrawinput::GetRawInputData).
+pub struct RawInput {
+	/// Header of the record, with the device handle. Its `wParam` field holds
+	/// the same [`co::RIM`](crate::co::RIM) code of
+	/// [`wm::Input`](crate::msg::wm::Input).
+	pub header: RAWINPUTHEADER,
+	/// Data of the record, according to the device type.
+	pub data: RawInputData,
+}
+
+/// Data of a [`RawInput`](crate::RawInput) record.
+pub enum RawInputData {
+	/// Keyboard data.
+	Keyboard(RAWKEYBOARD),
+	/// Mouse data.
+	Mouse(RAWMOUSE),
+	/// HID data.
+	Hid(RawHid),
+}
+
+/// Data of a HID device, which corresponds to the variable-length
+/// [`RAWHID`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-rawhid)
+/// struct.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RawHid {
+	/// Size of each HID report, in bytes.
+	pub size_hid: u32,
+	/// Number of HID reports.
+	pub count: u32,
+	/// The contents of all reports, one after the other.
+	pub raw_data: Vec<u8>,
+}
+
+impl RawHid {
+	/// Returns an iterator over the HID reports.
+	#[must_use]
+	pub fn reports(&self) -> impl Iterator<Item = &[u8]> {
+		self.raw_data.chunks_exact(self.size_hid.max(1) as _)
+	}
+}
+
+impl RawInput {
+	/// Decodes a raw `RAWINPUT` block, as written by
+	/// [`GetRawInputData`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getrawinputdata).
+	///
+	/// The header must have the native pointer size of the current process.
+	/// Bytes past the size declared in the header are ignored.
+	#[must_use]
+	pub fn parse(data: &[u8]) -> SysResult<Self> {
+		let header = unsafe { Self::read_struct::<RAWINPUTHEADER>(data, 0)? };
+		let off_data = std::mem::size_of::<RAWINPUTHEADER>();
+		let data = match header.dwSize as usize {
+			sz if sz < off_data => return Err(co::ERROR::INVALID_DATA),
+			sz => data.get(..sz).ok_or(co::ERROR::INVALID_DATA)?, // declared size can't exceed the buffer
+		};
+
+		let data = match header.dwType {
+			co::RIM_TYPE::KEYBOARD => RawInputData::Keyboard(
+				unsafe { Self::read_struct::<RAWKEYBOARD>(data, off_data)? }),
+			co::RIM_TYPE::MOUSE => RawInputData::Mouse(
+				unsafe { Self::read_struct::<RAWMOUSE>(data, off_data)? }),
+			co::RIM_TYPE::HID => {
+				let size_hid = unsafe { Self::read_struct::<u32>(data, off_data)? };
+				let count = unsafe { Self::read_struct::<u32>(data, off_data + 4)? };
+				let raw_data = (size_hid as usize).checked_mul(count as _)
+					.and_then(|sz| sz.checked_add(off_data + 8))
+					.and_then(|end| data.get(off_data + 8..end))
+					.ok_or(co::ERROR::INVALID_DATA)?;
+				RawInputData::Hid(RawHid { size_hid, count, raw_data: raw_data.to_vec() })
+			},
+			_ => return Err(co::ERROR::INVALID_DATA),
+		};
+
+		Ok(Self { header, data })
+	}
+
+	/// Bounds-checked unaligned read of a struct.
+	///
+	/// # Safety
+	///
+	/// `T` must be valid for any bit pattern.
+	unsafe fn read_struct<T>(data: &[u8], offset: usize) -> SysResult<T> {
+		offset.checked_add(std::mem::size_of::<T>())
+			.and_then(|end| data.get(offset..end))
+			.map(|chunk| std::ptr::read_unaligned(chunk.as_ptr() as *const T))
+			.ok_or(co::ERROR::INVALID_DATA)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const HDR: usize = std::mem::size_of::<RAWINPUTHEADER>();
+
+	fn input(kind: co::RIM_TYPE, body: &[u8]) -> Vec<u8> {
+		let mut data = Vec::<u8>::default();
+		data.extend_from_slice(&kind.raw().to_le_bytes());
+		data.extend_from_slice(&((HDR + body.len()) as u32).to_le_bytes());
+		data.extend_from_slice(&0x1234usize.to_le_bytes()); // hDevice
+		data.extend_from_slice(&(co::RIM::INPUT.raw() as usize).to_le_bytes()); // wParam
+		data.extend_from_slice(body);
+		data
+	}
+
+	fn hid(size_hid: u32, count: u32, reports: &[u8]) -> Vec<u8> {
+		let mut body = Vec::<u8>::default();
+		body.extend_from_slice(&size_hid.to_le_bytes());
+		body.extend_from_slice(&count.to_le_bytes());
+		body.extend_from_slice(reports);
+		input(co::RIM_TYPE::HID, &body)
+	}
+
+	fn set_size(data: &mut [u8], size: u32) {
+		data[4..8].copy_from_slice(&size.to_le_bytes());
+	}
+
+	#[test]
+	fn keyboard() {
+		let mut body = Vec::<u8>::default();
+		body.extend_from_slice(&0x1eu16.to_le_bytes()); // MakeCode
+		body.extend_from_slice(&0u16.to_le_bytes()); // Flags
+		body.extend_from_slice(&0u16.to_le_bytes()); // Reserved
+		body.extend_from_slice(&0x41u16.to_le_bytes()); // VKey
+		body.extend_from_slice(&co::WM::KEYDOWN.raw().to_le_bytes());
+		body.extend_from_slice(&0u32.to_le_bytes()); // ExtraInformation
+
+		let ri = RawInput::parse(&input(co::RIM_TYPE::KEYBOARD, &body)).unwrap();
+		assert_eq!(ri.header.dwType, co::RIM_TYPE::KEYBOARD);
+		assert_eq!(ri.header.dwSize as usize, HDR + 16);
+		match ri.data {
+			RawInputData::Keyboard(kb) => {
+				assert_eq!(kb.MakeCode, 0x1e);
+				assert_eq!(kb.VKey.raw(), 0x41);
+				assert_eq!(kb.Message, co::WM::KEYDOWN);
+			},
+			_ => panic!("not keyboard"),
+		}
+	}
+
+	#[test]
+	fn mouse() {
+		let mut body = vec![0u8; 24];
+		body[12..16].copy_from_slice(&(-5i32).to_le_bytes()); // lLastX
+		body[16..20].copy_from_slice(&7i32.to_le_bytes()); // lLastY
+
+		match RawInput::parse(&input(co::RIM_TYPE::MOUSE, &body)).unwrap().data {
+			RawInputData::Mouse(m) => assert_eq!((m.lLastX, m.lLastY), (-5, 7)),
+			_ => panic!("not mouse"),
+		}
+	}
+
+	#[test]
+	fn hid_reports() {
+		let mut data = hid(3, 2, &[1, 2, 3, 4, 5, 6]);
+		data.extend_from_slice(&[0xff; 4]); // past the declared size, ignored
+
+		match RawInput::parse(&data).unwrap().data {
+			RawInputData::Hid(h) => {
+				assert_eq!((h.size_hid, h.count), (3, 2));
+				assert_eq!(h.reports().collect::<Vec<_>>(), [&[1, 2, 3][..], &[4, 5, 6][..]]);
+			},
+			_ => panic!("not HID"),
+		}
+
+		match RawInput::parse(&hid(0, 0, &[])).unwrap().data {
+			RawInputData::Hid(h) => assert_eq!(h.reports().count(), 0),
+			_ => panic!("not HID"),
+		}
+	}
+
+	#[test]
+	fn truncated() {
+		let data = input(co::RIM_TYPE::MOUSE, &[0; 24]);
+		assert_eq!(RawInput::parse(&[]).err(), Some(co::ERROR::INVALID_DATA));
+		assert_eq!(RawInput::parse(&data[..HDR - 1]).err(), Some(co::ERROR::INVALID_DATA));
+		assert_eq!(RawInput::parse(&data[..data.len() - 1]).err(), Some(co::ERROR::INVALID_DATA));
+
+		let data = hid(4, 2, &[0; 7]); // one byte short
+		assert_eq!(RawInput::parse(&data).err(), Some(co::ERROR::INVALID_DATA));
+		assert_eq!(RawInput::parse(&data[..HDR + 6]).err(), Some(co::ERROR::INVALID_DATA)); // no count
+	}
+
+	#[test]
+	fn bad_header_size() {
+		let mut data = input(co::RIM_TYPE::MOUSE, &[0; 24]);
+		let len = data.len() as u32;
+		set_size(&mut data, len + 1); // larger than the buffer
+		assert_eq!(RawInput::parse(&data).err(), Some(co::ERROR::INVALID_DATA));
+		set_size(&mut data, u32::MAX);
+		assert_eq!(RawInput::parse(&data).err(), Some(co::ERROR::INVALID_DATA));
+		set_size(&mut data, HDR as u32 - 1); // smaller than the header
+		assert_eq!(RawInput::parse(&data).err(), Some(co::ERROR::INVALID_DATA));
+		set_size(&mut data, HDR as u32 + 8); // cuts the mouse data
+		assert_eq!(RawInput::parse(&data).err(), Some(co::ERROR::INVALID_DATA));
+
+		let mut data = hid(2, 2, &[1, 2, 3, 4]);
+		set_size(&mut data, HDR as u32 + 8 + 2); // cuts the second report
+		assert_eq!(RawInput::parse(&data).err(), Some(co::ERROR::INVALID_DATA));
+	}
+
+	#[test]
+	fn oversized_hid_count() {
+		let data = hid(u32::MAX, u32::MAX, &[0; 16]);
+		assert_eq!(RawInput::parse(&data).err(), Some(co::ERROR::INVALID_DATA));
+		let data = hid(1, u32::MAX, &[0; 16]);
+		assert_eq!(RawInput::parse(&data).err(), Some(co::ERROR::INVALID_DATA));
+	}
+
+	#[test]
+	fn unknown_type() {
+		let data = input(unsafe { co::RIM_TYPE::from_raw(7) }, &[0; 24]);
+		assert_eq!(RawInput::parse(&data).err(), Some(co::ERROR::INVALID_DATA));
+	}
+}
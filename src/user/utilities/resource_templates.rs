@@ -0,0 +1,523 @@
+use crate::co;
+use crate::decl::*;
+use crate::kernel::privs::*;
+
+const DS_SETFONT: u32 = 0x40;
+
+/// A decoded
+/// [`RT_DIALOG`](https://learn.microsoft.com/en-us/windows/win32/dlgbox/dialog-box-templates)
+/// resource, either in the standard
+/// [`DLGTEMPLATE`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-dlgtemplate)
+/// format or in the extended
+/// [`DLGTEMPLATEEX`](https://learn.microsoft.com/en-us/windows/win32/dlgbox/dlgtemplateex)
+/// one.
+///
+/// Positions and sizes are in dialog units.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let hexe = w::HINSTANCE::LoadLibrary("hand.exe")?;
+/// let dlg = hexe.load_dialog_template(w::IdStr::Id(103), None)?;
+///
+/// println!("{} ({}x{})", dlg.title, dlg.size.cx, dlg.size.cy);
+/// for item in dlg.items.iter() {
+///     println!("  {} {} \"{}\"", item.id, item.class_name(),
+///         item.title.as_ref().map(|t| t.to_string()).unwrap_or_default());
+/// }
+/// # Ok::<_, winsafe::co::ERROR>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct ResDialog {
+	/// Whether the template is a `DLGTEMPLATEEX`.
+	pub is_extended: bool,
+	/// Help context identifier. Always zero in standard templates.
+	pub help_id: u32,
+	/// Window styles, which also carry the `DS_*` dialog styles.
+	pub style: co::WS,
+	/// Extended window styles.
+	pub ex_style: co::WS_EX,
+	/// Position of the upper-left corner.
+	pub pos: POINT,
+	/// Width and height.
+	pub size: SIZE,
+	/// Menu resource, if any.
+	pub menu: Option<IdStr>,
+	/// Window class, if not the default dialog class.
+	pub class: Option<IdStr>,
+	/// Title of the dialog.
+	pub title: String,
+	/// Font, present if `DS_SETFONT` or `DS_SHELLFONT` are set.
+	pub font: Option<ResDialogFont>,
+	/// The controls.
+	pub items: Vec<ResDialogItem>,
+}
+
+/// Font of a [`ResDialog`](crate::ResDialog).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ResDialogFont {
+	/// Size in points.
+	pub point_size: u16,
+	/// Weight. Always zero in standard templates.
+	pub weight: u16,
+	/// Whether the font is italic. Always false in standard templates.
+	pub italic: bool,
+	/// Character set. Always zero in standard templates.
+	pub char_set: u8,
+	/// Typeface name.
+	pub face: String,
+}
+
+/// A control of a [`ResDialog`](crate::ResDialog), which corresponds to a
+/// [`DLGITEMTEMPLATE`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-dlgitemtemplate)
+/// or a
+/// [`DLGITEMTEMPLATEEX`](https://learn.microsoft.com/en-us/windows/win32/dlgbox/dlgitemtemplateex)
+/// structure.
+#[derive(Clone, Debug)]
+pub struct ResDialogItem {
+	/// Help context identifier. Always zero in standard templates.
+	pub help_id: u32,
+	/// Window styles, which also carry the control-specific styles.
+	pub style: co::WS,
+	/// Extended window styles.
+	pub ex_style: co::WS_EX,
+	/// Position of the upper-left corner.
+	pub pos: POINT,
+	/// Width and height.
+	pub size: SIZE,
+	/// Control ID. Standard templates have 16-bit IDs.
+	pub id: u32,
+	/// Window class, either a name or the atom of a predefined class.
+	pub class: IdStr,
+	/// Text of the control, or the ID of a resource, like an icon.
+	pub title: Option<IdStr>,
+	/// Raw data passed to the control in `WM_CREATE`.
+	pub creation_data: Vec<u8>,
+}
+
+impl ResDialogItem {
+	/// Returns the window class name, translating the atoms of the predefined
+	/// classes – like `0x0080` to `Button`.
+	#[must_use]
+	pub fn class_name(&self) -> String {
+		match &self.class {
+			IdStr::Id(0x0080) => "Button".to_owned(),
+			IdStr::Id(0x0081) => "Edit".to_owned(),
+			IdStr::Id(0x0082) => "Static".to_owned(),
+			IdStr::Id(0x0083) => "ListBox".to_owned(),
+			IdStr::Id(0x0084) => "ScrollBar".to_owned(),
+			IdStr::Id(0x0085) => "ComboBox".to_owned(),
+			IdStr::Id(atom) => format!("#{}", atom),
+			IdStr::Str(name) => name.to_string(),
+		}
+	}
+}
+
+impl ResDialog {
+	/// Decodes the raw data of the resource.
+	#[must_use]
+	pub fn parse(data: &[u8]) -> SysResult<Self> {
+		let mut rd = ByteReader::new(data);
+		let is_extended = data.len() >= 4
+			&& data[0..2] == [1, 0] // dlgVer
+			&& data[2..4] == [0xff, 0xff]; // signature
+
+		let (help_id, ex_style, style) = if is_extended {
+			rd.skip(4)?;
+			(rd.u32()?, rd.u32()?, rd.u32()?)
+		} else {
+			let style = rd.u32()?;
+			(0, rd.u32()?, style)
+		};
+		let num_items = rd.u16()?;
+		let (pos, size) = Self::parse_rect(&mut rd)?;
+		let menu = rd.sz_or_ord()?;
+		let class = rd.sz_or_ord()?;
+		let title = rd.utf16_nullt()?;
+
+		let font = if style & DS_SETFONT != 0 {
+			let point_size = rd.u16()?;
+			let (weight, italic, char_set) = if is_extended {
+				(rd.u16()?, rd.u8()? != 0, rd.u8()?)
+			} else {
+				(0, false, 0)
+			};
+			Some(ResDialogFont { point_size, weight, italic, char_set, face: rd.utf16_nullt()? })
+		} else {
+			None
+		};
+
+		let items = (0..num_items)
+			.map(|_| Self::parse_item(&mut rd, is_extended))
+			.collect::<SysResult<Vec<_>>>()?;
+
+		Ok(Self {
+			is_extended,
+			help_id,
+			style: unsafe { co::WS::from_raw(style) },
+			ex_style: unsafe { co::WS_EX::from_raw(ex_style) },
+			pos,
+			size,
+			menu,
+			class,
+			title,
+			font,
+			items,
+		})
+	}
+
+	fn parse_rect(rd: &mut ByteReader) -> SysResult<(POINT, SIZE)> {
+		let pos = POINT::new(rd.i16()? as _, rd.i16()? as _);
+		let size = SIZE::new(rd.i16()? as _, rd.i16()? as _);
+		Ok((pos, size))
+	}
+
+	fn parse_item(rd: &mut ByteReader, is_extended: bool) -> SysResult<ResDialogItem> {
+		rd.align(4)?;
+		let (help_id, ex_style, style) = if is_extended {
+			(rd.u32()?, rd.u32()?, rd.u32()?)
+		} else {
+			let style = rd.u32()?;
+			(0, rd.u32()?, style)
+		};
+		let (pos, size) = Self::parse_rect(rd)?;
+		let id = if is_extended { rd.u32()? } else { rd.u16()? as _ };
+		let class = rd.sz_or_ord()?.ok_or(co::ERROR::INVALID_DATA)?;
+		let title = rd.sz_or_ord()?;
+		let num_extra = rd.u16()?;
+		let creation_data = rd.bytes(num_extra as _)?.to_vec();
+
+		Ok(ResDialogItem {
+			help_id,
+			style: unsafe { co::WS::from_raw(style) },
+			ex_style: unsafe { co::WS_EX::from_raw(ex_style) },
+			pos,
+			size,
+			id,
+			class,
+			title,
+			creation_data,
+		})
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// A decoded
+/// [`RT_MENU`](https://learn.microsoft.com/en-us/windows/win32/menurc/menu-template-resources)
+/// resource, either in the standard
+/// [`MENUITEMTEMPLATE`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-menuitemtemplate)
+/// format or in the extended
+/// [`MENUEX_TEMPLATE_ITEM`](https://learn.microsoft.com/en-us/windows/win32/menurc/menuex-template-item)
+/// one.
+///
+/// # Examples
+///
+/// Printing the menu tree:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// fn print_items(items: &[w::ResMenuItem], level: usize) {
+///     for item in items.iter() {
+///         println!("{}{} {}", "  ".repeat(level), item.id, item.text);
+///         if let Some(submenu) = &item.submenu {
+///             print_items(submenu, level + 1);
+///         }
+///     }
+/// }
+///
+/// let hexe = w::HINSTANCE::LoadLibrary("hand.exe")?;
+/// let menu = hexe.load_menu_template(w::IdStr::Id(101), None)?;
+/// print_items(&menu.items, 0);
+/// # Ok::<_, winsafe::co::ERROR>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct ResMenu {
+	/// Whether the template is in the extended format.
+	pub is_extended: bool,
+	/// Help context identifier of the menu. Always zero in standard templates.
+	pub help_id: u32,
+	/// The top-level items.
+	pub items: Vec<ResMenuItem>,
+}
+
+/// An item of a [`ResMenu`](crate::ResMenu).
+#[derive(Clone, Debug)]
+pub struct ResMenuItem {
+	/// Command ID. Standard templates have 16-bit IDs, and zero for popups.
+	pub id: u32,
+	/// Text of the item, with the `&` mnemonics.
+	pub text: String,
+	/// Item type. Standard templates report a separator as an item with no
+	/// ID and no text.
+	pub item_type: co::MFT,
+	/// Item state.
+	pub state: co::MFS,
+	/// Help context identifier of the submenu. Always zero in standard
+	/// templates.
+	pub help_id: u32,
+	/// Items of the submenu, if this item opens a popup.
+	pub submenu: Option<Vec<ResMenuItem>>,
+}
+
+impl ResMenu {
+	/// Decodes the raw data of the resource.
+	#[must_use]
+	pub fn parse(data: &[u8]) -> SysResult<Self> {
+		let mut rd = ByteReader::new(data);
+		let version = rd.u16()?;
+		let offset = rd.u16()?;
+
+		match version {
+			0 => {
+				rd.skip(offset as _)?;
+				Ok(Self {
+					is_extended: false,
+					help_id: 0,
+					items: Self::parse_items(&mut rd)?,
+				})
+			},
+			1 => {
+				let help_id = rd.u32()?;
+				rd.seek(4 + offset as usize)?;
+				Ok(Self {
+					is_extended: true,
+					help_id,
+					items: Self::parse_items_ex(&mut rd)?,
+				})
+			},
+			_ => Err(co::ERROR::INVALID_DATA),
+		}
+	}
+
+	fn parse_items(rd: &mut ByteReader) -> SysResult<Vec<ResMenuItem>> {
+		const MF_POPUP: u16 = 0x0010;
+		const MF_END: u16 = 0x0080;
+
+		let mut items = Vec::<ResMenuItem>::default();
+		loop {
+			let option = rd.u16()?;
+			let id = if option & MF_POPUP == 0 { rd.u16()? } else { 0 };
+			let text = rd.utf16_nullt()?;
+
+			let mut item_type = unsafe {
+				co::MFT::from_raw(option as u32 & (
+					co::MFT::MENUBARBREAK
+						| co::MFT::MENUBREAK
+						| co::MFT::OWNERDRAW
+						| co::MFT::RIGHTJUSTIFY
+				).raw())
+			};
+			if id == 0 && text.is_empty() && option & MF_POPUP == 0 {
+				item_type |= co::MFT::SEPARATOR;
+			}
+
+			items.push(ResMenuItem {
+				id: id as _,
+				text,
+				item_type,
+				state: unsafe {
+					co::MFS::from_raw(option as u32 & (co::MFS::GRAYED | co::MFS::CHECKED).raw())
+				},
+				help_id: 0,
+				submenu: if option & MF_POPUP != 0 {
+					Some(Self::parse_items(rd)?)
+				} else {
+					None
+				},
+			});
+
+			if option & MF_END != 0 {
+				break;
+			}
+		}
+		Ok(items)
+	}
+
+	fn parse_items_ex(rd: &mut ByteReader) -> SysResult<Vec<ResMenuItem>> {
+		const POPUP: u16 = 0x0001;
+		const END: u16 = 0x0080;
+
+		let mut items = Vec::<ResMenuItem>::default();
+		loop {
+			rd.align(4)?;
+			let item_type = unsafe { co::MFT::from_raw(rd.u32()?) };
+			let state = unsafe { co::MFS::from_raw(rd.u32()?) };
+			let id = rd.u32()?;
+			let res_info = rd.u16()?;
+			let text = rd.utf16_nullt()?;
+
+			let (help_id, submenu) = if res_info & POPUP != 0 {
+				rd.align(4)?;
+				let help_id = rd.u32()?;
+				(help_id, Some(Self::parse_items_ex(rd)?))
+			} else {
+				(0, None)
+			};
+
+			items.push(ResMenuItem { id, text, item_type, state, help_id, submenu });
+
+			if res_info & END != 0 {
+				break;
+			}
+		}
+		Ok(items)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::prelude::*;
+
+	#[derive(Default)]
+	struct Fixture(Vec<u8>);
+
+	impl Fixture {
+		fn u8(mut self, v: u8) -> Self { self.0.push(v); self }
+		fn u16(mut self, v: u16) -> Self { self.0.extend_from_slice(&v.to_le_bytes()); self }
+		fn u32(mut self, v: u32) -> Self { self.0.extend_from_slice(&v.to_le_bytes()); self }
+		fn rect(self, x: i16, y: i16, cx: i16, cy: i16) -> Self {
+			self.u16(x as _).u16(y as _).u16(cx as _).u16(cy as _)
+		}
+		fn sz(mut self, s: &str) -> Self {
+			s.encode_utf16().chain(std::iter::once(0))
+				.for_each(|ch| self.0.extend_from_slice(&ch.to_le_bytes()));
+			self
+		}
+		fn align4(mut self) -> Self {
+			while self.0.len() % 4 != 0 { self.0.push(0); }
+			self
+		}
+	}
+
+	fn dlgtemplate() -> Vec<u8> {
+		Fixture::default()
+			.u32(0x8000_0000 | DS_SETFONT).u32(0).u16(2) // style, ex_style, cdit
+			.rect(10, 20, 200, 100)
+			.u16(0).u16(0).sz("Hi") // menu, class, title
+			.u16(8).sz("MS Shell Dlg")
+			.align4()
+			.u32(0x5001_0000).u32(0).rect(5, 5, 50, 14).u16(1) // IDOK
+			.u16(0xffff).u16(0x0080).sz("OK").u16(0)
+			.align4()
+			.u32(0x5000_0000).u32(0).rect(5, 25, 80, 10).u16(0xffff)
+			.sz("MyClass").u16(0xffff).u16(7).u16(2).u8(0xaa).u8(0xbb)
+			.0
+	}
+
+	fn dlgtemplateex() -> Vec<u8> {
+		Fixture::default()
+			.u16(1).u16(0xffff).u32(42).u32(0x100).u32(0x8000_0000 | DS_SETFONT) // dlgVer, signature, help, ex_style, style
+			.u16(1).rect(0, 0, 120, 60)
+			.u16(0xffff).u16(101).u16(0).sz("Extended") // menu, class, title
+			.u16(9).u16(700).u8(1).u8(1).sz("Segoe UI")
+			.align4()
+			.u32(7).u32(0).u32(0x5001_0000).rect(1, 2, 3, 4).u32(70000)
+			.u16(0xffff).u16(0x0081).u16(0).u16(0)
+			.0
+	}
+
+	#[test]
+	fn dialog_standard() {
+		let dlg = ResDialog::parse(&dlgtemplate()).unwrap();
+		assert!(!dlg.is_extended);
+		assert_eq!(dlg.help_id, 0);
+		assert_eq!((dlg.pos.x, dlg.pos.y, dlg.size.cx, dlg.size.cy), (10, 20, 200, 100));
+		assert!(dlg.menu.is_none() && dlg.class.is_none());
+		assert_eq!(dlg.title, "Hi");
+		assert_eq!(dlg.font, Some(ResDialogFont {
+			point_size: 8, weight: 0, italic: false, char_set: 0, face: "MS Shell Dlg".to_owned(),
+		}));
+		assert_eq!(dlg.items.len(), 2);
+
+		let ok = &dlg.items[0];
+		assert_eq!(ok.id, 1);
+		assert_eq!(ok.class_name(), "Button");
+		assert!(matches!(&ok.title, Some(IdStr::Str(t)) if t.to_string() == "OK"));
+		assert!(ok.creation_data.is_empty());
+
+		let custom = &dlg.items[1];
+		assert_eq!(custom.id, 0xffff);
+		assert_eq!(custom.class_name(), "MyClass");
+		assert!(matches!(custom.title, Some(IdStr::Id(7))));
+		assert_eq!(custom.creation_data, [0xaa, 0xbb]);
+	}
+
+	#[test]
+	fn dialog_extended() {
+		let dlg = ResDialog::parse(&dlgtemplateex()).unwrap();
+		assert!(dlg.is_extended);
+		assert_eq!(dlg.help_id, 42);
+		assert_eq!(dlg.ex_style.raw(), 0x100);
+		assert!(matches!(dlg.menu, Some(IdStr::Id(101))));
+		assert_eq!(dlg.title, "Extended");
+		assert_eq!(dlg.font, Some(ResDialogFont {
+			point_size: 9, weight: 700, italic: true, char_set: 1, face: "Segoe UI".to_owned(),
+		}));
+		assert_eq!(dlg.items.len(), 1);
+		assert_eq!(dlg.items[0].help_id, 7);
+		assert_eq!(dlg.items[0].id, 70000);
+		assert_eq!(dlg.items[0].class_name(), "Edit");
+		assert!(dlg.items[0].title.is_none());
+	}
+
+	#[test]
+	fn dialog_truncated() {
+		for data in [dlgtemplate(), dlgtemplateex()] {
+			for len in [0, 3, 17, data.len() / 2, data.len() - 1] {
+				assert!(ResDialog::parse(&data[..len]).is_err(), "len {}", len);
+			}
+		}
+	}
+
+	#[test]
+	fn menu_standard() {
+		let data = Fixture::default()
+			.u16(0).u16(0) // version, offset
+			.u16(0x0010).sz("&File") // MF_POPUP
+			.u16(0).u16(100).sz("&Open")
+			.u16(0).u16(0).sz("") // separator
+			.u16(0x0080 | 0x0001).u16(101).sz("E&xit") // MF_END | MF_GRAYED
+			.u16(0x0080).u16(200).sz("&About") // MF_END
+			.0;
+		let menu = ResMenu::parse(&data).unwrap();
+		assert!(!menu.is_extended);
+		assert_eq!(menu.items.len(), 2);
+		assert_eq!(menu.items[0].text, "&File");
+		assert_eq!(menu.items[1].id, 200);
+
+		let sub = menu.items[0].submenu.as_ref().unwrap();
+		assert_eq!(sub.len(), 3);
+		assert_eq!((sub[0].id, sub[0].text.as_str()), (100, "&Open"));
+		assert!(sub[1].item_type.has(co::MFT::SEPARATOR));
+		assert!(sub[2].state.has(co::MFS::GRAYED));
+
+		assert!(ResMenu::parse(&data[..data.len() - 2]).is_err());
+	}
+
+	#[test]
+	fn menu_extended() {
+		let data = Fixture::default()
+			.u16(1).u16(4).u32(5) // version, offset, help_id
+			.u32(0).u32(0).u32(0).u16(0x0001).sz("&Edit").align4().u32(9) // popup
+			.align4().u32(0).u32(co::MFS::CHECKED.raw()).u32(300).u16(0x0080).sz("&Wrap")
+			.align4().u32(0).u32(0).u32(400).u16(0x0080).sz("&Help")
+			.0;
+		let menu = ResMenu::parse(&data).unwrap();
+		assert!(menu.is_extended);
+		assert_eq!(menu.help_id, 5);
+		assert_eq!(menu.items.len(), 2);
+		assert_eq!(menu.items[0].help_id, 9);
+		let sub = menu.items[0].submenu.as_ref().unwrap();
+		assert_eq!((sub[0].id, sub[0].text.as_str()), (300, "&Wrap"));
+		assert!(sub[0].state.has(co::MFS::CHECKED));
+		assert_eq!(menu.items[1].id, 400);
+
+		assert!(ResMenu::parse(&data[..data.len() - 4]).is_err());
+		assert!(ResMenu::parse(&[2, 0, 0, 0]).is_err()); // unknown version
+	}
+}
@@ -0,0 +1,230 @@
+use std::cell::RefCell;
+use std::marker::PhantomData;
+
+use crate::co;
+use crate::decl::*;
+use crate::prelude::*;
+use crate::user::ffi;
+
+const HC_ACTION: i32 = 0;
+
+#[allow(clippy::type_complexity)]
+enum HookFunc {
+	Cbt(Box<dyn FnMut(co::HCBT, usize, isize) -> bool>),
+	GetMessage(Box<dyn FnMut(&mut MSG, co::PM) -> bool>),
+	KeyboardLl(Box<dyn FnMut(co::WM, &KBDLLHOOKSTRUCT) -> bool>),
+	MouseLl(Box<dyn FnMut(co::WM, &MSLLHOOKSTRUCT) -> bool>),
+}
+
+struct HookEntry {
+	hook_id: co::WH,
+	func: Option<HookFunc>, // taken while running, so reentrant calls are passed along
+}
+
+thread_local! {
+	static HOOKS: RefCell<Vec<HookEntry>> = RefCell::new(Vec::default());
+}
+
+/// A hook installed with
+/// [`HHOOK::SetWindowsHookEx`](crate::prelude::user_Hhook::SetWindowsHookEx),
+/// which runs a closure instead of a raw
+/// [`HOOKPROC`](crate::HOOKPROC). The hook is removed with
+/// [`HHOOK::UnhookWindowsHookEx`](crate::prelude::user_Hhook::UnhookWindowsHookEx)
+/// when the object goes out of scope.
+///
+/// The closure returns `true` to swallow the event, so it won't reach the
+/// next hooks nor the target window, or `false` to pass it along.
+///
+/// The closure is stored per thread, and it's called on the same thread which
+/// installed the hook, when it retrieves messages. So the thread must run a
+/// message loop – like the one of a [`gui`](crate::gui) window – while the
+/// hook is alive. Only one hook of each type can be installed per thread.
+///
+/// Low-level hooks are global, while the others are installed only for the
+/// current thread.
+///
+/// # Examples
+///
+/// Blocking the Windows keys:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let _hook = w::WindowsHook::keyboard_ll(|_, kb| { // keep guard alive
+///     kb.vkCode == co::VK::LWIN || kb.vkCode == co::VK::RWIN
+/// })?;
+///
+/// // ... run the message loop ...
+/// # Ok::<_, co::ERROR>(())
+/// ```
+pub struct WindowsHook {
+	hhook: HHOOK,
+	hook_id: co::WH,
+	_thread: PhantomData<*const ()>, // must be dropped on the same thread
+}
+
+impl Drop for WindowsHook {
+	fn drop(&mut self) {
+		let _ = self.hhook.UnhookWindowsHookEx(); // ignore errors
+		HOOKS.with(|hooks| {
+			hooks.borrow_mut().retain(|entry| entry.hook_id != self.hook_id);
+		});
+	}
+}
+
+impl WindowsHook {
+	/// Installs a [`co::WH::CBT`](crate::co::WH::CBT) hook for the current
+	/// thread.
+	///
+	/// The closure receives the notification code, and the raw `wParam` and
+	/// `lParam`, whose meaning depends on the code. Swallowing the event
+	/// prevents the operation, like creating, activating or resizing a window.
+	#[must_use]
+	pub fn cbt<F>(func: F) -> SysResult<Self>
+		where F: FnMut(co::HCBT, usize, isize) -> bool + 'static,
+	{
+		Self::install(co::WH::CBT, Self::cbt_proc, HookFunc::Cbt(Box::new(func)))
+	}
+
+	/// Installs a [`co::WH::GETMESSAGE`](crate::co::WH::GETMESSAGE) hook for
+	/// the current thread.
+	///
+	/// The closure receives the message about to be returned by
+	/// [`GetMessage`](crate::GetMessage) or [`PeekMessage`](crate::PeekMessage),
+	/// which can be modified, and whether it's being removed from the queue.
+	/// Swallowing the event replaces it with
+	/// [`co::WM::NULL`](crate::co::WM::NULL).
+	#[must_use]
+	pub fn get_message<F>(func: F) -> SysResult<Self>
+		where F: FnMut(&mut MSG, co::PM) -> bool + 'static,
+	{
+		Self::install(co::WH::GETMESSAGE, Self::get_message_proc,
+			HookFunc::GetMessage(Box::new(func)))
+	}
+
+	/// Installs a global [`co::WH::KEYBOARD_LL`](crate::co::WH::KEYBOARD_LL)
+	/// hook.
+	///
+	/// The closure receives the keyboard message – like
+	/// [`co::WM::KEYDOWN`](crate::co::WM::KEYDOWN) – and the event data.
+	#[must_use]
+	pub fn keyboard_ll<F>(func: F) -> SysResult<Self>
+		where F: FnMut(co::WM, &KBDLLHOOKSTRUCT) -> bool + 'static,
+	{
+		Self::install(co::WH::KEYBOARD_LL, Self::keyboard_ll_proc,
+			HookFunc::KeyboardLl(Box::new(func)))
+	}
+
+	/// Installs a global [`co::WH::MOUSE_LL`](crate::co::WH::MOUSE_LL) hook.
+	///
+	/// The closure receives the mouse message – like
+	/// [`co::WM::LBUTTONDOWN`](crate::co::WM::LBUTTONDOWN) – and the event
+	/// data, with the cursor position in screen coordinates.
+	#[must_use]
+	pub fn mouse_ll<F>(func: F) -> SysResult<Self>
+		where F: FnMut(co::WM, &MSLLHOOKSTRUCT) -> bool + 'static,
+	{
+		Self::install(co::WH::MOUSE_LL, Self::mouse_ll_proc,
+			HookFunc::MouseLl(Box::new(func)))
+	}
+
+	/// Returns the underlying hook handle.
+	#[must_use]
+	pub const fn hhook(&self) -> &HHOOK {
+		&self.hhook
+	}
+
+	fn install(hook_id: co::WH, proc: HOOKPROC, func: HookFunc) -> SysResult<Self> {
+		if HOOKS.with(|hooks| hooks.borrow().iter().any(|entry| entry.hook_id == hook_id)) {
+			return Err(co::ERROR::ALREADY_EXISTS);
+		}
+
+		let hhook = if hook_id == co::WH::KEYBOARD_LL || hook_id == co::WH::MOUSE_LL {
+			let hinst = HINSTANCE::GetModuleHandle(None)?;
+			HHOOK::SetWindowsHookEx(hook_id, proc, Some(&hinst), None)?
+		} else {
+			HHOOK::SetWindowsHookEx(hook_id, proc, None, Some(GetCurrentThreadId()))?
+		};
+
+		HOOKS.with(|hooks| {
+			hooks.borrow_mut().push(HookEntry { hook_id, func: Some(func) });
+		});
+		Ok(Self { hhook, hook_id, _thread: PhantomData })
+	}
+
+	fn run(hook_id: co::WH, call: impl FnOnce(&mut HookFunc) -> bool) -> bool {
+		let func = HOOKS.with(|hooks| {
+			hooks.borrow_mut().iter_mut()
+				.find(|entry| entry.hook_id == hook_id)
+				.and_then(|entry| entry.func.take())
+		});
+		let mut func = match func {
+			Some(func) => func,
+			None => return false, // reentrant call
+		};
+
+		let swallow = call(&mut func);
+
+		HOOKS.with(|hooks| {
+			if let Some(entry) = hooks.borrow_mut().iter_mut() // may have been unhooked meanwhile
+				.find(|entry| entry.hook_id == hook_id)
+			{
+				entry.func = Some(func);
+			}
+		});
+		swallow
+	}
+
+	fn call_next(code: i32, wparam: usize, lparam: isize) -> isize {
+		unsafe { ffi::CallNextHookEx(std::ptr::null_mut(), code, wparam, lparam) } // HHOOK is ignored
+	}
+
+	extern "system" fn cbt_proc(code: i32, wparam: usize, lparam: isize) -> isize {
+		if code >= 0 && Self::run(co::WH::CBT, |func| match func {
+			HookFunc::Cbt(func) => func(unsafe { co::HCBT::from_raw(code) }, wparam, lparam),
+			_ => false,
+		}) {
+			return 1;
+		}
+		Self::call_next(code, wparam, lparam)
+	}
+
+	extern "system" fn get_message_proc(code: i32, wparam: usize, lparam: isize) -> isize {
+		if code == HC_ACTION {
+			let msg = unsafe { &mut *(lparam as *mut MSG) };
+			if Self::run(co::WH::GETMESSAGE, |func| match func {
+				HookFunc::GetMessage(func) => func(msg, unsafe { co::PM::from_raw(wparam as _) }),
+				_ => false,
+			}) {
+				msg.message = co::WM::NULL;
+			}
+		}
+		Self::call_next(code, wparam, lparam)
+	}
+
+	extern "system" fn keyboard_ll_proc(code: i32, wparam: usize, lparam: isize) -> isize {
+		if code == HC_ACTION && Self::run(co::WH::KEYBOARD_LL, |func| match func {
+			HookFunc::KeyboardLl(func) => func(
+				unsafe { co::WM::from_raw(wparam as _) },
+				unsafe { &*(lparam as *const KBDLLHOOKSTRUCT) },
+			),
+			_ => false,
+		}) {
+			return 1;
+		}
+		Self::call_next(code, wparam, lparam)
+	}
+
+	extern "system" fn mouse_ll_proc(code: i32, wparam: usize, lparam: isize) -> isize {
+		if code == HC_ACTION && Self::run(co::WH::MOUSE_LL, |func| match func {
+			HookFunc::MouseLl(func) => func(
+				unsafe { co::WM::from_raw(wparam as _) },
+				unsafe { &*(lparam as *const MSLLHOOKSTRUCT) },
+			),
+			_ => false,
+		}) {
+			return 1;
+		}
+		Self::call_next(code, wparam, lparam)
+	}
+}
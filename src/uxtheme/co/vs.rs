@@ -2,6 +2,10 @@
 /// [parts and states](https://learn.microsoft.com/en-us/windows/win32/controls/parts-and-states).
 /// These are originally two separated constant types, but since they form a
 /// hierarchy, one single constant type can represent both part and state.
+///
+/// The parts and states which belong to each theme class are listed in
+/// [`co::VSCLASS`](crate::co::VSCLASS).
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
 pub struct VS {
 	pub(crate) part: i32,
 	pub(crate) state: i32,
@@ -30,15 +34,101 @@ impl VS {
 	}
 }
 
+/// A visual style
+/// [class](https://learn.microsoft.com/en-us/windows/win32/controls/parts-and-states),
+/// whose name is passed to
+/// [`HWND::OpenThemeData`](crate::prelude::uxtheme_Hwnd::OpenThemeData),
+/// along with the [`co::VS`](crate::co::VS) parts and states it defines.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let hwnd: w::HWND; // initialized somewhere
+/// # let hwnd = w::HWND::NULL;
+///
+/// let vs = co::VS::BUTTON_CHECKBOX_CHECKEDHOT;
+/// assert!(co::VSCLASS::BUTTON.contains(vs));
+///
+/// if let Some(htheme) = hwnd.OpenThemeData(co::VSCLASS::BUTTON.name()) {
+///     let sz = htheme.GetThemePartSize(None, vs, None, co::THEMESIZE::DRAW)?;
+/// }
+/// # Ok::<_, co::HRESULT>(())
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct VSCLASS {
+	name: &'static str,
+	values: &'static [VS],
+}
+
+impl std::fmt::Display for VSCLASS {
+	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+		write!(f, "{}", self.name)
+	}
+}
+
+impl VSCLASS {
+	/// Returns the class name.
+	#[must_use]
+	pub const fn name(&self) -> &'static str {
+		self.name
+	}
+
+	/// Returns all the part and state values defined for this class.
+	#[must_use]
+	pub const fn values(&self) -> &'static [VS] {
+		self.values
+	}
+
+	/// Returns the distinct parts defined for this class.
+	#[must_use]
+	pub fn parts(&self) -> Vec<i32> {
+		let mut parts = self.values.iter()
+			.map(|vs| vs.part)
+			.collect::<Vec<_>>();
+		parts.sort_unstable();
+		parts.dedup();
+		parts
+	}
+
+	/// Returns the states defined for the given part of this class. Parts
+	/// without states have a single value with state zero.
+	#[must_use]
+	pub fn states(&self, part: i32) -> Vec<VS> {
+		self.values.iter()
+			.filter(|vs| vs.part == part)
+			.copied()
+			.collect()
+	}
+
+	/// Returns `true` if the part and state are defined for this class.
+	#[must_use]
+	pub fn contains(&self, part_state: VS) -> bool {
+		self.values.contains(&part_state)
+	}
+}
+
 macro_rules! impl_pub_const_vs {
-	( $( $pubvalname:ident $part:expr, $state:expr )* ) => {
+	(
+		$class:ident;
+		$( $pubvalname:ident $part:expr, $state:expr )*
+	) => {
 		impl VS {
 			$( pub const $pubvalname: Self = Self::new($part, $state); )*
 		}
+
+		impl VSCLASS {
+			#[doc = concat!("The `", stringify!($class), "` class.")]
+			pub const $class: Self = Self {
+				name: stringify!($class),
+				values: &[ $( VS::$pubvalname, )* ],
+			};
+		}
 	};
 }
 
-impl_pub_const_vs! {
+impl_pub_const_vs! { AEROWIZARD;
 	AEROWIZARD_TITLEBAR_S_TITLEBAR_ACTIVE 1, 1
 	AEROWIZARD_TITLEBAR_S_TITLEBAR_INACTIVE 1, 2
 
@@ -51,7 +141,7 @@ impl_pub_const_vs! {
 	AEROWIZARD_BUTTON 5, 0
 }
 
-impl_pub_const_vs! {
+impl_pub_const_vs! { BUTTON;
 	BUTTON_PUSHBUTTON_NORMAL 1, 1
 	BUTTON_PUSHBUTTON_HOT 1, 2
 	BUTTON_PUSHBUTTON_PRESSED 1, 3
@@ -117,7 +207,7 @@ impl_pub_const_vs! {
 	BUTTON_PUSHBUTTONDROPDOWN_DISABLED 11, 2
 }
 
-impl_pub_const_vs! {
+impl_pub_const_vs! { COMBOBOX;
 	COMBOBOX_DROPDOWNBUTTON_NORMAL 1, 1
 	COMBOBOX_DROPDOWNBUTTON_HOT 1, 2
 	COMBOBOX_DROPDOWNBUTTON_PRESSED 1, 3
@@ -159,13 +249,13 @@ impl_pub_const_vs! {
 	COMBOBOX_DROPDOWNITEM_HIGHLIGHTED 9, 2
 }
 
-impl_pub_const_vs! {
+impl_pub_const_vs! { COMMUNICATIONS;
 	COMMUNICATIONS_TAB_NORMAL 1, 1
 	COMMUNICATIONS_TAB_HOT 1, 2
 	COMMUNICATIONS_TAB_SELECTED 1, 3
 }
 
-impl_pub_const_vs! {
+impl_pub_const_vs! { CONTROLPANEL;
 	CONTROLPANEL_NAVIGATIONPANE 1, 0
 
 	CONTROLPANEL_CONTENTPANE 2, 0
@@ -216,7 +306,7 @@ impl_pub_const_vs! {
 	CONTROLPANEL_BODYTITLE 19, 0
 }
 
-impl_pub_const_vs! {
+impl_pub_const_vs! { DATEPICKER;
 	DATEPICKER_DATETEXT_NORMAL 1, 1
 	DATEPICKER_DATETEXT_DISABLED 1, 2
 	DATEPICKER_DATETEXT_SELECTED 1, 3
@@ -232,7 +322,7 @@ impl_pub_const_vs! {
 	DATEPICKER_SHOWCALENDARBUTTONRIGHT_DISABLED 3, 4
 }
 
-impl_pub_const_vs! {
+impl_pub_const_vs! { DRAGDROP;
 	DRAGDROP_COPY_HIGHLIGHT 1, 1
 	DRAGDROP_COPY_NOHIGHLIGHT 1, 2
 
@@ -256,7 +346,7 @@ impl_pub_const_vs! {
 	DRAGDROP_TEXTBG 8, 0
 }
 
-impl_pub_const_vs! {
+impl_pub_const_vs! { EDIT;
 	EDIT_EDITTEXT_NORMAL 1, 1
 	EDIT_EDITTEXT_HOT 1, 2
 	EDIT_EDITTEXT_SELECTED 1, 3
@@ -303,7 +393,7 @@ impl_pub_const_vs! {
 	EDIT_EDITBORDER_HVSCROLL_DISABLED 9, 4
 }
 
-impl_pub_const_vs! {
+impl_pub_const_vs! { EXPLORERBAR;
 	EXPLORERBAR_HEADERBACKGROUND 1, 0
 
 	EXPLORERBAR_HEADERCLOSE_NORMAL 2, 1
@@ -346,7 +436,7 @@ impl_pub_const_vs! {
 	EXPLORERBAR_SPECIALGROUPHEAD 12, 0
 }
 
-impl_pub_const_vs! {
+impl_pub_const_vs! { FLYOUT;
 	FLYOUT_HEADER 1, 0
 
 	FLYOUT_BODY_NORMAL 2, 1
@@ -370,7 +460,7 @@ impl_pub_const_vs! {
 	FLYOUT_LINKHEADER_HOVER 8, 2
 }
 
-impl_pub_const_vs! {
+impl_pub_const_vs! { HEADER;
 	HEADER_HEADERITEM_NORMAL 1, 1
 	HEADER_HEADERITEM_HOT 1, 2
 	HEADER_HEADERITEM_PRESSED 1, 3
@@ -407,7 +497,7 @@ impl_pub_const_vs! {
 	HEADER_HEADEROVERFLOW_HOT 7, 2
 }
 
-impl_pub_const_vs! {
+impl_pub_const_vs! { LISTBOX;
 	LISTBOX_BORDER_HSCROLL_NORMAL 1, 1
 	LISTBOX_BORDER_HSCROLL_FOCUSED 1, 2
 	LISTBOX_BORDER_HSCROLL_HOT 1, 3
@@ -434,7 +524,7 @@ impl_pub_const_vs! {
 	LISTBOX_ITEM_SELECTEDNOTFOCUS 5, 4
 }
 
-impl_pub_const_vs! {
+impl_pub_const_vs! { LISTVIEW;
 	LISTVIEW_LISTITEM_NORMAL 1, 1
 	LISTVIEW_LISTITEM_HOT 1, 2
 	LISTVIEW_LISTITEM_SELECTED 1, 3
@@ -495,7 +585,7 @@ impl_pub_const_vs! {
 	LISTVIEW_COLUMNDETAIL 10, 0
 }
 
-impl_pub_const_vs! {
+impl_pub_const_vs! { MENU;
 	MENU_MENUITEM_TMSCHEMA 1, 0
 
 	MENU_MENUDROPDOWN_TMSCHEMA 2, 0
@@ -556,7 +646,7 @@ impl_pub_const_vs! {
 	MENU_SYSTEMRESTORE_DISABLED 20, 2
 }
 
-impl_pub_const_vs! {
+impl_pub_const_vs! { NAVIGATION;
 	NAVIGATION_BACKBUTTON_NORMAL 1, 1
 	NAVIGATION_BACKBUTTON_HOT 1, 2
 	NAVIGATION_BACKBUTTON_PRESSED 1, 3
@@ -573,7 +663,7 @@ impl_pub_const_vs! {
 	NAVIGATION_MENUBUTTON_DISABLED 3, 4
 }
 
-impl_pub_const_vs! {
+impl_pub_const_vs! { PROGRESS;
 	PROGRESS_BAR 1, 0
 
 	PROGRESS_BARVERT 2, 0
@@ -607,7 +697,7 @@ impl_pub_const_vs! {
 	PROGRESS_TRANSPARENTBARVERT_PARTIAL 12, 2
 }
 
-impl_pub_const_vs! {
+impl_pub_const_vs! { REBAR;
 	REBAR_GRIPPER 1, 0
 
 	REBAR_GRIPPERVERT 2, 0
@@ -633,7 +723,7 @@ impl_pub_const_vs! {
 	REBAR_SPLITTERVERT_PRESSED 8, 3
 }
 
-impl_pub_const_vs! {
+impl_pub_const_vs! { SCROLLBAR;
 	SCROLLBAR_ARROWBTN_UPNORMAL 1, 1
 	SCROLLBAR_ARROWBTN_UPHOT 1, 2
 	SCROLLBAR_ARROWBTN_UPPRESSED 1, 3
@@ -719,7 +809,7 @@ impl_pub_const_vs! {
 	SCROLLBAR_SIZEBOXBKGND_HOVER 11, 5
 }
 
-impl_pub_const_vs! {
+impl_pub_const_vs! { SPIN;
 	SPIN_UP_NORMAL 1, 1
 	SPIN_UP_HOT 1, 2
 	SPIN_UP_PRESSED 1, 3
@@ -741,7 +831,7 @@ impl_pub_const_vs! {
 	SPIN_DOWNHORZ_DISABLED 4, 4
 }
 
-impl_pub_const_vs! {
+impl_pub_const_vs! { STATUS;
 	STATUS_PANE 1, 0
 
 	STATUS_GRIPPERPANE 2, 0
@@ -749,7 +839,7 @@ impl_pub_const_vs! {
 	STATUS_GRIPPER 3, 0
 }
 
-impl_pub_const_vs! {
+impl_pub_const_vs! { TAB;
 	TAB_TABITEM_NORMAL 1, 1
 	TAB_TABITEM_HOT 1, 2
 	TAB_TABITEM_SELECTED 1, 3
@@ -805,7 +895,7 @@ impl_pub_const_vs! {
 	TAB_AEROWIZARDBODY 11, 0
 }
 
-impl_pub_const_vs! {
+impl_pub_const_vs! { TASKDIALOG;
 	TASKDIALOG_PRIMARYPANEL 1, 0
 
 	TASKDIALOG_MAININSTRUCTIONPANE 2, 0
@@ -856,7 +946,7 @@ impl_pub_const_vs! {
 	TASKDIALOG_RADIOBUTTONPANE 21, 0
 }
 
-impl_pub_const_vs! {
+impl_pub_const_vs! { TEXTSTYLE;
 	TEXTSTYLE_MAININSTRUCTION 1, 0
 
 	TEXTSTYLE_INSTRUCTION 2, 0
@@ -880,7 +970,7 @@ impl_pub_const_vs! {
 	TEXTSTYLE_CONTROLLABEL_CONTROLLABEL_DISABLED 9, 2
 }
 
-impl_pub_const_vs! {
+impl_pub_const_vs! { TOOLBAR;
 	TOOLBAR_BUTTON_NORMAL 1, 1
 	TOOLBAR_BUTTON_HOT 1, 2
 	TOOLBAR_BUTTON_PRESSED 1, 3
@@ -945,7 +1035,7 @@ impl_pub_const_vs! {
 	TOOLBAR_DROPDOWNBUTTONGLYPH_OTHERSIDEHOT 7, 8
 }
 
-impl_pub_const_vs! {
+impl_pub_const_vs! { TOOLTIP;
 	TOOLTIP_STANDARD_NORMAL 1, 1
 	TOOLTIP_STANDARD_LINK 1, 2
 
@@ -972,7 +1062,7 @@ impl_pub_const_vs! {
 	TOOLTIP_WRENCH_PRESSED 7, 3
 }
 
-impl_pub_const_vs! {
+impl_pub_const_vs! { TRACKBAR;
 	TRACKBAR_TRACK_NORMAL 1, 1
 
 	TRACKBAR_TRACKVERT_NORMAL 2, 1
@@ -1018,7 +1108,7 @@ impl_pub_const_vs! {
 	TRACKBAR_TICSVERT_NORMAL 10, 1
 }
 
-impl_pub_const_vs! {
+impl_pub_const_vs! { TREEVIEW;
 	TREEVIEW_TREEITEM_NORMAL 1, 1
 	TREEVIEW_TREEITEM_HOT 1, 2
 	TREEVIEW_TREEITEM_SELECTED 1, 3
@@ -1035,7 +1125,7 @@ impl_pub_const_vs! {
 	TREEVIEW_HOTGLYPH_OPENED 4, 2
 }
 
-impl_pub_const_vs! {
+impl_pub_const_vs! { USERTILE;
 	USERTILE_STROKEBACKGROUND 1, 0
 
 	USERTILE_HOVERBACKGROUND_NORMAL 2, 1
@@ -1043,12 +1133,12 @@ impl_pub_const_vs! {
 	USERTILE_HOVERBACKGROUND_PRESSED 2, 3
 }
 
-impl_pub_const_vs! {
+impl_pub_const_vs! { TEXTSELECTIONGRIPPER;
 	TEXTSELECTIONGRIPPER_GRIPPER_NORMAL 1, 1
 	TEXTSELECTIONGRIPPER_GRIPPER_CENTERED 1, 2
 }
 
-impl_pub_const_vs! {
+impl_pub_const_vs! { WINDOW;
 	WINDOW_CAPTION_ACTIVE 1, 1
 	WINDOW_CAPTION_INACTIVE 1, 2
 	WINDOW_CAPTION_DISABLED 1, 3
@@ -1185,21 +1275,21 @@ impl_pub_const_vs! {
 	WINDOW_BORDER 39, 0
 }
 
-impl_pub_const_vs! {
+impl_pub_const_vs! { LINK;
 	LINK_HYPERLINK_NORMALTEXT 1, 1
 	LINK_HYPERLINK_LINKTEXT 1, 2
 }
 
-impl_pub_const_vs! {
+impl_pub_const_vs! { EMPTYMARKUP;
 	EMPTYMARKUP_MARKUPTEXT_NORMALTEXT 1, 1
 	EMPTYMARKUP_MARKUPTEXT_LINKTEXT 1, 2
 }
 
-impl_pub_const_vs! {
+impl_pub_const_vs! { STATIC;
 	STATIC_TEXT 1, 0
 }
 
-impl_pub_const_vs! {
+impl_pub_const_vs! { PAGE;
 	PAGE_UP 1, 0
 
 	PAGE_DOWN 2, 0
@@ -1209,7 +1299,7 @@ impl_pub_const_vs! {
 	PAGE_DOWNHORZ 4, 0
 }
 
-impl_pub_const_vs! {
+impl_pub_const_vs! { MONTHCAL;
 	MONTHCAL_BACKGROUND 1, 0
 
 	MONTHCAL_BORDERS 2, 0
@@ -1264,19 +1354,19 @@ impl_pub_const_vs! {
 	MONTHCAL_NAVPREV_DISABLED 11, 4
 }
 
-impl_pub_const_vs! {
+impl_pub_const_vs! { CLOCK;
 	CLOCK_TIME_NORMAL 1, 1
 	CLOCK_TIME_HOT 1, 2
 	CLOCK_TIME_PRESSED 1, 3
 }
 
-impl_pub_const_vs! {
+impl_pub_const_vs! { TRAYNOTIFY;
 	TRAYNOTIFY_BACKGROUND 1, 0
 
 	TRAYNOTIFY_ANIMBACKGROUND 2, 0
 }
 
-impl_pub_const_vs! {
+impl_pub_const_vs! { TASKBAR;
 	TASKBAR_BACKGROUNDBOTTOM 1, 0
 
 	TASKBAR_BACKGROUNDRIGHT 2, 0
@@ -1294,7 +1384,7 @@ impl_pub_const_vs! {
 	TASKBAR_SIZINGBARLEFT 8, 0
 }
 
-impl_pub_const_vs! {
+impl_pub_const_vs! { TASKBAND;
 	TASKBAND_GROUPCOUNT 1, 0
 
 	TASKBAND_FLASHBUTTON 2, 0
@@ -1302,7 +1392,7 @@ impl_pub_const_vs! {
 	TASKBAND_FLASHBUTTONGROUPMENU 3, 0
 }
 
-impl_pub_const_vs! {
+impl_pub_const_vs! { STARTPANEL;
 	STARTPANEL_USERPANE 1, 0
 
 	STARTPANEL_MOREPROGRAMS 2, 0
@@ -1360,7 +1450,7 @@ impl_pub_const_vs! {
 	STARTPANEL_LOGOFFSPLITBUTTONDROPDOWN 19, 0
 }
 
-impl_pub_const_vs! {
+impl_pub_const_vs! { MENUBAND;
 	MENUBAND_NEWAPPBUTTON_NORMAL 1, 1
 	MENUBAND_NEWAPPBUTTON_HOT 1, 2
 	MENUBAND_NEWAPPBUTTON_PRESSED 1, 3
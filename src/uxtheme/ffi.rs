@@ -8,17 +8,21 @@ extern_sys! { "uxtheme";
 extern_sys! { "uxtheme";
 	CloseThemeData(HANDLE) -> HRES
 	DrawThemeBackground(HANDLE, HANDLE, i32, i32, PCVOID, PCVOID) -> HRES
+	DrawThemeText(HANDLE, HANDLE, i32, i32, PCSTR, i32, u32, u32, PCVOID) -> HRES
 	GetThemeAppProperties() -> u32
 	GetThemeBackgroundContentRect(HANDLE, HANDLE, i32, i32, PCVOID, PVOID) -> HRES
 	GetThemeBackgroundExtent(HANDLE, HANDLE, i32, i32, PCVOID, PVOID) -> HRES
 	GetThemeBackgroundRegion(HANDLE, HANDLE, i32, i32, PCVOID, *mut HANDLE) -> HRES
 	GetThemeColor(HANDLE, i32, i32, i32, *mut u32) -> HRES
+	GetThemeFont(HANDLE, HANDLE, i32, i32, i32, PVOID) -> HRES
 	GetThemeMargins(HANDLE, HANDLE, i32, i32, i32, PCVOID, PVOID) -> HRES
 	GetThemeMetric(HANDLE, HANDLE, i32, i32, i32, *mut i32) -> HRES
 	GetThemePartSize(HANDLE, HANDLE, i32, i32, PCVOID, u32, PVOID) -> HRES
 	GetThemePosition(HANDLE, i32, i32, i32, PVOID) -> HRES
 	GetThemePropertyOrigin(HANDLE, i32, i32, i32, *mut u32) -> HRES
 	GetThemeRect(HANDLE, i32, i32, i32, PVOID) -> HRES
+	GetThemeSysColor(HANDLE, i32) -> u32
+	GetThemeTextExtent(HANDLE, HANDLE, i32, i32, PCSTR, i32, u32, PCVOID, PVOID) -> HRES
 	IsAppThemed() -> BOOL
 	IsCompositionActive() -> BOOL
 	IsThemeActive() -> BOOL
@@ -46,6 +46,34 @@ pub trait uxtheme_Htheme: Handle {
 		)
 	}
 
+	/// [`DrawThemeText`](https://learn.microsoft.com/en-us/windows/win32/api/uxtheme/nf-uxtheme-drawthemetext)
+	/// function.
+	fn DrawThemeText(&self,
+		hdc: &HDC,
+		part_state: co::VS,
+		text: &str,
+		text_flags: co::DT,
+		rc: RECT,
+	) -> HrResult<()>
+	{
+		let wtext = WString::from_str(text);
+		ok_to_hrresult(
+			unsafe {
+				ffi::DrawThemeText(
+					self.ptr(),
+					hdc.ptr(),
+					part_state.part,
+					part_state.state,
+					wtext.as_ptr(),
+					wtext.str_len() as _,
+					text_flags.raw(),
+					0, // reserved
+					&rc as *const _ as _,
+				)
+			},
+		)
+	}
+
 	/// [`GetThemeAppProperties`](https://learn.microsoft.com/en-us/windows/win32/api/uxtheme/nf-uxtheme-getthemeappproperties)
 	/// function.
 	#[must_use]
@@ -149,6 +177,30 @@ pub trait uxtheme_Htheme: Handle {
 		).map(|_| color)
 	}
 
+	/// [`GetThemeFont`](https://learn.microsoft.com/en-us/windows/win32/api/uxtheme/nf-uxtheme-getthemefont)
+	/// function.
+	#[must_use]
+	fn GetThemeFont(&self,
+		hdc: Option<&HDC>,
+		part_state: co::VS,
+		prop: co::TMT,
+	) -> HrResult<LOGFONT>
+	{
+		let mut lf = LOGFONT::default();
+		ok_to_hrresult(
+			unsafe {
+				ffi::GetThemeFont(
+					self.ptr(),
+					hdc.map_or(std::ptr::null_mut(), |h| h.ptr()),
+					part_state.part(),
+					part_state.state(),
+					prop.raw(),
+					&mut lf as *mut _ as _,
+				)
+			},
+		).map(|_| lf)
+	}
+
 	/// [`GetThemeMargins`](https://learn.microsoft.com/en-us/windows/win32/api/uxtheme/nf-uxtheme-getthememargins)
 	/// function.
 	#[must_use]
@@ -291,6 +343,45 @@ pub trait uxtheme_Htheme: Handle {
 		).map(|_| rc)
 	}
 
+	/// [`GetThemeSysColor`](https://learn.microsoft.com/en-us/windows/win32/api/uxtheme/nf-uxtheme-getthemesyscolor)
+	/// function.
+	#[must_use]
+	fn GetThemeSysColor(&self, color_id: co::COLOR) -> COLORREF {
+		unsafe {
+			COLORREF::from_raw(ffi::GetThemeSysColor(self.ptr(), color_id.raw()))
+		}
+	}
+
+	/// [`GetThemeTextExtent`](https://learn.microsoft.com/en-us/windows/win32/api/uxtheme/nf-uxtheme-getthemetextextent)
+	/// function.
+	#[must_use]
+	fn GetThemeTextExtent(&self,
+		hdc: &HDC,
+		part_state: co::VS,
+		text: &str,
+		text_flags: co::DT,
+		bounds: Option<&RECT>,
+	) -> HrResult<RECT>
+	{
+		let wtext = WString::from_str(text);
+		let mut rc_extent = RECT::default();
+		ok_to_hrresult(
+			unsafe {
+				ffi::GetThemeTextExtent(
+					self.ptr(),
+					hdc.ptr(),
+					part_state.part(),
+					part_state.state(),
+					wtext.as_ptr(),
+					wtext.str_len() as _,
+					text_flags.raw(),
+					bounds.map_or(std::ptr::null(), |p| p as *const _ as _),
+					&mut rc_extent as *mut _ as _,
+				)
+			},
+		).map(|_| rc_extent)
+	}
+
 	/// [`IsThemeBackgroundPartiallyTransparent`](https://learn.microsoft.com/en-us/windows/win32/api/uxtheme/nf-uxtheme-isthemebackgroundpartiallytransparent)
 	/// function.
 	#[must_use]
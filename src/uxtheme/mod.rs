@@ -3,6 +3,7 @@
 mod funcs;
 mod handles;
 mod structs;
+mod utilities;
 
 pub(in crate::uxtheme) mod ffi;
 pub mod co;
@@ -12,6 +13,7 @@ pub mod decl {
 	pub use super::funcs::*;
 	pub use super::handles::decl::*;
 	pub use super::structs::*;
+	pub use super::utilities::*;
 }
 
 pub mod traits {
@@ -0,0 +1,3 @@
+mod themed_painter;
+
+pub use themed_painter::{PaintState, ThemedPainter};
@@ -0,0 +1,254 @@
+use crate::co;
+use crate::decl::*;
+use crate::guard::*;
+use crate::prelude::*;
+
+/// Interaction state of a control drawn by
+/// [`ThemedPainter`](crate::ThemedPainter).
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum PaintState {
+	/// Ordinary state.
+	Normal,
+	/// Mouse is over the control.
+	Hot,
+	/// Mouse button is pressed over the control.
+	Pressed,
+	/// Control is disabled.
+	Disabled,
+}
+
+impl PaintState {
+	/// Offset of the state within a group of visual style states, which are
+	/// always ordered as normal, hot, pressed and disabled.
+	const fn offset(&self) -> i32 {
+		match self {
+			Self::Normal => 0,
+			Self::Hot => 1,
+			Self::Pressed => 2,
+			Self::Disabled => 3,
+		}
+	}
+}
+
+/// Draws the look of native controls into an [`HDC`](crate::HDC), for owner-
+/// drawn and custom controls.
+///
+/// The [`co::VSCLASS::BUTTON`](crate::co::VSCLASS::BUTTON) and
+/// [`co::VSCLASS::PROGRESS`](crate::co::VSCLASS::PROGRESS) theme data are
+/// opened when the object is created. If visual styles are off – or the
+/// application has no Common Controls v6 manifest – the classic look is drawn
+/// with [`HDC::DrawFrameControl`](crate::prelude::user_Hdc::DrawFrameControl)
+/// and [`HDC::DrawEdge`](crate::prelude::user_Hdc::DrawEdge).
+///
+/// The text is drawn with the font currently selected into the `HDC`.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let hwnd: w::HWND; // initialized somewhere
+/// # let hwnd = w::HWND::NULL;
+///
+/// let mut painter = w::ThemedPainter::new(&hwnd);
+///
+/// // ... after WM_THEMECHANGED ...
+/// painter.reload(&hwnd);
+///
+/// // ... in WM_PAINT ...
+/// let hdc = hwnd.BeginPaint()?;
+/// painter.check_box(&hdc, w::RECT { left: 10, top: 10, right: 150, bottom: 30 },
+///     "Enabled", co::BST::CHECKED, w::PaintState::Hot)?;
+/// painter.progress(&hdc, w::RECT { left: 10, top: 40, right: 150, bottom: 56 },
+///     30, 100)?;
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub struct ThemedPainter {
+	hbutton: Option<CloseThemeDataGuard>,
+	hprogress: Option<CloseThemeDataGuard>,
+}
+
+impl ThemedPainter {
+	/// Opens the theme data for the given window.
+	#[must_use]
+	pub fn new(hwnd: &HWND) -> Self {
+		Self {
+			hbutton: hwnd.OpenThemeData(co::VSCLASS::BUTTON.name()),
+			hprogress: hwnd.OpenThemeData(co::VSCLASS::PROGRESS.name()),
+		}
+	}
+
+	/// Closes and reopens the theme data. Call this method when the window
+	/// receives [`co::WM::THEMECHANGED`](crate::co::WM::THEMECHANGED).
+	pub fn reload(&mut self, hwnd: &HWND) {
+		*self = Self::new(hwnd); // current theme data closed
+	}
+
+	/// Returns `true` if the controls are drawn with visual styles; `false`
+	/// means the classic look.
+	#[must_use]
+	pub const fn is_themed(&self) -> bool {
+		self.hbutton.is_some()
+	}
+
+	/// Draws a push button with a centered text.
+	pub fn button(&self,
+		hdc: &HDC,
+		rc: RECT,
+		text: &str,
+		state: PaintState,
+	) -> HrResult<()>
+	{
+		let text_flags = co::DT::CENTER | co::DT::VCENTER | co::DT::SINGLELINE;
+
+		if let Some(htheme) = &self.hbutton {
+			let vs = co::VS::new(
+				co::VS::BUTTON_PUSHBUTTON_NORMAL.part(),
+				co::VS::BUTTON_PUSHBUTTON_NORMAL.state() + state.offset(),
+			);
+			htheme.DrawThemeBackground(hdc, vs, rc, rc)?;
+			let rc_content = htheme.GetThemeBackgroundContentRect(hdc, vs, rc)?;
+			htheme.DrawThemeText(hdc, vs, text, text_flags, rc_content)
+		} else {
+			let mut flags = co::DFCS::BUTTONPUSH;
+			match state {
+				PaintState::Pressed => flags |= co::DFCS::PUSHED,
+				PaintState::Disabled => flags |= co::DFCS::INACTIVE,
+				_ => {},
+			}
+
+			let mut rc_content = rc;
+			hdc.DrawFrameControl(&mut rc_content, co::DFC::BUTTON, flags | co::DFCS::ADJUSTRECT)
+				.map_err(|err| err.to_hresult())?;
+			if state == PaintState::Pressed { // classic buttons shift the text
+				rc_content.left += 1;
+				rc_content.top += 1;
+			}
+			Self::classic_text(hdc, rc_content, text, text_flags, state)
+		}
+	}
+
+	/// Draws a check box, with the text at its right.
+	pub fn check_box(&self,
+		hdc: &HDC,
+		rc: RECT,
+		text: &str,
+		check: co::BST,
+		state: PaintState,
+	) -> HrResult<()>
+	{
+		let text_flags = co::DT::LEFT | co::DT::VCENTER | co::DT::SINGLELINE;
+
+		if let Some(htheme) = &self.hbutton {
+			let first_state = match check {
+				co::BST::CHECKED => co::VS::BUTTON_CHECKBOX_CHECKEDNORMAL,
+				co::BST::INDETERMINATE => co::VS::BUTTON_CHECKBOX_MIXEDNORMAL,
+				_ => co::VS::BUTTON_CHECKBOX_UNCHECKEDNORMAL,
+			};
+			let vs = co::VS::new(first_state.part(), first_state.state() + state.offset());
+
+			let sz = htheme.GetThemePartSize(Some(hdc), vs, None, co::THEMESIZE::DRAW)?;
+			let (rc_box, rc_text) = Self::split_check_box(rc, sz);
+			htheme.DrawThemeBackground(hdc, vs, rc_box, rc_box)?;
+			htheme.DrawThemeText(hdc, vs, text, text_flags, rc_text)
+		} else {
+			let mut flags = match check {
+				co::BST::CHECKED => co::DFCS::BUTTONCHECK | co::DFCS::CHECKED,
+				co::BST::INDETERMINATE => co::DFCS::BUTTON3STATE | co::DFCS::CHECKED,
+				_ => co::DFCS::BUTTONCHECK,
+			};
+			match state {
+				PaintState::Pressed => flags |= co::DFCS::PUSHED,
+				PaintState::Disabled => flags |= co::DFCS::INACTIVE,
+				_ => {},
+			}
+
+			let sz = SIZE::new(
+				GetSystemMetrics(co::SM::CXMENUCHECK),
+				GetSystemMetrics(co::SM::CYMENUCHECK),
+			);
+			let (mut rc_box, rc_text) = Self::split_check_box(rc, sz);
+			hdc.DrawFrameControl(&mut rc_box, co::DFC::BUTTON, flags)
+				.map_err(|err| err.to_hresult())?;
+			Self::classic_text(hdc, rc_text, text, text_flags, state)
+		}
+	}
+
+	/// Draws a horizontal progress bar, filled up to `pos`, which ranges from
+	/// zero to `max`.
+	pub fn progress(&self,
+		hdc: &HDC,
+		rc: RECT,
+		pos: u32,
+		max: u32,
+	) -> HrResult<()>
+	{
+		let fill_width = |rc_bar: RECT| -> i32 {
+			let pos = pos.min(max) as i64;
+			((rc_bar.right - rc_bar.left) as i64 * pos / max.max(1) as i64) as _
+		};
+
+		if let Some(htheme) = &self.hprogress {
+			htheme.DrawThemeBackground(hdc, co::VS::PROGRESS_BAR, rc, rc)?;
+			let mut rc_fill = htheme.GetThemeBackgroundContentRect(
+				hdc, co::VS::PROGRESS_BAR, rc)?;
+			rc_fill.right = rc_fill.left + fill_width(rc_fill);
+			if rc_fill.right > rc_fill.left {
+				htheme.DrawThemeBackground(hdc, co::VS::PROGRESS_FILL_NORMAL, rc_fill, rc_fill)?;
+			}
+			Ok(())
+		} else {
+			let mut rc_bar = rc;
+			hdc.DrawEdge(&mut rc_bar, co::BDR::SUNKENOUTER, co::BF::RECT | co::BF::ADJUST)
+				.map_err(|err| err.to_hresult())?;
+			hdc.FillRect(rc_bar, &HBRUSH::from_sys_color(co::COLOR::BTNFACE))
+				.map_err(|err| err.to_hresult())?;
+
+			let mut rc_fill = rc_bar;
+			rc_fill.right = rc_fill.left + fill_width(rc_bar);
+			if rc_fill.right > rc_fill.left {
+				hdc.FillRect(rc_fill, &HBRUSH::from_sys_color(co::COLOR::HIGHLIGHT))
+					.map_err(|err| err.to_hresult())?;
+			}
+			Ok(())
+		}
+	}
+
+	/// Returns the rectangles of the box, vertically centered at the left, and
+	/// of the text.
+	fn split_check_box(rc: RECT, sz_box: SIZE) -> (RECT, RECT) {
+		let top = rc.top + (rc.bottom - rc.top - sz_box.cy) / 2;
+		let rc_box = RECT {
+			left: rc.left,
+			top,
+			right: rc.left + sz_box.cx,
+			bottom: top + sz_box.cy,
+		};
+		let rc_text = RECT { left: rc_box.right + sz_box.cx / 3, ..rc };
+		(rc_box, rc_text)
+	}
+
+	fn classic_text(
+		hdc: &HDC,
+		rc: RECT,
+		text: &str,
+		text_flags: co::DT,
+		state: PaintState,
+	) -> HrResult<()>
+	{
+		let color = GetSysColor(if state == PaintState::Disabled {
+			co::COLOR::GRAYTEXT
+		} else {
+			co::COLOR::BTNTEXT
+		});
+
+		let prev_mode = hdc.SetBkMode(co::BKMODE::TRANSPARENT)
+			.map_err(|err| err.to_hresult())?;
+		let prev_color = hdc.SetTextColor(color)
+			.map_err(|err| err.to_hresult())?;
+		let res = hdc.DrawText(text, &rc, text_flags);
+		let _ = hdc.SetTextColor(prev_color);
+		let _ = hdc.SetBkMode(prev_mode);
+		res.map(|_| ()).map_err(|err| err.to_hresult())
+	}
+}